@@ -1,11 +1,103 @@
 #![no_std]
+#![allow(clippy::too_many_arguments)]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short, Address, BytesN, Env, IntoVal, String,
-     Val, Vec,
+    contract, contracterror, contractevent, contractimpl, contracttype, symbol_short, Address,
+    BytesN, Env, IntoVal, String, Symbol, Val, Vec,
 };
 
+pub(crate) const DAY_IN_LEDGERS: u32 = 17280;
+pub(crate) const TTL_EXTENSION: u32 = 30 * DAY_IN_LEDGERS;
+pub(crate) const TTL_THRESHOLD: u32 = TTL_EXTENSION - DAY_IN_LEDGERS;
+pub(crate) const LEDGER_CLOSE_SECONDS: u64 = 5;
 
-#[derive(Clone)]
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    NotInitialized = 2,
+    InvalidSupply = 3,
+    InvalidPrice = 4,
+    InvalidFeeBps = 5,
+    EventInitFailed = 6,
+    EventNotFound = 7,
+    InvalidSchedule = 8,
+    NoPendingAdmin = 9,
+    EventUpgradeFailed = 10,
+    TemplateNotFound = 11,
+    TemplateExists = 12,
+}
+
+/// Tier configuration forwarded to the ticket contract's `init`. Field names
+/// and types must match the ticket contract's `TierConfig`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TierConfig {
+    pub name: String,
+    pub supply: u32,
+    pub price: i128,
+    pub creator_fee_bps: u32,
+    pub max_per_wallet: u32,
+}
+
+/// Sale and event timestamps forwarded to the ticket contract's `init`.
+/// Field names and types must match the ticket contract's `EventSchedule`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct EventSchedule {
+    pub sale_start: u64,
+    pub sale_end: u64,
+    pub event_start: u64,
+    pub event_end: u64,
+}
+
+/// Everything needed to configure a new event. Pass an empty `tiers` list
+/// for a single tier built from `total_supply`, `primary_price` and
+/// `creator_fee_bps`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct EventParams {
+    pub total_supply: u32,
+    pub primary_price: i128,
+    pub creator_fee_bps: u32,
+    pub event_metadata: String,
+    pub name: String,
+    pub symbol: String,
+    pub payment_token: Address,
+    pub tiers: Vec<TierConfig>,
+    pub schedule: EventSchedule,
+}
+
+/// The platform's cut of sales on events deployed from now on. Field names
+/// and types must match the ticket contract's `ProtocolFee`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ProtocolFee {
+    pub primary_bps: u32,
+    pub secondary_bps: u32,
+    pub treasury: Address,
+}
+
+/// Who may upgrade an event's code. Variants must match the ticket contract's
+/// `UpgradeAuthority`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum UpgradeAuthority {
+    Factory,
+    Creator,
+}
+
+/// A ticket contract WASM registered with the factory, such as version 1 of
+/// "standard" or "soulbound". Each name and version pair is registered once.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Template {
+    pub template_id: u32,
+    pub name: String,
+    pub version: u32,
+    pub wasm_hash: BytesN<32>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
 pub struct EventRecord {
     pub event_contract: Address,
@@ -13,16 +105,41 @@ pub struct EventRecord {
     pub name: String,
     pub symbol: String,
     pub created_at: u64,
+    pub event_start: u64,
+    pub event_end: u64,
+    pub template_id: u32,
+    pub template_version: u32,
+}
+
+/// Emitted when the factory deploys and initializes a new event contract
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventCreated {
+    #[topic]
+    pub event_id: u32,
+    #[topic]
+    pub event_creator: Address,
+    pub event_contract: Address,
+    pub name: String,
+    pub symbol: String,
 }
 
+/// Factory configuration and the event counter live in instance storage,
+/// while templates, event records and per-creator indexes are persistent
+/// entries. Event ids run from 1 to the counter with no gaps.
 #[derive(Clone)]
 #[contracttype]
-pub enum DataKey {
-    WasmHash,
+pub(crate) enum DataKey {
+    Admin,
+    PendingAdmin,
+    ProtocolFee,
+    TemplateCounter,
+    DefaultTemplate,
+    Template(u32),
+    TemplateId(String, u32),
     EventCounter,
     EventRecord(u32),
     CreatorEvents(Address),
-    AllEvents,
 }
 
 #[contract]
@@ -30,66 +147,124 @@ pub struct TicketFactory;
 
 #[contractimpl]
 impl TicketFactory {
-    /// Initialize the factory with the ticket contract WASM hash
-    pub fn initialize(env: Env, wasm_hash: BytesN<32>) {
-        if env.storage().instance().has(&DataKey::WasmHash) {
-            panic!("Factory already initialized");
-        }
+    /// Set up the factory with its admin and the ticket contract WASM hash,
+    /// which is registered as template 1, "standard" version 1, and becomes
+    /// the default template. This runs as part of the deployment, so nobody
+    /// can claim the factory in between, and the admin must authorize it.
+    /// No protocol fee is charged until the admin sets one.
+    pub fn __constructor(env: Env, admin: Address, wasm_hash: BytesN<32>) -> Result<(), Error> {
+        admin.require_auth();
+
+        let protocol_fee = ProtocolFee {
+            primary_bps: 0,
+            secondary_bps: 0,
+            treasury: admin.clone(),
+        };
 
-        env.storage().instance().set(&DataKey::WasmHash, &wasm_hash);
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&DataKey::ProtocolFee, &protocol_fee);
         env.storage().instance().set(&DataKey::EventCounter, &0u32);
+        Self::extend_instance_ttl(&env);
+
+        let template_id =
+            Self::register_template(&env, String::from_str(&env, "standard"), 1, wasm_hash)?;
         env.storage()
             .instance()
-            .set(&DataKey::AllEvents, &Vec::<u32>::new(&env));
+            .set(&DataKey::DefaultTemplate, &template_id);
+
+        Ok(())
     }
 
-    /// Deploy a new ticket marketplace event
+    /// Deploy a new ticket marketplace event from a registered template, or
+    /// from the default template when `template_id` is 0
     pub fn create_event(
         env: Env,
         salt: BytesN<32>,
         event_creator: Address,
-        total_supply: u32,
-        primary_price: i128,
-        creator_fee_bps: u32,
-        event_metadata: String,
-        name: String,
-        symbol: String,
-        payment_token: Address,
-    ) -> Address {
+        template_id: u32,
+        params: EventParams,
+    ) -> Result<Address, Error> {
         // Authenticate the creator
         event_creator.require_auth();
+        Self::extend_instance_ttl(&env);
 
-        // Retrieve the stored WASM hash for the NFT contract
-        let wasm_hash: BytesN<32> = env
-            .storage()
-            .instance()
-            .get(&DataKey::WasmHash)
-            .expect("Factory not initialized");
+        // Validate the parameters forwarded to the child's `init` up front so
+        // the organizer gets a precise error instead of a failed deployment.
+        if params.total_supply == 0 {
+            return Err(Error::InvalidSupply);
+        }
+        if params.primary_price <= 0 {
+            return Err(Error::InvalidPrice);
+        }
+        if params.creator_fee_bps > 10000 {
+            return Err(Error::InvalidFeeBps);
+        }
+        Self::validate_tiers(params.total_supply, &params.tiers)?;
+        Self::validate_schedule(&params.schedule)?;
+
+        // Creator fees and the protocol's resale cut come out of the same price
+        let protocol_fee = Self::get_protocol_fee(env.clone())?;
+        let mut max_creator_fee_bps = params.creator_fee_bps;
+        for tier in params.tiers.iter() {
+            max_creator_fee_bps = max_creator_fee_bps.max(tier.creator_fee_bps);
+        }
+        if max_creator_fee_bps + protocol_fee.secondary_bps > 10000 {
+            return Err(Error::InvalidFeeBps);
+        }
+
+        // Retrieve the WASM hash of the requested template
+        let template = if template_id == 0 {
+            Self::read_default_template(&env)?
+        } else {
+            Self::read_template(&env, template_id).ok_or(Error::TemplateNotFound)?
+        };
 
         // Deploy the contract using the Factory's address + salt for deterministic addressing
         let deployed_address = env
             .deployer()
             .with_current_contract(salt)
-            .deploy(wasm_hash);
+            .deploy_v2(template.wasm_hash, ());
 
         // Prepare arguments for the child contract's `init` function.
         // Important: invoke_contract requires a Vec<Val>, not a tuple.
         let mut init_args: Vec<Val> = Vec::new(&env);
         init_args.push_back(event_creator.clone().into_val(&env));
-        init_args.push_back(total_supply.into_val(&env));
-        init_args.push_back(primary_price.into_val(&env));
-        init_args.push_back(creator_fee_bps.into_val(&env));
-        init_args.push_back(event_metadata.into_val(&env));
-        init_args.push_back(name.clone().into_val(&env));
-        init_args.push_back(symbol.clone().into_val(&env));
-        init_args.push_back(payment_token.into_val(&env));
-
-        // Call the `initialize` function on the newly deployed contract
-        env.invoke_contract::<()>(
+        init_args.push_back(params.total_supply.into_val(&env));
+        init_args.push_back(params.primary_price.into_val(&env));
+        init_args.push_back(params.creator_fee_bps.into_val(&env));
+        init_args.push_back(params.event_metadata.into_val(&env));
+        init_args.push_back(params.name.clone().into_val(&env));
+        init_args.push_back(params.symbol.clone().into_val(&env));
+        init_args.push_back(params.payment_token.into_val(&env));
+        init_args.push_back(params.tiers.into_val(&env));
+        init_args.push_back(params.schedule.clone().into_val(&env));
+
+        // Call the `init` function on the newly deployed contract. Any rejection
+        // from the child is surfaced as a distinct factory error.
+        let init_result = env.try_invoke_contract::<(), soroban_sdk::Error>(
             &deployed_address,
-            &symbol_short!("init"), // Assuming the child function is named "init" or "initialize"
+            &symbol_short!("init"),
             init_args,
         );
+        if !matches!(init_result, Ok(Ok(()))) {
+            return Err(Error::EventInitFailed);
+        }
+
+        // Record this factory on the event along with the current protocol fee,
+        // so later fee changes only apply to events deployed after them
+        let mut factory_args: Vec<Val> = Vec::new(&env);
+        factory_args.push_back(env.current_contract_address().into_val(&env));
+        factory_args.push_back(protocol_fee.into_val(&env));
+        let factory_result = env.try_invoke_contract::<(), soroban_sdk::Error>(
+            &deployed_address,
+            &Symbol::new(&env, "set_factory"),
+            factory_args,
+        );
+        if !matches!(factory_result, Ok(Ok(()))) {
+            return Err(Error::EventInitFailed);
+        }
 
         // --- Storage Logic Below ---
 
@@ -99,80 +274,252 @@ impl TicketFactory {
             .instance()
             .get(&DataKey::EventCounter)
             .unwrap_or(0);
-            
+
         let event_id = counter + 1;
 
         let event_record = EventRecord {
             event_contract: deployed_address.clone(),
             event_creator: event_creator.clone(),
-            name: name.clone(),
-            symbol: symbol.clone(),
+            name: params.name.clone(),
+            symbol: params.symbol.clone(),
             created_at: env.ledger().timestamp(),
+            event_start: params.schedule.event_start,
+            event_end: params.schedule.event_end,
+            template_id: template.template_id,
+            template_version: template.version,
         };
 
         // 1. Store the specific event record
-        env.storage()
-            .instance()
-            .set(&DataKey::EventRecord(event_id), &event_record);
+        Self::write_event_record(&env, event_id, &event_record);
 
         // 2. Update global counter
         env.storage()
             .instance()
             .set(&DataKey::EventCounter, &event_id);
 
-        // 3. Add to "All Events" list
-        let mut all_events: Vec<u32> = env
-            .storage()
+        // 3. Add to "Creator Events" list
+        let mut creator_events = Self::read_creator_events(&env, &event_creator);
+
+        creator_events.push_back(event_id);
+
+        Self::write_creator_events(
+            &env,
+            &event_creator,
+            &creator_events,
+            params.schedule.event_end,
+        );
+
+        EventCreated {
+            event_id,
+            event_creator,
+            event_contract: deployed_address.clone(),
+            name: params.name,
+            symbol: params.symbol,
+        }
+        .publish(&env);
+
+        Ok(deployed_address)
+    }
+
+    // === Administration ===
+
+    /// Register a ticket contract WASM that new events can be deployed from
+    /// (admin only). Returns the new template's id.
+    pub fn add_template(
+        env: Env,
+        name: String,
+        version: u32,
+        wasm_hash: BytesN<32>,
+    ) -> Result<u32, Error> {
+        Self::read_admin(&env)?.require_auth();
+        Self::extend_instance_ttl(&env);
+
+        Self::register_template(&env, name, version, wasm_hash)
+    }
+
+    /// Replace the ticket contract WASM used for new events (admin only). The
+    /// hash is registered as the next version of the default template's name,
+    /// which becomes the new default template.
+    pub fn set_wasm_hash(env: Env, wasm_hash: BytesN<32>) -> Result<(), Error> {
+        Self::read_admin(&env)?.require_auth();
+        Self::extend_instance_ttl(&env);
+
+        let name = Self::read_default_template(&env)?.name;
+        let mut version = 0;
+        for template in Self::get_templates(env.clone()).iter() {
+            if template.name == name {
+                version = version.max(template.version);
+            }
+        }
+
+        let template_id = Self::register_template(&env, name, version + 1, wasm_hash)?;
+        env.storage()
             .instance()
-            .get(&DataKey::AllEvents)
-            .unwrap_or(Vec::new(&env));
-            
-        all_events.push_back(event_id);
-        
+            .set(&DataKey::DefaultTemplate, &template_id);
+
+        Ok(())
+    }
+
+    /// Upgrade the code of deployed events to a registered template (admin
+    /// only). Every event must still leave upgrades to the factory, otherwise
+    /// none of them are upgraded.
+    pub fn upgrade_events(env: Env, event_ids: Vec<u32>, template_id: u32) -> Result<(), Error> {
+        Self::read_admin(&env)?.require_auth();
+        Self::extend_instance_ttl(&env);
+
+        let template = Self::read_template(&env, template_id).ok_or(Error::TemplateNotFound)?;
+
+        for event_id in event_ids.iter() {
+            let mut record = Self::read_event_record(&env, event_id).ok_or(Error::EventNotFound)?;
+
+            let mut upgrade_args: Vec<Val> = Vec::new(&env);
+            upgrade_args.push_back(template.wasm_hash.clone().into_val(&env));
+            let upgrade_result = env.try_invoke_contract::<(), soroban_sdk::Error>(
+                &record.event_contract,
+                &Symbol::new(&env, "upgrade"),
+                upgrade_args,
+            );
+            if !matches!(upgrade_result, Ok(Ok(()))) {
+                return Err(Error::EventUpgradeFailed);
+            }
+
+            record.template_id = template_id;
+            record.template_version = template.version;
+            Self::write_event_record(&env, event_id, &record);
+        }
+
+        Ok(())
+    }
+
+    /// Hand the right to upgrade a deployed event to its creator (admin
+    /// only). The event must still leave upgrades to the factory; once the
+    /// creator holds that right, only the creator can hand it back.
+    pub fn set_event_upgrade_authority(
+        env: Env,
+        event_id: u32,
+        authority: UpgradeAuthority,
+    ) -> Result<(), Error> {
+        Self::read_admin(&env)?.require_auth();
+        Self::extend_instance_ttl(&env);
+
+        let record = Self::read_event_record(&env, event_id).ok_or(Error::EventNotFound)?;
+
+        let mut authority_args: Vec<Val> = Vec::new(&env);
+        authority_args.push_back(authority.into_val(&env));
+        let authority_result = env.try_invoke_contract::<(), soroban_sdk::Error>(
+            &record.event_contract,
+            &Symbol::new(&env, "set_upgrade_authority"),
+            authority_args,
+        );
+        if !matches!(authority_result, Ok(Ok(()))) {
+            return Err(Error::EventUpgradeFailed);
+        }
+
+        Ok(())
+    }
+
+    /// Propose a new admin (admin only). The proposal takes effect once the new
+    /// admin calls `accept_admin`, and a later proposal replaces it.
+    pub fn transfer_admin(env: Env, new_admin: Address) -> Result<(), Error> {
+        Self::read_admin(&env)?.require_auth();
+        Self::extend_instance_ttl(&env);
+
         env.storage()
             .instance()
-            .set(&DataKey::AllEvents, &all_events);
+            .set(&DataKey::PendingAdmin, &new_admin);
 
-        // 4. Add to "Creator Events" list
-        let mut creator_events: Vec<u32> = env
+        Ok(())
+    }
+
+    /// Accept a pending admin transfer (proposed admin only)
+    pub fn accept_admin(env: Env) -> Result<(), Error> {
+        Self::extend_instance_ttl(&env);
+
+        let pending_admin: Address = env
             .storage()
             .instance()
-            .get(&DataKey::CreatorEvents(event_creator.clone()))
-            .unwrap_or(Vec::new(&env));
-            
-        creator_events.push_back(event_id);
-        
+            .get(&DataKey::PendingAdmin)
+            .ok_or(Error::NoPendingAdmin)?;
+        pending_admin.require_auth();
+
         env.storage()
             .instance()
-            .set(&DataKey::CreatorEvents(event_creator), &creator_events);
+            .set(&DataKey::Admin, &pending_admin);
+        env.storage().instance().remove(&DataKey::PendingAdmin);
 
-        deployed_address
+        Ok(())
     }
 
-    /// Get event record by ID
-    pub fn get_event(env: Env, event_id: u32) -> EventRecord {
+    /// Get the admin proposed by `transfer_admin`, if any
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        Self::extend_instance_ttl(&env);
+        env.storage().instance().get(&DataKey::PendingAdmin)
+    }
+
+    /// Set the protocol fee charged on events deployed from now on (admin only).
+    /// Events that already exist keep the fee they were deployed with.
+    pub fn set_protocol_fee(env: Env, protocol_fee: ProtocolFee) -> Result<(), Error> {
+        Self::read_admin(&env)?.require_auth();
+        Self::extend_instance_ttl(&env);
+
+        if protocol_fee.primary_bps > 10000 || protocol_fee.secondary_bps > 10000 {
+            return Err(Error::InvalidFeeBps);
+        }
+
         env.storage()
             .instance()
-            .get(&DataKey::EventRecord(event_id))
-            .expect("Event not found")
+            .set(&DataKey::ProtocolFee, &protocol_fee);
+
+        Ok(())
+    }
+
+    /// Get the protocol fee charged on newly deployed events
+    pub fn get_protocol_fee(env: Env) -> Result<ProtocolFee, Error> {
+        Self::extend_instance_ttl(&env);
+        env.storage()
+            .instance()
+            .get(&DataKey::ProtocolFee)
+            .ok_or(Error::NotInitialized)
+    }
+
+    /// Get the factory admin
+    pub fn get_admin(env: Env) -> Result<Address, Error> {
+        Self::extend_instance_ttl(&env);
+        Self::read_admin(&env)
+    }
+
+    /// Extend the TTL of an event record and its creator's index until a
+    /// month after the event ends. Anyone may call this and pay for the
+    /// extension.
+    pub fn extend_event_ttl(env: Env, event_id: u32) -> Result<(), Error> {
+        Self::extend_instance_ttl(&env);
+
+        let record = Self::read_event_record(&env, event_id).ok_or(Error::EventNotFound)?;
+        Self::extend_event_entry_ttl(&env, &DataKey::EventRecord(event_id), record.event_end);
+
+        let creator_key = DataKey::CreatorEvents(record.event_creator);
+        if env.storage().persistent().has(&creator_key) {
+            Self::extend_event_entry_ttl(&env, &creator_key, record.event_end);
+        }
+
+        Ok(())
+    }
+
+    /// Get event record by ID
+    pub fn get_event(env: Env, event_id: u32) -> Result<EventRecord, Error> {
+        Self::extend_instance_ttl(&env);
+        Self::read_event_record(&env, event_id).ok_or(Error::EventNotFound)
     }
 
     /// Get all events created by a specific address
     pub fn get_creator_events(env: Env, creator: Address) -> Vec<EventRecord> {
-        let event_ids: Vec<u32> = env
-            .storage()
-            .instance()
-            .get(&DataKey::CreatorEvents(creator))
-            .unwrap_or(Vec::new(&env));
+        Self::extend_instance_ttl(&env);
+        let event_ids = Self::read_creator_events(&env, &creator);
 
         let mut events = Vec::new(&env);
         for i in 0..event_ids.len() {
             if let Some(event_id) = event_ids.get(i) {
-                if let Some(record) = env
-                    .storage()
-                    .instance()
-                    .get::<DataKey, EventRecord>(&DataKey::EventRecord(event_id))
-                {
+                if let Some(record) = Self::read_event_record(&env, event_id) {
                     events.push_back(record);
                 }
             }
@@ -182,22 +529,17 @@ impl TicketFactory {
 
     /// Get all events
     pub fn get_all_events(env: Env) -> Vec<EventRecord> {
-        let event_ids: Vec<u32> = env
+        Self::extend_instance_ttl(&env);
+        let event_count: u32 = env
             .storage()
             .instance()
-            .get(&DataKey::AllEvents)
-            .unwrap_or(Vec::new(&env));
+            .get(&DataKey::EventCounter)
+            .unwrap_or(0);
 
         let mut events = Vec::new(&env);
-        for i in 0..event_ids.len() {
-            if let Some(event_id) = event_ids.get(i) {
-                if let Some(record) = env
-                    .storage()
-                    .instance()
-                    .get::<DataKey, EventRecord>(&DataKey::EventRecord(event_id))
-                {
-                    events.push_back(record);
-                }
+        for event_id in 1..=event_count {
+            if let Some(record) = Self::read_event_record(&env, event_id) {
+                events.push_back(record);
             }
         }
         events
@@ -205,17 +547,221 @@ impl TicketFactory {
 
     /// Get total number of events created
     pub fn get_event_count(env: Env) -> u32 {
+        Self::extend_instance_ttl(&env);
         env.storage()
             .instance()
             .get(&DataKey::EventCounter)
             .unwrap_or(0)
     }
 
-    /// Get the WASM hash stored in the factory
-    pub fn get_wasm_hash(env: Env) -> BytesN<32> {
+    /// Get a template by ID
+    pub fn get_template(env: Env, template_id: u32) -> Result<Template, Error> {
+        Self::extend_instance_ttl(&env);
+        Self::read_template(&env, template_id).ok_or(Error::TemplateNotFound)
+    }
+
+    /// Get the WASM hash of the default template, used for new events
+    pub fn get_wasm_hash(env: Env) -> Result<BytesN<32>, Error> {
+        Self::extend_instance_ttl(&env);
+        Ok(Self::read_default_template(&env)?.wasm_hash)
+    }
+
+    /// Get the ID of the default template
+    pub fn get_default_template(env: Env) -> Result<u32, Error> {
+        Self::extend_instance_ttl(&env);
+        Ok(Self::read_default_template(&env)?.template_id)
+    }
+
+    /// Get the ID of a template by name and version
+    pub fn get_template_id(env: Env, name: String, version: u32) -> Result<u32, Error> {
+        Self::extend_instance_ttl(&env);
+        let key = DataKey::TemplateId(name, version);
+        let template_id = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(Error::TemplateNotFound)?;
+        Self::extend_persistent_ttl(&env, &key);
+        Ok(template_id)
+    }
+
+    /// Get all registered templates
+    pub fn get_templates(env: Env) -> Vec<Template> {
+        Self::extend_instance_ttl(&env);
+        let counter: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::TemplateCounter)
+            .unwrap_or(0);
+
+        let mut templates = Vec::new(&env);
+        for template_id in 1..=counter {
+            if let Some(template) = Self::read_template(&env, template_id) {
+                templates.push_back(template);
+            }
+        }
+        templates
+    }
+
+    // === Helper Functions ===
+
+    fn read_admin(env: &Env) -> Result<Address, Error> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)
+    }
+
+    fn register_template(
+        env: &Env,
+        name: String,
+        version: u32,
+        wasm_hash: BytesN<32>,
+    ) -> Result<u32, Error> {
+        let id_key = DataKey::TemplateId(name.clone(), version);
+        if env.storage().persistent().has(&id_key) {
+            return Err(Error::TemplateExists);
+        }
+
+        let counter: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::TemplateCounter)
+            .unwrap_or(0);
+        let template_id = counter + 1;
+
+        let template = Template {
+            template_id,
+            name,
+            version,
+            wasm_hash,
+        };
+        let key = DataKey::Template(template_id);
+        env.storage().persistent().set(&key, &template);
+        Self::extend_persistent_ttl(env, &key);
+        env.storage().persistent().set(&id_key, &template_id);
+        Self::extend_persistent_ttl(env, &id_key);
+        env.storage()
+            .instance()
+            .set(&DataKey::TemplateCounter, &template_id);
+
+        Ok(template_id)
+    }
+
+    fn read_template(env: &Env, template_id: u32) -> Option<Template> {
+        let key = DataKey::Template(template_id);
+        let template = env.storage().persistent().get(&key);
+        if template.is_some() {
+            Self::extend_persistent_ttl(env, &key);
+        }
+        template
+    }
+
+    fn read_default_template(env: &Env) -> Result<Template, Error> {
+        let template_id: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::DefaultTemplate)
+            .ok_or(Error::NotInitialized)?;
+        Self::read_template(env, template_id).ok_or(Error::TemplateNotFound)
+    }
+
+    fn validate_schedule(schedule: &EventSchedule) -> Result<(), Error> {
+        if schedule.sale_start >= schedule.sale_end
+            || schedule.event_start > schedule.event_end
+            || schedule.sale_end > schedule.event_end
+        {
+            return Err(Error::InvalidSchedule);
+        }
+
+        Ok(())
+    }
+
+    fn validate_tiers(total_supply: u32, tiers: &Vec<TierConfig>) -> Result<(), Error> {
+        if tiers.is_empty() {
+            return Ok(());
+        }
+
+        let mut tier_supply: u32 = 0;
+        for tier in tiers.iter() {
+            if tier.supply == 0 {
+                return Err(Error::InvalidSupply);
+            }
+            if tier.price <= 0 {
+                return Err(Error::InvalidPrice);
+            }
+            if tier.creator_fee_bps > 10000 {
+                return Err(Error::InvalidFeeBps);
+            }
+            tier_supply = tier_supply
+                .checked_add(tier.supply)
+                .ok_or(Error::InvalidSupply)?;
+        }
+
+        if tier_supply != total_supply {
+            return Err(Error::InvalidSupply);
+        }
+
+        Ok(())
+    }
+
+    // Event records and creator indexes are extended when they are written
+    // or through `extend_event_ttl`, never on reads, because extensions made
+    // by read-only calls are not persisted.
+    fn read_event_record(env: &Env, event_id: u32) -> Option<EventRecord> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::EventRecord(event_id))
+    }
+
+    fn write_event_record(env: &Env, event_id: u32, record: &EventRecord) {
+        let key = DataKey::EventRecord(event_id);
+        env.storage().persistent().set(&key, record);
+        Self::extend_event_entry_ttl(env, &key, record.event_end);
+    }
+
+    fn read_creator_events(env: &Env, creator: &Address) -> Vec<u32> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::CreatorEvents(creator.clone()))
+            .unwrap_or(Vec::new(env))
+    }
+
+    fn write_creator_events(env: &Env, creator: &Address, event_ids: &Vec<u32>, event_end: u64) {
+        let key = DataKey::CreatorEvents(creator.clone());
+        env.storage().persistent().set(&key, event_ids);
+        Self::extend_event_entry_ttl(env, &key, event_end);
+    }
+
+    /// Ledgers from now until `TTL_EXTENSION` after `event_end`, capped at
+    /// the network's maximum TTL
+    fn event_ttl_extension(env: &Env, event_end: u64) -> u32 {
+        let max_ttl = env.storage().max_ttl();
+        let until_end = event_end.saturating_sub(env.ledger().timestamp()) / LEDGER_CLOSE_SECONDS;
+        let extension = until_end.saturating_add(TTL_EXTENSION as u64);
+        extension.min(max_ttl as u64) as u32
+    }
+
+    fn extend_event_entry_ttl(env: &Env, key: &DataKey, event_end: u64) {
+        let extend_to = Self::event_ttl_extension(env, event_end);
+        env.storage().persistent().extend_ttl(
+            key,
+            extend_to.saturating_sub(DAY_IN_LEDGERS),
+            extend_to,
+        );
+    }
+
+    fn extend_instance_ttl(env: &Env) {
         env.storage()
             .instance()
-            .get(&DataKey::WasmHash)
-            .expect("Factory not initialized")
+            .extend_ttl(TTL_THRESHOLD, TTL_EXTENSION);
+    }
+
+    fn extend_persistent_ttl(env: &Env, key: &DataKey) {
+        env.storage()
+            .persistent()
+            .extend_ttl(key, TTL_THRESHOLD, TTL_EXTENSION);
     }
 }
+
+mod test;
@@ -1,21 +1,960 @@
 #![cfg(test)]
+extern crate std;
 
 use super::*;
-use soroban_sdk::{vec, Env, String};
+use soroban_sdk::{
+    testutils::{
+        storage::Instance as _, storage::Persistent as _, Address as _, AuthorizedFunction,
+        AuthorizedInvocation, Events, Ledger, MockAuth, MockAuthInvoke,
+    },
+    token, vec, Address, BytesN, Env, Event, IntoVal, String, Symbol, Vec,
+};
+
+mod nft {
+    soroban_sdk::contractimport!(file = "testdata/nft_collections.wasm");
+}
+
+// A contract that only exposes `add(a, b)` and therefore has no `init`
+mod add {
+    soroban_sdk::contractimport!(file = "testdata/add.wasm");
+}
+const ADD_WASM: &[u8] = add::WASM;
+
+// Helper function to deploy a factory and a payment token
+fn setup_test<'a>(env: &Env) -> (TicketFactoryClient<'a>, Address, Address) {
+    env.mock_all_auths();
+
+    let admin = Address::generate(env);
+    let wasm_hash = env.deployer().upload_contract_wasm(nft::WASM);
+    let contract_id = env.register(TicketFactory, (&admin, &wasm_hash));
+    let client = TicketFactoryClient::new(env, &contract_id);
+
+    let token_admin = Address::generate(env);
+    let token_id = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+
+    let creator = Address::generate(env);
+
+    (client, token_id, creator)
+}
+
+const DAY_SECONDS: u64 = 86_400;
+
+// Parameters for a single-tier event on sale for 30 days
+fn event_params(
+    env: &Env,
+    payment_token: &Address,
+    total_supply: u32,
+    primary_price: i128,
+    creator_fee_bps: u32,
+) -> EventParams {
+    EventParams {
+        total_supply,
+        primary_price,
+        creator_fee_bps,
+        event_metadata: String::from_str(env, "Test Event"),
+        name: String::from_str(env, "Event Tickets"),
+        symbol: String::from_str(env, "EVNT"),
+        payment_token: payment_token.clone(),
+        tiers: Vec::new(env),
+        schedule: EventSchedule {
+            sale_start: 0,
+            sale_end: 30 * DAY_SECONDS,
+            event_start: 31 * DAY_SECONDS,
+            event_end: 31 * DAY_SECONDS + DAY_SECONDS / 4,
+        },
+    }
+}
+
+// Helper function to create an event with the given pricing parameters
+fn try_create_event(
+    env: &Env,
+    client: &TicketFactoryClient,
+    salt: u8,
+    creator: &Address,
+    payment_token: &Address,
+    total_supply: u32,
+    primary_price: i128,
+    creator_fee_bps: u32,
+) -> Result<Address, Error> {
+    match client.try_create_event(
+        &BytesN::from_array(env, &[salt; 32]),
+        creator,
+        &1,
+        &event_params(
+            env,
+            payment_token,
+            total_supply,
+            primary_price,
+            creator_fee_bps,
+        ),
+    ) {
+        Ok(Ok(address)) => Ok(address),
+        Err(Ok(err)) => Err(err),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn test_create_event() {
+    let env = Env::default();
+    let (client, payment_token, creator) = setup_test(&env);
+
+    let event_address = try_create_event(
+        &env,
+        &client,
+        1,
+        &creator,
+        &payment_token,
+        100,
+        10_000_000,
+        250,
+    )
+    .unwrap();
+
+    let event = nft::Client::new(&env, &event_address);
+    let event_info = event.get_event_info();
+    assert_eq!(event_info.event_creator, creator);
+    assert_eq!(event_info.total_supply, 100);
+    assert_eq!(event_info.primary_price, 10_000_000);
+    assert_eq!(event_info.payment_token, payment_token);
+    assert_eq!(event_info.schedule.sale_end, 30 * DAY_SECONDS);
+
+    assert_eq!(client.get_event_count(), 1);
+    let record = client.get_event(&1);
+    assert_eq!(record.event_contract, event_address);
+    assert_eq!(record.event_creator, creator);
+    assert_eq!(record.event_start, 31 * DAY_SECONDS);
+    assert_eq!(record.event_end, 31 * DAY_SECONDS + DAY_SECONDS / 4);
+    assert_eq!(record.template_id, 1);
+    assert_eq!(record.template_version, 1);
+    assert_eq!(client.get_creator_events(&creator).len(), 1);
+    assert_eq!(client.get_all_events().len(), 1);
+
+    // The token is usable straight away
+    token::StellarAssetClient::new(&env, &payment_token).mint(&creator, &10_000_000);
+    assert_eq!(event.mint_ticket(&creator, &0), 1);
+}
+
+#[test]
+fn test_create_event_with_tiers() {
+    let env = Env::default();
+    let (client, payment_token, creator) = setup_test(&env);
+
+    let tier = |name: &str, supply: u32, price: i128| TierConfig {
+        name: String::from_str(&env, name),
+        supply,
+        price,
+        creator_fee_bps: 500,
+        max_per_wallet: 0,
+    };
+    let create = |salt: u8, total_supply: u32, tiers: Vec<TierConfig>| {
+        let mut params = event_params(&env, &payment_token, total_supply, 10_000_000, 250);
+        params.tiers = tiers;
+        client.try_create_event(
+            &BytesN::from_array(&env, &[salt; 32]),
+            &creator,
+            &1,
+            &params,
+        )
+    };
+
+    let event_address = create(
+        1,
+        60,
+        vec![
+            &env,
+            tier("GA", 50, 10_000_000),
+            tier("VIP", 10, 40_000_000),
+        ],
+    )
+    .unwrap()
+    .unwrap();
+
+    let event = nft::Client::new(&env, &event_address);
+    let tiers = event.get_tiers();
+    assert_eq!(tiers.len(), 2);
+    assert_eq!(tiers.get(1).unwrap().name, String::from_str(&env, "VIP"));
+    assert_eq!(tiers.get(1).unwrap().price, 40_000_000);
+    assert_eq!(event.get_tier_available(&1), 10);
+
+    assert_eq!(
+        create(
+            2,
+            61,
+            vec![
+                &env,
+                tier("GA", 50, 10_000_000),
+                tier("VIP", 10, 40_000_000)
+            ]
+        ),
+        Err(Ok(Error::InvalidSupply))
+    );
+    assert_eq!(
+        create(3, 50, vec![&env, tier("GA", 50, 0)]),
+        Err(Ok(Error::InvalidPrice))
+    );
+    assert_eq!(client.get_event_count(), 1);
+}
+
+#[test]
+fn test_protocol_fee() {
+    let env = Env::default();
+    let (client, payment_token, creator) = setup_test(&env);
+    let treasury = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let token_admin = token::StellarAssetClient::new(&env, &payment_token);
+    let token = token::Client::new(&env, &payment_token);
+
+    // Events deployed before a fee is set are not charged one
+    let free_address = try_create_event(
+        &env,
+        &client,
+        1,
+        &creator,
+        &payment_token,
+        100,
+        10_000_000,
+        250,
+    )
+    .unwrap();
+
+    let protocol_fee = ProtocolFee {
+        primary_bps: 200,
+        secondary_bps: 100,
+        treasury: treasury.clone(),
+    };
+    client.set_protocol_fee(&protocol_fee);
+    assert_eq!(client.get_protocol_fee(), protocol_fee);
+
+    let event_address = try_create_event(
+        &env,
+        &client,
+        2,
+        &creator,
+        &payment_token,
+        100,
+        10_000_000,
+        500,
+    )
+    .unwrap();
+
+    let event = nft::Client::new(&env, &event_address);
+    assert_eq!(event.get_factory(), Some(client.address.clone()));
+    let event_fee = event.get_protocol_fee().unwrap();
+    assert_eq!(event_fee.primary_bps, 200);
+    assert_eq!(event_fee.secondary_bps, 100);
+    assert_eq!(event_fee.treasury, treasury);
+
+    // Primary: 2% of the price goes to the treasury
+    token_admin.mint(&buyer, &100_000_000);
+    let ticket_id = event.mint_ticket(&buyer, &0);
+    assert_eq!(token.balance(&treasury), 200_000);
+    assert_eq!(token.balance(&creator), 9_800_000);
+
+    // Secondary: 1% to the treasury on top of the 5% creator fee
+    let reseller = Address::generate(&env);
+    token_admin.mint(&reseller, &100_000_000);
+    event.list_ticket(&buyer, &ticket_id, &20_000_000, &None);
+    event.buy_secondary_ticket(&reseller, &ticket_id);
+    assert_eq!(token.balance(&treasury), 400_000);
+    assert_eq!(token.balance(&creator), 10_800_000);
+    assert_eq!(token.balance(&buyer), 90_000_000 + 18_800_000);
+
+    // Raising the fee later does not change existing events
+    client.set_protocol_fee(&ProtocolFee {
+        primary_bps: 1_000,
+        secondary_bps: 1_000,
+        treasury: treasury.clone(),
+    });
+    assert_eq!(event.get_protocol_fee().unwrap().primary_bps, 200);
+    let free_event = nft::Client::new(&env, &free_address);
+    assert_eq!(free_event.get_protocol_fee().unwrap().primary_bps, 0);
+}
+
+#[test]
+fn test_protocol_fee_errors() {
+    let env = Env::default();
+    let (client, payment_token, creator) = setup_test(&env);
+    let treasury = Address::generate(&env);
+
+    assert_eq!(
+        client.try_set_protocol_fee(&ProtocolFee {
+            primary_bps: 10_001,
+            secondary_bps: 0,
+            treasury: treasury.clone(),
+        }),
+        Err(Ok(Error::InvalidFeeBps))
+    );
+
+    // Creator and protocol cuts cannot add up to more than the resale price
+    client.set_protocol_fee(&ProtocolFee {
+        primary_bps: 0,
+        secondary_bps: 9_000,
+        treasury,
+    });
+    assert_eq!(
+        try_create_event(
+            &env,
+            &client,
+            1,
+            &creator,
+            &payment_token,
+            100,
+            10_000_000,
+            1_001
+        ),
+        Err(Error::InvalidFeeBps)
+    );
+    try_create_event(
+        &env,
+        &client,
+        1,
+        &creator,
+        &payment_token,
+        100,
+        10_000_000,
+        1_000,
+    )
+    .unwrap();
+}
+
+// === Administration ===
+
+// Authorize only `address` for a single call to `contract`
+fn mock_auth_for_contract(
+    env: &Env,
+    contract: &Address,
+    address: &Address,
+    fn_name: &str,
+    args: Vec<soroban_sdk::Val>,
+) {
+    env.mock_auths(&[MockAuth {
+        address,
+        invoke: &MockAuthInvoke {
+            contract,
+            fn_name,
+            args,
+            sub_invokes: &[],
+        },
+    }]);
+}
+
+// Authorize only `address` for a single call to the factory
+fn mock_auth_for(
+    env: &Env,
+    client: &TicketFactoryClient,
+    address: &Address,
+    fn_name: &str,
+    args: Vec<soroban_sdk::Val>,
+) {
+    mock_auth_for_contract(env, &client.address, address, fn_name, args);
+}
 
 #[test]
-fn test() {
+fn test_constructor() {
     let env = Env::default();
-    let contract_id = env.register(Contract, ());
-    let client = ContractClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let wasm_hash = env.deployer().upload_contract_wasm(nft::WASM);
+    let contract_id = env.register(TicketFactory, (&admin, &wasm_hash));
+    let client = TicketFactoryClient::new(&env, &contract_id);
+    assert_eq!(
+        env.auths(),
+        std::vec![(
+            admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    client.address.clone(),
+                    Symbol::new(&env, "__constructor"),
+                    (admin.clone(), wasm_hash.clone()).into_val(&env),
+                )),
+                sub_invocations: std::vec![],
+            }
+        )]
+    );
+
+    // The factory is set up at deployment, with nothing left to claim
+    assert_eq!(client.get_admin(), admin);
+    assert_eq!(client.get_template(&1).wasm_hash, wasm_hash);
+    assert_eq!(client.get_templates().len(), 1);
+    assert_eq!(client.get_event_count(), 0);
+    assert_eq!(
+        client.get_protocol_fee(),
+        ProtocolFee {
+            primary_bps: 0,
+            secondary_bps: 0,
+            treasury: admin,
+        }
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_constructor_requires_admin_auth() {
+    let env = Env::default();
+
+    let admin = Address::generate(&env);
+    let wasm_hash = env.deployer().upload_contract_wasm(nft::WASM);
+    env.register(TicketFactory, (&admin, &wasm_hash));
+}
+
+#[test]
+fn test_add_template() {
+    let env = Env::default();
+    let (client, _, _) = setup_test(&env);
+    let admin = client.get_admin();
+    let attacker = Address::generate(&env);
+    let name = String::from_str(&env, "soulbound");
+    let new_hash = env.deployer().upload_contract_wasm(ADD_WASM);
+
+    // The constructor registers the first template
+    let standard = client.get_template(&1);
+    assert_eq!(standard.name, String::from_str(&env, "standard"));
+    assert_eq!(standard.version, 1);
+
+    mock_auth_for(
+        &env,
+        &client,
+        &attacker,
+        "add_template",
+        (name.clone(), 1u32, new_hash.clone()).into_val(&env),
+    );
+    assert!(client.try_add_template(&name, &1, &new_hash).is_err());
+    assert_eq!(client.get_templates().len(), 1);
+
+    mock_auth_for(
+        &env,
+        &client,
+        &admin,
+        "add_template",
+        (name.clone(), 1u32, new_hash.clone()).into_val(&env),
+    );
+    assert_eq!(client.add_template(&name, &1, &new_hash), 2);
+
+    let template = client.get_template(&2);
+    assert_eq!(template.template_id, 2);
+    assert_eq!(template.name, name);
+    assert_eq!(template.version, 1);
+    assert_eq!(template.wasm_hash, new_hash);
+    assert_eq!(client.get_template_id(&name, &1), 2);
+    assert_eq!(client.get_templates().len(), 2);
+
+    // Each name and version pair is registered once
+    env.mock_all_auths();
+    assert_eq!(
+        client.try_add_template(&name, &1, &new_hash),
+        Err(Ok(Error::TemplateExists))
+    );
+    assert_eq!(client.add_template(&name, &2, &new_hash), 3);
+    assert_eq!(
+        client.try_get_template(&4),
+        Err(Ok(Error::TemplateNotFound))
+    );
+    assert_eq!(
+        client.try_get_template_id(&name, &3),
+        Err(Ok(Error::TemplateNotFound))
+    );
+}
+
+#[test]
+fn test_set_wasm_hash() {
+    let env = Env::default();
+    let (client, payment_token, creator) = setup_test(&env);
+    let admin = client.get_admin();
+    let attacker = Address::generate(&env);
+    let wasm_hash = client.get_wasm_hash();
+    assert_eq!(wasm_hash, client.get_template(&1).wasm_hash);
+    assert_eq!(client.get_default_template(), 1);
+
+    mock_auth_for(
+        &env,
+        &client,
+        &attacker,
+        "set_wasm_hash",
+        (wasm_hash.clone(),).into_val(&env),
+    );
+    assert!(client.try_set_wasm_hash(&wasm_hash).is_err());
+    assert_eq!(client.get_default_template(), 1);
+
+    // The hash becomes the next "standard" version and the new default
+    env.mock_all_auths();
+    client.add_template(&String::from_str(&env, "standard"), &2, &wasm_hash);
+    mock_auth_for(
+        &env,
+        &client,
+        &admin,
+        "set_wasm_hash",
+        (wasm_hash.clone(),).into_val(&env),
+    );
+    client.set_wasm_hash(&wasm_hash);
+    assert_eq!(client.get_default_template(), 3);
+    assert_eq!(client.get_template(&3).version, 3);
+    assert_eq!(
+        client.get_template_id(&String::from_str(&env, "standard"), &3),
+        3
+    );
+
+    // Template 0 deploys from the default template
+    env.mock_all_auths();
+    client.create_event(
+        &BytesN::from_array(&env, &[1; 32]),
+        &creator,
+        &0,
+        &event_params(&env, &payment_token, 100, 10_000_000, 250),
+    );
+    assert_eq!(client.get_event(&1).template_id, 3);
+    assert_eq!(client.get_event(&1).template_version, 3);
+}
+
+#[test]
+fn test_create_event_from_template() {
+    let env = Env::default();
+    let (client, payment_token, creator) = setup_test(&env);
+
+    let wasm_hash = client.get_template(&1).wasm_hash;
+    let template_id = client.add_template(&String::from_str(&env, "standard"), &2, &wasm_hash);
+
+    let params = event_params(&env, &payment_token, 100, 10_000_000, 250);
+    assert_eq!(
+        client.try_create_event(&BytesN::from_array(&env, &[1; 32]), &creator, &9, &params),
+        Err(Ok(Error::TemplateNotFound))
+    );
+
+    let event_address = client.create_event(
+        &BytesN::from_array(&env, &[1; 32]),
+        &creator,
+        &template_id,
+        &params,
+    );
+    assert_eq!(
+        nft::Client::new(&env, &event_address)
+            .get_event_info()
+            .total_supply,
+        100
+    );
+
+    // The record keeps the template the event was deployed from
+    let record = client.get_event(&1);
+    assert_eq!(record.template_id, template_id);
+    assert_eq!(record.template_version, 2);
+}
+
+#[test]
+fn test_non_admin_rejected() {
+    let env = Env::default();
+    let (client, _, _) = setup_test(&env);
+    let attacker = Address::generate(&env);
+    let protocol_fee = ProtocolFee {
+        primary_bps: 10_000,
+        secondary_bps: 0,
+        treasury: attacker.clone(),
+    };
+
+    mock_auth_for(
+        &env,
+        &client,
+        &attacker,
+        "set_protocol_fee",
+        (protocol_fee.clone(),).into_val(&env),
+    );
+    assert!(client.try_set_protocol_fee(&protocol_fee).is_err());
+
+    mock_auth_for(
+        &env,
+        &client,
+        &attacker,
+        "transfer_admin",
+        (attacker.clone(),).into_val(&env),
+    );
+    assert!(client.try_transfer_admin(&attacker).is_err());
+    assert_eq!(client.get_pending_admin(), None);
+}
+
+#[test]
+fn test_transfer_admin() {
+    let env = Env::default();
+    let (client, _, _) = setup_test(&env);
+    let admin = client.get_admin();
+    let new_admin = Address::generate(&env);
+    let attacker = Address::generate(&env);
+
+    assert_eq!(client.try_accept_admin(), Err(Ok(Error::NoPendingAdmin)));
+
+    client.transfer_admin(&new_admin);
+    assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+    // Nothing changes until the new admin accepts
+    assert_eq!(client.get_admin(), admin);
+
+    mock_auth_for(&env, &client, &attacker, "accept_admin", ().into_val(&env));
+    assert!(client.try_accept_admin().is_err());
+
+    mock_auth_for(&env, &client, &new_admin, "accept_admin", ().into_val(&env));
+    client.accept_admin();
+    assert_eq!(client.get_admin(), new_admin);
+    assert_eq!(client.get_pending_admin(), None);
+
+    // The old admin has lost its rights
+    let name = String::from_str(&env, "soulbound");
+    let new_hash = env.deployer().upload_contract_wasm(ADD_WASM);
+    mock_auth_for(
+        &env,
+        &client,
+        &admin,
+        "add_template",
+        (name.clone(), 1u32, new_hash.clone()).into_val(&env),
+    );
+    assert!(client.try_add_template(&name, &1, &new_hash).is_err());
+}
+
+// === Upgrades ===
+
+#[test]
+fn test_upgrade_events() {
+    let env = Env::default();
+    let (client, payment_token, creator) = setup_test(&env);
+
+    let first = try_create_event(
+        &env,
+        &client,
+        1,
+        &creator,
+        &payment_token,
+        100,
+        10_000_000,
+        250,
+    )
+    .unwrap();
+    let second = try_create_event(
+        &env,
+        &client,
+        2,
+        &creator,
+        &payment_token,
+        100,
+        10_000_000,
+        250,
+    )
+    .unwrap();
+    assert_eq!(nft::Client::new(&env, &first).version(), 5);
+    assert_eq!(
+        nft::Client::new(&env, &first).get_upgrade_authority(),
+        nft::UpgradeAuthority::Factory
+    );
+
+    let new_hash = env.deployer().upload_contract_wasm(ADD_WASM);
+    let template_id = client.add_template(&String::from_str(&env, "standard"), &2, &new_hash);
+    assert_eq!(
+        client.try_upgrade_events(&vec![&env, 1], &9),
+        Err(Ok(Error::TemplateNotFound))
+    );
+    assert_eq!(
+        client.try_upgrade_events(&vec![&env, 1, 3], &template_id),
+        Err(Ok(Error::EventNotFound))
+    );
+
+    client.upgrade_events(&vec![&env, 1, 2], &template_id);
+
+    // Both events now run the new code, and their records follow
+    assert_eq!(add::Client::new(&env, &first).add(&2, &3), 5);
+    assert_eq!(add::Client::new(&env, &second).add(&2, &3), 5);
+    assert_eq!(client.get_event(&1).template_id, template_id);
+    assert_eq!(client.get_event(&2).template_version, 2);
+}
+
+#[test]
+fn test_upgrade_events_respects_creator_authority() {
+    let env = Env::default();
+    let (client, payment_token, creator) = setup_test(&env);
+
+    let event_address = try_create_event(
+        &env,
+        &client,
+        1,
+        &creator,
+        &payment_token,
+        100,
+        10_000_000,
+        250,
+    )
+    .unwrap();
+    let event = nft::Client::new(&env, &event_address);
+    let admin = client.get_admin();
+    let attacker = Address::generate(&env);
+    let args: Vec<Val> = (1u32, UpgradeAuthority::Creator).into_val(&env);
+
+    // Nobody but the factory can move upgrades away from it
+    mock_auth_for_contract(
+        &env,
+        &event_address,
+        &creator,
+        "set_upgrade_authority",
+        (nft::UpgradeAuthority::Creator,).into_val(&env),
+    );
+    assert!(event
+        .try_set_upgrade_authority(&nft::UpgradeAuthority::Creator)
+        .is_err());
+    mock_auth_for(
+        &env,
+        &client,
+        &attacker,
+        "set_event_upgrade_authority",
+        args.clone(),
+    );
+    assert!(client
+        .try_set_event_upgrade_authority(&1, &UpgradeAuthority::Creator)
+        .is_err());
+
+    // The creator takes over upgrades, with the factory admin's consent
+    mock_auth_for(
+        &env,
+        &client,
+        &admin,
+        "set_event_upgrade_authority",
+        args.clone(),
+    );
+    client.set_event_upgrade_authority(&1, &UpgradeAuthority::Creator);
+    assert_eq!(
+        event.get_upgrade_authority(),
+        nft::UpgradeAuthority::Creator
+    );
+    mock_auth_for(&env, &client, &admin, "set_event_upgrade_authority", args);
+    assert_eq!(
+        client.try_set_event_upgrade_authority(&1, &UpgradeAuthority::Creator),
+        Err(Ok(Error::EventUpgradeFailed))
+    );
+    env.mock_all_auths();
+
+    let new_hash = env.deployer().upload_contract_wasm(ADD_WASM);
+    let template_id = client.add_template(&String::from_str(&env, "standard"), &2, &new_hash);
+    assert_eq!(
+        client.try_upgrade_events(&vec![&env, 1], &template_id),
+        Err(Ok(Error::EventUpgradeFailed))
+    );
+    assert_eq!(client.get_event(&1).template_id, 1);
+
+    mock_auth_for_contract(
+        &env,
+        &event_address,
+        &creator,
+        "upgrade",
+        (new_hash.clone(),).into_val(&env),
+    );
+    event.upgrade(&new_hash);
+    assert_eq!(add::Client::new(&env, &event_address).add(&2, &3), 5);
+}
+
+#[test]
+fn test_upgrade_events_admin_only() {
+    let env = Env::default();
+    let (client, payment_token, creator) = setup_test(&env);
+    let attacker = Address::generate(&env);
+
+    try_create_event(
+        &env,
+        &client,
+        1,
+        &creator,
+        &payment_token,
+        100,
+        10_000_000,
+        250,
+    )
+    .unwrap();
+
+    let new_hash = env.deployer().upload_contract_wasm(ADD_WASM);
+    let template_id = client.add_template(&String::from_str(&env, "standard"), &2, &new_hash);
+    let event_ids = vec![&env, 1u32];
+    mock_auth_for(
+        &env,
+        &client,
+        &attacker,
+        "upgrade_events",
+        (event_ids.clone(), template_id).into_val(&env),
+    );
+    assert!(client.try_upgrade_events(&event_ids, &template_id).is_err());
+}
+
+#[test]
+fn test_create_event_validation() {
+    let env = Env::default();
+    let (client, payment_token, creator) = setup_test(&env);
+
+    assert_eq!(
+        try_create_event(
+            &env,
+            &client,
+            1,
+            &creator,
+            &payment_token,
+            0,
+            10_000_000,
+            250
+        ),
+        Err(Error::InvalidSupply)
+    );
+    assert_eq!(
+        try_create_event(&env, &client, 1, &creator, &payment_token, 100, 0, 250),
+        Err(Error::InvalidPrice)
+    );
+    assert_eq!(
+        try_create_event(
+            &env,
+            &client,
+            1,
+            &creator,
+            &payment_token,
+            100,
+            10_000_000,
+            10_001
+        ),
+        Err(Error::InvalidFeeBps)
+    );
+
+    // The sale has to close by the end of the event
+    let mut params = event_params(&env, &payment_token, 100, 10_000_000, 250);
+    params.schedule.sale_end = params.schedule.event_end + 1;
+    assert_eq!(
+        client.try_create_event(&BytesN::from_array(&env, &[1; 32]), &creator, &1, &params),
+        Err(Ok(Error::InvalidSchedule))
+    );
+    assert_eq!(client.get_event_count(), 0);
+}
+
+#[test]
+fn test_create_event_child_init_failed() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // A template without an `init` entry point rejects the factory's call
+    let wasm_hash = env.deployer().upload_contract_wasm(ADD_WASM);
+    let contract_id = env.register(TicketFactory, (Address::generate(&env), wasm_hash));
+    let client = TicketFactoryClient::new(&env, &contract_id);
+
+    let creator = Address::generate(&env);
+    let payment_token = Address::generate(&env);
+
+    assert_eq!(
+        try_create_event(
+            &env,
+            &client,
+            1,
+            &creator,
+            &payment_token,
+            100,
+            10_000_000,
+            250
+        ),
+        Err(Error::EventInitFailed)
+    );
+    assert_eq!(client.get_event_count(), 0);
+}
+
+#[test]
+fn test_get_event_not_found() {
+    let env = Env::default();
+    let (client, _, _) = setup_test(&env);
+
+    assert_eq!(client.try_get_event(&1), Err(Ok(Error::EventNotFound)));
+}
+
+#[test]
+fn test_extend_event_ttl() {
+    let env = Env::default();
+    let (client, payment_token, creator) = setup_test(&env);
+
+    try_create_event(
+        &env,
+        &client,
+        1,
+        &creator,
+        &payment_token,
+        100,
+        10_000_000,
+        250,
+    )
+    .unwrap();
+
+    let ttl = |key: DataKey| {
+        env.as_contract(&client.address, || env.storage().persistent().get_ttl(&key))
+    };
+    let record_ttl = || ttl(DataKey::EventRecord(1));
+    let creator_ttl = || ttl(DataKey::CreatorEvents(creator.clone()));
+
+    // Records live until a month after the event ends
+    let event_end = 31 * DAY_SECONDS + DAY_SECONDS / 4;
+    let until_end = (event_end / LEDGER_CLOSE_SECONDS) as u32;
+    assert_eq!(record_ttl(), until_end + TTL_EXTENSION);
+    assert_eq!(creator_ttl(), until_end + TTL_EXTENSION);
+
+    // Reads leave the TTL alone
+    client.get_event(&1);
+    client.get_all_events();
+    assert_eq!(record_ttl(), until_end + TTL_EXTENSION);
+
+    // Three weeks after the event the record has aged but is still live
+    let elapsed = event_end + 21 * DAY_SECONDS;
+    env.ledger().with_mut(|li| {
+        li.timestamp = elapsed;
+        li.sequence_number += (elapsed / LEDGER_CLOSE_SECONDS) as u32;
+    });
+    assert_eq!(record_ttl(), TTL_EXTENSION - 21 * DAY_IN_LEDGERS);
+
+    client.extend_event_ttl(&1);
+    assert_eq!(record_ttl(), TTL_EXTENSION);
+    assert_eq!(creator_ttl(), TTL_EXTENSION);
+    assert_eq!(
+        env.as_contract(&client.address, || env.storage().instance().get_ttl()),
+        TTL_EXTENSION
+    );
+
+    assert_eq!(
+        client.try_extend_event_ttl(&2),
+        Err(Ok(Error::EventNotFound))
+    );
+}
+
+#[test]
+fn test_create_event_emits_event_created() {
+    let env = Env::default();
+    let (client, payment_token, creator) = setup_test(&env);
+
+    let event_address = try_create_event(
+        &env,
+        &client,
+        1,
+        &creator,
+        &payment_token,
+        100,
+        10_000_000,
+        250,
+    )
+    .unwrap();
 
-    let words = client.hello(&String::from_str(&env, "Dev"));
+    let expected = EventCreated {
+        event_id: 1,
+        event_creator: creator,
+        event_contract: event_address,
+        name: String::from_str(&env, "Event Tickets"),
+        symbol: String::from_str(&env, "EVNT"),
+    };
+    let mut factory_events = Vec::new(&env);
+    for event in env.events().all().iter() {
+        if event.0 == client.address {
+            factory_events.push_back(event);
+        }
+    }
     assert_eq!(
-        words,
+        factory_events,
         vec![
             &env,
-            String::from_str(&env, "Hello"),
-            String::from_str(&env, "Dev"),
+            (
+                client.address.clone(),
+                expected.topics(&env),
+                expected.data(&env)
+            )
         ]
     );
 }
@@ -1,15 +1,96 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, contracttype, token, Address, Env, String, Vec};
+#![allow(clippy::too_many_arguments)]
+use soroban_sdk::{
+    contract, contracterror, contractevent, contractimpl, contracttype, token, xdr::ToXdr, Address,
+    Bytes, BytesN, Env, Map, String, Symbol, TryFromVal, Val, Vec,
+};
+
+/// Version of the ticket contract interface, bumped whenever entry points or
+/// stored types change so clients know which bindings to use
+pub const CONTRACT_VERSION: u32 = 5;
+
+pub(crate) const DAY_IN_LEDGERS: u32 = 17280;
+pub(crate) const LEDGER_CLOSE_SECONDS: u64 = 5;
+pub(crate) const DEFAULT_MIN_TTL_EXTENSION: u32 = 30 * DAY_IN_LEDGERS;
+/// Longest base URI accepted, leaving room for the ticket id appended to it
+pub(crate) const MAX_BASE_URI_LEN: u32 = 236;
+/// Upper bound on tiers per event, since they all live in instance storage
+pub(crate) const MAX_TIERS: u32 = 16;
+/// Upper bound on royalty recipients paid on each secondary sale
+pub(crate) const MAX_ROYALTY_SPLITS: u32 = 10;
+/// A bid in the last minutes of an auction extends it by this long
+pub const AUCTION_EXTENSION_SECONDS: u64 = 600;
+/// How much each auction bid must beat the previous one by
+pub const MIN_BID_INCREMENT_BPS: u32 = 500;
+/// Upper bound on open offers per ticket, since their buyers share one entry
+pub const MAX_OFFERS_PER_TICKET: u32 = 20;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    InvalidSupply = 3,
+    InvalidPrice = 4,
+    InvalidFeeBps = 5,
+    SoldOut = 6,
+    TicketNotFound = 7,
+    NotOwner = 8,
+    TicketUsed = 9,
+    AlreadyListed = 10,
+    NotListed = 11,
+    NotSeller = 12,
+    NotCreator = 13,
+    InvalidTtlPolicy = 14,
+    NotApproved = 15,
+    MetadataFrozen = 16,
+    InvalidUri = 17,
+    TierNotFound = 18,
+    TooManyTiers = 19,
+    InvalidQuantity = 20,
+    WalletLimitReached = 21,
+    InvalidSchedule = 22,
+    SaleNotStarted = 23,
+    SaleEnded = 24,
+    InvalidPresale = 25,
+    PresaleNotActive = 26,
+    NotOnAllowlist = 27,
+    PriceAboveCap = 28,
+    PriceBelowFloor = 29,
+    InvalidResalePolicy = 30,
+    InvalidRoyaltySplits = 31,
+    FactoryAlreadySet = 32,
+    NoFactory = 33,
+    TicketsAlreadySold = 34,
+    EventCancelled = 35,
+    EventNotCancelled = 36,
+    NotRefundable = 37,
+    EventEnded = 38,
+    ProceedsLocked = 39,
+    NoProceeds = 40,
+    InvalidExpiry = 41,
+    OfferExists = 42,
+    OfferNotFound = 43,
+    OfferExpired = 44,
+    BidNotFound = 45,
+    TierMismatch = 46,
+    ListingExpired = 47,
+    AuctionNotFound = 48,
+    AuctionNotEnded = 49,
+    BidTooLow = 50,
+}
 
-#[derive(Clone)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
 pub struct TicketData {
     pub owner: Address,
     pub ticket_id: u32,
+    pub tier_id: u32,
     pub is_used: bool,
 }
 
-#[derive(Clone)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
 pub struct EventInfo {
     pub event_creator: Address,
@@ -20,24 +101,482 @@ pub struct EventInfo {
     pub payment_token: Address,
     pub name: String,
     pub symbol: String,
+    pub schedule: EventSchedule,
+    pub royalty_splits: Vec<RoyaltySplit>,
+    pub royalty_remainder_recipient: Address,
+}
+
+/// The platform's cut of sales, set by the deploying factory. `primary_bps`
+/// is taken from primary sales and `secondary_bps` from resales, on top of
+/// the creator fee, and both are paid to `treasury`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ProtocolFee {
+    pub primary_bps: u32,
+    pub secondary_bps: u32,
+    pub treasury: Address,
+}
+
+/// Who may upgrade the contract code: the deploying factory (on behalf of its
+/// admin) or the event creator
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum UpgradeAuthority {
+    Factory,
+    Creator,
+}
+
+/// A share of the creator fee on secondary sales, in basis points of the fee.
+/// Whatever the splits leave over, including rounding dust, goes to
+/// `EventInfo.royalty_remainder_recipient`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct RoyaltySplit {
+    pub recipient: Address,
+    pub bps: u32,
+}
+
+/// Ledger timestamps (seconds) bounding the primary sale and the event
+/// itself. Tickets can be minted from `sale_start` until just before
+/// `sale_end`, and the sale must close by the end of the event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct EventSchedule {
+    pub sale_start: u64,
+    pub sale_end: u64,
+    pub event_start: u64,
+    pub event_end: u64,
+}
+
+/// An allowlist sale of one tier that runs from `start` until the public
+/// sale opens. Eligible addresses prove membership with a Merkle proof
+/// against `merkle_root`, whose leaves are the SHA-256 of each address's XDR
+/// and whose pairs are hashed in sorted order.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PresaleConfig {
+    pub merkle_root: BytesN<32>,
+    pub tier_id: u32,
+    pub start: u64,
+    pub price: i128,
+    pub max_per_wallet: u32,
+}
+
+/// A tier as configured at deployment. `max_per_wallet` caps how many
+/// tickets of the tier one address may buy on the primary market (0 for no cap).
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TierConfig {
+    pub name: String,
+    pub supply: u32,
+    pub price: i128,
+    pub creator_fee_bps: u32,
+    pub max_per_wallet: u32,
+}
+
+/// A tier and its primary sales so far. Tier ids are positions in the list
+/// passed to `init`, starting at 0.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Tier {
+    pub tier_id: u32,
+    pub name: String,
+    pub supply: u32,
+    pub price: i128,
+    pub creator_fee_bps: u32,
+    pub max_per_wallet: u32,
+    pub minted: u32,
+}
+
+/// A descending-price primary sale of one tier. From `sale_start` the price
+/// drops by `price_drop` every `drop_interval` seconds until it reaches
+/// `floor_price`. With `rebate`, buyers get back what they paid above the
+/// clearing price, the price of the last ticket sold.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct DutchAuction {
+    pub tier_id: u32,
+    pub start_price: i128,
+    pub floor_price: i128,
+    pub price_drop: i128,
+    pub drop_interval: u64,
+    pub rebate: bool,
 }
 
-#[derive(Clone)]
+/// Limits on secondary listing prices. `max_price` is an absolute cap and
+/// `max_markup_bps` a cap relative to the ticket's tier price (10000 is face
+/// value); when both are set the lower one applies. `min_price` is a floor.
+/// Zero disables a limit.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ResalePolicy {
+    pub max_price: i128,
+    pub max_markup_bps: u32,
+    pub min_price: i128,
+}
+
+/// A ticket for sale on the secondary market. Listings lapse at `expires_at`
+/// (a ledger timestamp), if set, and in any case once the event starts.
+#[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
 pub struct SecondaryListing {
     pub ticket_id: u32,
     pub seller: Address,
     pub price: i128,
+    pub expires_at: Option<u64>,
+}
+
+/// A buyer's standing bid for up to `quantity` more tickets of the event, or
+/// of one tier, at `price` each. The price of every unfilled ticket is held
+/// in escrow by the contract.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct CollectionBid {
+    pub bid_id: u32,
+    pub buyer: Address,
+    pub tier_id: Option<u32>,
+    pub price: i128,
+    pub quantity: u32,
+}
+
+/// An English auction of a ticket. The highest bid is held in escrow by the
+/// contract, and bids must reach `reserve` before `end_time`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Auction {
+    pub ticket_id: u32,
+    pub seller: Address,
+    pub reserve: i128,
+    pub end_time: u64,
+    pub highest_bidder: Option<Address>,
+    pub highest_bid: i128,
+}
+
+/// A buyer's offer for a ticket, with the price held in escrow by the
+/// contract. It can be accepted by whoever owns the ticket until `expiry`
+/// (a ledger timestamp).
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TicketOffer {
+    pub ticket_id: u32,
+    pub buyer: Address,
+    pub price: i128,
+    pub expiry: u64,
+}
+
+/// Emitted when a ticket is bought on the primary market
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Mint {
+    #[topic]
+    pub ticket_id: u32,
+    #[topic]
+    pub buyer: Address,
+    pub tier_id: u32,
+    pub price: i128,
+}
+
+/// Emitted when a ticket is listed on the secondary market
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct List {
+    #[topic]
+    pub ticket_id: u32,
+    #[topic]
+    pub seller: Address,
+    pub price: i128,
+    pub expires_at: Option<u64>,
+}
+
+/// Emitted when a listing is withdrawn, either by the seller or at check-in
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Delist {
+    #[topic]
+    pub ticket_id: u32,
+    #[topic]
+    pub seller: Address,
+}
+
+/// Emitted when a seller changes the price of a listing
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceUpdate {
+    #[topic]
+    pub ticket_id: u32,
+    #[topic]
+    pub seller: Address,
+    pub old_price: i128,
+    pub new_price: i128,
+}
+
+/// Emitted when a listed ticket is bought on the secondary market
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Sale {
+    #[topic]
+    pub ticket_id: u32,
+    #[topic]
+    pub seller: Address,
+    #[topic]
+    pub buyer: Address,
+    pub price: i128,
+    pub creator_fee: i128,
+    pub protocol_fee: i128,
+}
+
+/// Emitted when a buyer makes an offer for a ticket
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Offer {
+    #[topic]
+    pub ticket_id: u32,
+    #[topic]
+    pub buyer: Address,
+    pub price: i128,
+    pub expiry: u64,
+}
+
+/// Emitted when a buyer withdraws an offer and gets the escrow back
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OfferCancel {
+    #[topic]
+    pub ticket_id: u32,
+    #[topic]
+    pub buyer: Address,
+}
+
+/// Emitted when a buyer places a bid for any ticket of the event or a tier
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CollectionBidPlace {
+    #[topic]
+    pub bid_id: u32,
+    #[topic]
+    pub buyer: Address,
+    pub tier_id: Option<u32>,
+    pub price: i128,
+    pub quantity: u32,
+}
+
+/// Emitted when a buyer withdraws a collection bid and gets the rest of the
+/// escrow back
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CollectionBidCancel {
+    #[topic]
+    pub bid_id: u32,
+    #[topic]
+    pub buyer: Address,
+    pub refund: i128,
+}
+
+/// Emitted when an owner puts a ticket up for auction
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuctionStart {
+    #[topic]
+    pub ticket_id: u32,
+    #[topic]
+    pub seller: Address,
+    pub reserve: i128,
+    pub end_time: u64,
+}
+
+/// Emitted when a bid becomes the highest bid of an auction
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuctionBid {
+    #[topic]
+    pub ticket_id: u32,
+    #[topic]
+    pub bidder: Address,
+    pub amount: i128,
+    pub end_time: u64,
+}
+
+/// Emitted when an auction closes without a sale
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuctionCancel {
+    #[topic]
+    pub ticket_id: u32,
+    #[topic]
+    pub seller: Address,
+}
+
+/// Emitted when a ticket is transferred peer to peer
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Transfer {
+    #[topic]
+    pub ticket_id: u32,
+    #[topic]
+    pub from: Address,
+    #[topic]
+    pub to: Address,
+}
+
+/// Emitted when an owner approves (or clears) a single-ticket spender
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Approve {
+    #[topic]
+    pub ticket_id: u32,
+    #[topic]
+    pub owner: Address,
+    pub approved: Option<Address>,
+}
+
+/// Emitted when an owner grants or revokes an operator for all their tickets
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApproveForAll {
+    #[topic]
+    pub owner: Address,
+    #[topic]
+    pub operator: Address,
+    pub approved: bool,
+}
+
+/// Emitted when the creator changes the base URI shared by all tickets
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BaseUriUpdate {
+    pub base_uri: String,
+}
+
+/// Emitted when the creator sets or clears a ticket's own metadata URI
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketUriUpdate {
+    #[topic]
+    pub ticket_id: u32,
+    pub uri: Option<String>,
+}
+
+/// Emitted when the creator permanently freezes ticket metadata
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MetadataFreeze {
+    #[topic]
+    pub creator: Address,
+}
+
+/// Emitted when the contract code is replaced
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Upgrade {
+    pub new_wasm_hash: BytesN<32>,
+}
+
+/// Emitted when the event creator cancels the event
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Cancel {
+    #[topic]
+    pub creator: Address,
+}
+
+/// Emitted when a holder of a cancelled event gets their payment back
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Refund {
+    #[topic]
+    pub ticket_id: u32,
+    #[topic]
+    pub owner: Address,
+    pub amount: i128,
+}
+
+/// Emitted when a Dutch auction buyer gets back what they paid above the
+/// clearing price
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Rebate {
+    #[topic]
+    pub ticket_id: u32,
+    #[topic]
+    pub buyer: Address,
+    pub amount: i128,
+}
+
+/// Emitted when the event creator withdraws escrowed primary sale proceeds
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Withdraw {
+    #[topic]
+    pub creator: Address,
+    pub amount: i128,
+    pub protocol_fee: i128,
+}
+
+/// Emitted when the event creator marks a ticket as used
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CheckIn {
+    #[topic]
+    pub ticket_id: u32,
+    #[topic]
+    pub owner: Address,
 }
 
-#[derive(Clone)]
+/// How long storage is kept alive whenever it is touched. Entries are extended
+/// by at least `min_extension` ledgers, and far enough to outlive
+/// `retain_until` (a ledger timestamp). Until the creator sets a policy,
+/// `retain_until` is the event's `event_end`.
+#[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
-pub enum DataKey {
+pub struct TtlPolicy {
+    pub min_extension: u32,
+    pub retain_until: u64,
+}
+
+/// Event-wide settings, counters, escrow and proceeds totals and Dutch
+/// auction state live in instance storage. Per-ticket and per-user entries
+/// are persistent, so the instance entry, which is loaded on every call,
+/// does not grow with the number of tickets sold.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub(crate) enum DataKey {
     EventInfo,
     Ticket(u32),
     TicketsMinted,
     SecondaryListing(u32),
     UserTickets(Address),
+    TtlPolicy,
+    Approval(u32),
+    ApprovalForAll(Address, Address),
+    BaseUri,
+    TicketUri(u32),
+    MetadataFrozen,
+    Tiers,
+    MaxPerWallet,
+    Purchased(Address),
+    TierPurchased(Address, u32),
+    Presale,
+    PresalePurchased(Address),
+    ResalePolicy,
+    Factory,
+    ProtocolFee,
+    UpgradeAuthority,
+    Escrow,
+    Cancelled,
+    EscrowedPayment(u32),
+    EscrowBalance,
+    ReleasedProceeds,
+    Offer(u32, Address),
+    TicketOffers(u32),
+    BidCounter,
+    CollectionBid(u32),
+    Auction(u32),
+    DutchAuction,
+    DutchLastPrice,
+    DutchBuyer(u32),
+    RebatePaid,
+    RebateTickets,
+    Claimable(Address),
 }
 
 #[contract]
@@ -45,7 +584,10 @@ pub struct TicketMarketplace;
 
 #[contractimpl]
 impl TicketMarketplace {
-    /// Initialize the ticket marketplace contract
+    /// Initialize the ticket marketplace contract. With an empty `tiers` list
+    /// the event has a single tier built from `total_supply`, `primary_price`
+    /// and `creator_fee_bps`; otherwise the tier supplies must add up to
+    /// `total_supply`.
     pub fn init(
         env: Env,
         event_creator: Address,
@@ -56,429 +598,2826 @@ impl TicketMarketplace {
         name: String,
         symbol: String,
         payment_token: Address,
-    ) {
+        tiers: Vec<TierConfig>,
+        schedule: EventSchedule,
+    ) -> Result<(), Error> {
         // Ensure not already initialized
         if env.storage().instance().has(&DataKey::EventInfo) {
-            panic!("Contract already initialized");
+            return Err(Error::AlreadyInitialized);
         }
 
         // Validate inputs
         if total_supply == 0 {
-            panic!("Total supply must be greater than 0");
+            return Err(Error::InvalidSupply);
         }
         if primary_price <= 0 {
-            panic!("Primary price must be positive");
+            return Err(Error::InvalidPrice);
         }
         if creator_fee_bps > 10000 {
-            panic!("Fee basis points cannot exceed 10000 (100%)");
+            return Err(Error::InvalidFeeBps);
         }
+        if schedule.sale_start >= schedule.sale_end
+            || schedule.event_start > schedule.event_end
+            || schedule.sale_end > schedule.event_end
+        {
+            return Err(Error::InvalidSchedule);
+        }
+
+        let tiers = if tiers.is_empty() {
+            Vec::from_array(
+                &env,
+                [TierConfig {
+                    name: String::from_str(&env, "General Admission"),
+                    supply: total_supply,
+                    price: primary_price,
+                    creator_fee_bps,
+                    max_per_wallet: 0,
+                }],
+            )
+        } else {
+            tiers
+        };
+        let tiers = Self::build_tiers(&env, total_supply, tiers)?;
 
         let event_info = EventInfo {
+            royalty_splits: Vec::new(&env),
+            royalty_remainder_recipient: event_creator.clone(),
             event_creator,
             total_supply,
             primary_price,
             creator_fee_bps,
             event_metadata,
             payment_token,
-            // --- NEW ASSIGNMENTS ---
             name,
             symbol,
+            schedule,
         };
 
         env.storage()
             .instance()
             .set(&DataKey::EventInfo, &event_info);
+        env.storage().instance().set(&DataKey::Tiers, &tiers);
         env.storage().instance().set(&DataKey::TicketsMinted, &0u32);
-    }
+        Self::extend_instance_ttl(&env);
 
-    /// Mint a ticket from primary marketplace (buy from event creator)
-    pub fn mint_ticket(env: Env, buyer: Address) -> u32 {
-        buyer.require_auth();
+        Ok(())
+    }
 
-        let event_info: EventInfo = env
-            .storage()
-            .instance()
-            .get(&DataKey::EventInfo)
-            .expect("Contract not initialized");
+    /// Record the factory that deployed this event and the protocol fee it
+    /// charges. Both the factory and the event creator must authorize this,
+    /// and it can only be done once, before any ticket is sold.
+    pub fn set_factory(env: Env, factory: Address, protocol_fee: ProtocolFee) -> Result<(), Error> {
+        factory.require_auth();
+        Self::extend_instance_ttl(&env);
 
-        let tickets_minted: u32 = env
-            .storage()
-            .instance()
-            .get(&DataKey::TicketsMinted)
-            .unwrap_or(0);
+        let event_info = Self::read_event_info(&env)?;
+        event_info.event_creator.require_auth();
 
-        // Check if sold out
-        if tickets_minted >= event_info.total_supply {
-            panic!("All tickets sold out");
+        if env.storage().instance().has(&DataKey::Factory) {
+            return Err(Error::FactoryAlreadySet);
+        }
+        if Self::read_tickets_minted(&env) > 0 {
+            return Err(Error::TicketsAlreadySold);
         }
 
-        // Transfer payment from buyer to event creator
-        let token_client = token::Client::new(&env, &event_info.payment_token);
-        token_client.transfer(&buyer, &event_info.event_creator, &event_info.primary_price);
-
-        // Create new ticket
-        let ticket_id = tickets_minted + 1;
-        let ticket = TicketData {
-            owner: buyer.clone(),
-            ticket_id,
-            is_used: false,
-        };
+        if protocol_fee.primary_bps > 10000 || protocol_fee.secondary_bps > 10000 {
+            return Err(Error::InvalidFeeBps);
+        }
+        for tier in Self::read_tiers(&env)?.iter() {
+            if tier.creator_fee_bps + protocol_fee.secondary_bps > 10000 {
+                return Err(Error::InvalidFeeBps);
+            }
+        }
 
-        // Store ticket
-        env.storage()
-            .instance()
-            .set(&DataKey::Ticket(ticket_id), &ticket);
+        env.storage().instance().set(&DataKey::Factory, &factory);
         env.storage()
             .instance()
-            .set(&DataKey::TicketsMinted, &ticket_id);
+            .set(&DataKey::ProtocolFee, &protocol_fee);
 
-        // Add to user's ticket list
-        Self::add_ticket_to_user(&env, &buyer, ticket_id);
+        Ok(())
+    }
+
+    /// Mint a ticket of the given tier from primary marketplace (buy from event creator)
+    pub fn mint_ticket(env: Env, buyer: Address, tier_id: u32) -> Result<u32, Error> {
+        buyer.require_auth();
+        Self::extend_instance_ttl(&env);
+
+        let event_info = Self::read_event_info(&env)?;
+        Self::require_public_sale(&env, &event_info)?;
 
-        ticket_id
+        let unit_price = Self::dutch_price(&env, &event_info, tier_id);
+        let ticket_id = Self::mint(&env, &event_info, &buyer, tier_id, 1, unit_price)?;
+        Self::record_dutch_sale(&env, &buyer, ticket_id, 1, unit_price);
+
+        Ok(ticket_id)
+    }
+
+    /// Mint several tickets of one tier in a single purchase. The buyer pays
+    /// once for the whole batch and receives consecutive ticket ids; if the
+    /// tier cannot cover the full quantity nothing is minted.
+    pub fn mint_tickets(
+        env: Env,
+        buyer: Address,
+        tier_id: u32,
+        quantity: u32,
+    ) -> Result<Vec<u32>, Error> {
+        buyer.require_auth();
+        Self::extend_instance_ttl(&env);
+
+        let event_info = Self::read_event_info(&env)?;
+        Self::require_public_sale(&env, &event_info)?;
+
+        let unit_price = Self::dutch_price(&env, &event_info, tier_id);
+        let first_id = Self::mint(&env, &event_info, &buyer, tier_id, quantity, unit_price)?;
+        Self::record_dutch_sale(&env, &buyer, first_id, quantity, unit_price);
+
+        Ok(Self::ticket_range(&env, first_id, quantity))
+    }
+
+    /// Mint presale tickets at the presale price. The buyer proves they are on
+    /// the allowlist with the sibling hashes from their leaf up to the root.
+    pub fn mint_presale(
+        env: Env,
+        buyer: Address,
+        quantity: u32,
+        proof: Vec<BytesN<32>>,
+    ) -> Result<Vec<u32>, Error> {
+        buyer.require_auth();
+        Self::extend_instance_ttl(&env);
+
+        let event_info = Self::read_event_info(&env)?;
+        let presale = Self::read_presale(&env).ok_or(Error::PresaleNotActive)?;
+
+        let now = env.ledger().timestamp();
+        if now < presale.start || now >= event_info.schedule.sale_start {
+            return Err(Error::PresaleNotActive);
+        }
+
+        let leaf = env.crypto().sha256(&buyer.clone().to_xdr(&env)).to_bytes();
+        if !Self::verify_proof(&env, &presale.merkle_root, leaf, &proof) {
+            return Err(Error::NotOnAllowlist);
+        }
+
+        // The presale cap applies on top of the wallet caps checked when minting
+        let key = DataKey::PresalePurchased(buyer.clone());
+        let purchased = Self::read_purchased(&env, &key);
+        if purchased.saturating_add(quantity) > presale.max_per_wallet {
+            return Err(Error::WalletLimitReached);
+        }
+
+        let first_id = Self::mint(
+            &env,
+            &event_info,
+            &buyer,
+            presale.tier_id,
+            quantity,
+            Some(presale.price),
+        )?;
+        Self::write_purchased(&env, &key, purchased + quantity);
+
+        Ok(Self::ticket_range(&env, first_id, quantity))
     }
 
-    /// List ticket on secondary marketplace
-    pub fn list_ticket(env: Env, seller: Address, ticket_id: u32, price: i128) {
+    /// List ticket on secondary marketplace, optionally until `expires_at`.
+    /// A lapsed listing of the ticket is replaced.
+    pub fn list_ticket(
+        env: Env,
+        seller: Address,
+        ticket_id: u32,
+        price: i128,
+        expires_at: Option<u64>,
+    ) -> Result<(), Error> {
         seller.require_auth();
+        Self::extend_instance_ttl(&env);
+
+        Self::require_not_cancelled(&env)?;
 
         if price <= 0 {
-            panic!("Price must be positive");
+            return Err(Error::InvalidPrice);
         }
 
         // Get ticket and verify ownership
-        let ticket: TicketData = env
-            .storage()
-            .instance()
-            .get(&DataKey::Ticket(ticket_id))
-            .expect("Ticket does not exist");
+        let ticket = Self::read_ticket(&env, ticket_id)?;
 
         if ticket.owner != seller {
-            panic!("Only ticket owner can list");
+            return Err(Error::NotOwner);
         }
 
         if ticket.is_used {
-            panic!("Cannot list used ticket");
+            return Err(Error::TicketUsed);
         }
 
-        // Check if already listed
-        if env
-            .storage()
-            .instance()
-            .has(&DataKey::SecondaryListing(ticket_id))
-        {
-            panic!("Ticket already listed");
+        Self::check_resale_price(&env, &ticket, price)?;
+
+        // Listings are only open until the event starts
+        let now = env.ledger().timestamp();
+        if now >= Self::read_event_info(&env)?.schedule.event_start {
+            return Err(Error::ListingExpired);
+        }
+        if expires_at.is_some_and(|expires_at| expires_at <= now) {
+            return Err(Error::InvalidExpiry);
+        }
+
+        // Check if already listed or auctioned
+        if Self::is_for_sale(&env, ticket_id)? {
+            return Err(Error::AlreadyListed);
         }
 
         let listing = SecondaryListing {
             ticket_id,
-            seller,
+            seller: seller.clone(),
             price,
+            expires_at,
         };
 
-        env.storage()
-            .instance()
-            .set(&DataKey::SecondaryListing(ticket_id), &listing);
+        Self::write_listing(&env, &listing);
+
+        List {
+            ticket_id,
+            seller,
+            price,
+            expires_at,
+        }
+        .publish(&env);
+
+        Ok(())
     }
 
     /// Buy ticket from secondary marketplace
-    pub fn buy_secondary_ticket(env: Env, buyer: Address, ticket_id: u32) {
+    pub fn buy_secondary_ticket(env: Env, buyer: Address, ticket_id: u32) -> Result<(), Error> {
         buyer.require_auth();
+        Self::extend_instance_ttl(&env);
 
-        let listing: SecondaryListing = env
-            .storage()
-            .instance()
-            .get(&DataKey::SecondaryListing(ticket_id))
-            .expect("Ticket not listed for sale");
+        Self::require_not_cancelled(&env)?;
 
-        let mut ticket: TicketData = env
-            .storage()
-            .instance()
-            .get(&DataKey::Ticket(ticket_id))
-            .expect("Ticket does not exist");
+        let listing = Self::read_listing(&env, ticket_id)?;
+        let ticket = Self::read_ticket(&env, ticket_id)?;
 
         if ticket.is_used {
-            panic!("Cannot buy used ticket");
+            return Err(Error::TicketUsed);
         }
 
-        let event_info: EventInfo = env
-            .storage()
-            .instance()
-            .get(&DataKey::EventInfo)
-            .expect("Contract not initialized");
-
-        // Calculate creator fee
-        let creator_fee = (listing.price * event_info.creator_fee_bps as i128) / 10000;
-        let seller_amount = listing.price - creator_fee;
+        let event_info = Self::read_event_info(&env)?;
 
-        let token_client = token::Client::new(&env, &event_info.payment_token);
-
-        // Transfer creator fee
-        if creator_fee > 0 {
-            token_client.transfer(&buyer, &event_info.event_creator, &creator_fee);
+        if !Self::is_listing_active(&env, &event_info, &listing) {
+            return Err(Error::ListingExpired);
         }
 
-        // Transfer payment to seller
-        token_client.transfer(&buyer, &listing.seller, &seller_amount);
+        let (creator_fee, protocol_fee) = Self::pay_secondary_sale(
+            &env,
+            &event_info,
+            &buyer,
+            Some(&listing.seller),
+            &ticket,
+            listing.price,
+        )?;
 
         // Update ticket ownership
-        Self::remove_ticket_from_user(&env, &ticket.owner, ticket_id);
-        ticket.owner = buyer.clone();
-        env.storage()
-            .instance()
-            .set(&DataKey::Ticket(ticket_id), &ticket);
-        Self::add_ticket_to_user(&env, &buyer, ticket_id);
+        Self::move_ticket(&env, &ticket, &buyer);
 
         // Remove listing
-        env.storage()
-            .instance()
-            .remove(&DataKey::SecondaryListing(ticket_id));
+        Self::remove_listing(&env, ticket_id);
+
+        Sale {
+            ticket_id,
+            seller: listing.seller,
+            buyer,
+            price: listing.price,
+            creator_fee,
+            protocol_fee,
+        }
+        .publish(&env);
+
+        Ok(())
     }
 
     /// Delist ticket from secondary marketplace
-    pub fn delist_ticket(env: Env, seller: Address, ticket_id: u32) {
+    pub fn delist_ticket(env: Env, seller: Address, ticket_id: u32) -> Result<(), Error> {
         seller.require_auth();
+        Self::extend_instance_ttl(&env);
 
-        let listing: SecondaryListing = env
-            .storage()
-            .instance()
-            .get(&DataKey::SecondaryListing(ticket_id))
-            .expect("Ticket not listed");
+        let listing = Self::read_listing(&env, ticket_id)?;
 
         if listing.seller != seller {
-            panic!("Only the seller can delist");
+            return Err(Error::NotSeller);
         }
 
-        env.storage()
-            .instance()
-            .remove(&DataKey::SecondaryListing(ticket_id));
+        Self::remove_listing(&env, ticket_id);
+
+        Delist { ticket_id, seller }.publish(&env);
+
+        Ok(())
     }
 
     /// Update listing price
-    pub fn update_listing_price(env: Env, seller: Address, ticket_id: u32, new_price: i128) {
+    pub fn update_listing_price(
+        env: Env,
+        seller: Address,
+        ticket_id: u32,
+        new_price: i128,
+    ) -> Result<(), Error> {
         seller.require_auth();
+        Self::extend_instance_ttl(&env);
+
+        Self::require_not_cancelled(&env)?;
 
         if new_price <= 0 {
-            panic!("Price must be positive");
+            return Err(Error::InvalidPrice);
         }
 
-        let mut listing: SecondaryListing = env
-            .storage()
-            .instance()
-            .get(&DataKey::SecondaryListing(ticket_id))
-            .expect("Ticket not listed");
+        let mut listing = Self::read_listing(&env, ticket_id)?;
 
         if listing.seller != seller {
-            panic!("Only the seller can update price");
+            return Err(Error::NotSeller);
+        }
+
+        if !Self::is_listing_active(&env, &Self::read_event_info(&env)?, &listing) {
+            return Err(Error::ListingExpired);
         }
 
+        let ticket = Self::read_ticket(&env, ticket_id)?;
+        Self::check_resale_price(&env, &ticket, new_price)?;
+
+        let old_price = listing.price;
         listing.price = new_price;
-        env.storage()
-            .instance()
-            .set(&DataKey::SecondaryListing(ticket_id), &listing);
+        Self::write_listing(&env, &listing);
+
+        PriceUpdate {
+            ticket_id,
+            seller,
+            old_price,
+            new_price,
+        }
+        .publish(&env);
+
+        Ok(())
     }
 
     /// Transfer ticket directly (P2P)
-    pub fn transfer_ticket(env: Env, from: Address, to: Address, ticket_id: u32) {
+    pub fn transfer_ticket(
+        env: Env,
+        from: Address,
+        to: Address,
+        ticket_id: u32,
+    ) -> Result<(), Error> {
         from.require_auth();
+        Self::extend_instance_ttl(&env);
 
-        let mut ticket: TicketData = env
-            .storage()
-            .instance()
-            .get(&DataKey::Ticket(ticket_id))
-            .expect("Ticket does not exist");
+        let ticket = Self::read_ticket(&env, ticket_id)?;
 
         if ticket.owner != from {
-            panic!("Only ticket owner can transfer");
+            return Err(Error::NotOwner);
+        }
+
+        Self::transfer_unlisted(&env, &ticket, &to)
+    }
+
+    /// Mark ticket as used (check-in at event). Check-in opens at the start
+    /// of the event, so escrowed payments cannot be released before then.
+    pub fn mark_ticket_used(env: Env, creator: Address, ticket_id: u32) -> Result<(), Error> {
+        let event_info = Self::require_creator(&env, &creator)?;
+
+        Self::require_not_cancelled(&env)?;
+
+        if env.ledger().timestamp() < event_info.schedule.event_start {
+            return Err(Error::ProceedsLocked);
         }
 
+        let mut ticket = Self::read_ticket(&env, ticket_id)?;
+
         if ticket.is_used {
-            panic!("Cannot transfer used ticket");
+            return Err(Error::TicketUsed);
         }
 
-        // Check if listed in secondary market
-        if env
-            .storage()
-            .instance()
-            .has(&DataKey::SecondaryListing(ticket_id))
-        {
-            panic!("Cannot transfer listed ticket. Delist first.");
+        ticket.is_used = true;
+        Self::write_ticket(&env, &ticket);
+
+        // Release the ticket's escrowed payment to the creator, except for
+        // what an unclaimed Dutch auction rebate could still take
+        if let Some(held) = Self::read_escrowed_payment(&env, ticket_id) {
+            let key = DataKey::EscrowedPayment(ticket_id);
+            let reserved = match Self::read_dutch_purchase(&env, ticket_id) {
+                Some((_, price)) => price - Self::rebate_floor(&env, &event_info)?,
+                None => 0,
+            };
+            if reserved > 0 {
+                env.storage().persistent().set(&key, &reserved);
+            } else {
+                env.storage().persistent().remove(&key);
+            }
+            Self::add_proceeds(&env, &DataKey::ReleasedProceeds, held - reserved);
         }
 
-        // Update ownership
-        Self::remove_ticket_from_user(&env, &from, ticket_id);
-        ticket.owner = to.clone();
+        // Remove from secondary market if listed
+        if let Some(listing) = Self::load_listing(&env, ticket_id) {
+            Self::remove_listing(&env, ticket_id);
+            Delist {
+                ticket_id,
+                seller: listing.seller,
+            }
+            .publish(&env);
+        }
+
+        CheckIn {
+            ticket_id,
+            owner: ticket.owner,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Split the creator fee on secondary sales between several recipients
+    /// (event creator only). Shares are in basis points of the fee and may
+    /// total at most 10000; the remainder goes to `remainder_recipient`.
+    pub fn set_royalty_splits(
+        env: Env,
+        creator: Address,
+        splits: Vec<RoyaltySplit>,
+        remainder_recipient: Address,
+    ) -> Result<(), Error> {
+        let mut event_info = Self::require_creator(&env, &creator)?;
+
+        if splits.len() > MAX_ROYALTY_SPLITS {
+            return Err(Error::InvalidRoyaltySplits);
+        }
+        let mut total_bps: u32 = 0;
+        for split in splits.iter() {
+            if split.bps == 0 {
+                return Err(Error::InvalidRoyaltySplits);
+            }
+            total_bps = total_bps.saturating_add(split.bps);
+        }
+        if total_bps > 10000 {
+            return Err(Error::InvalidRoyaltySplits);
+        }
+
+        event_info.royalty_splits = splits;
+        event_info.royalty_remainder_recipient = remainder_recipient;
+        env.storage()
+            .instance()
+            .set(&DataKey::EventInfo, &event_info);
+
+        Ok(())
+    }
+
+    /// Set the limits on secondary listing prices (event creator only).
+    /// Listings made before a change keep their price until it is updated.
+    pub fn set_resale_policy(
+        env: Env,
+        creator: Address,
+        policy: ResalePolicy,
+    ) -> Result<(), Error> {
+        Self::require_creator(&env, &creator)?;
+
+        if policy.max_price < 0
+            || policy.min_price < 0
+            || (policy.max_price > 0 && policy.min_price > policy.max_price)
+        {
+            return Err(Error::InvalidResalePolicy);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::ResalePolicy, &policy);
+
+        Ok(())
+    }
+
+    /// Get the limits on secondary listing prices
+    pub fn get_resale_policy(env: Env) -> ResalePolicy {
+        Self::extend_instance_ttl(&env);
+        Self::read_resale_policy(&env)
+    }
+
+    /// Get the highest price a ticket may be listed for, if it is capped
+    pub fn get_max_resale_price(env: Env, ticket_id: u32) -> Result<Option<i128>, Error> {
+        Self::extend_instance_ttl(&env);
+        let ticket = Self::read_ticket(&env, ticket_id)?;
+
+        Self::max_resale_price(&env, &ticket)
+    }
+
+    // === NFT Interface ===
+
+    /// Get the number of tickets held by an address
+    pub fn balance(env: Env, owner: Address) -> u32 {
+        Self::extend_instance_ttl(&env);
+        Self::read_user_tickets(&env, &owner).len()
+    }
+
+    /// Get the current owner of a ticket
+    pub fn owner_of(env: Env, ticket_id: u32) -> Result<Address, Error> {
+        Self::extend_instance_ttl(&env);
+        Ok(Self::read_ticket(&env, ticket_id)?.owner)
+    }
+
+    /// Get the metadata URI of a ticket. A per-ticket override wins, then the
+    /// base URI followed by the ticket id, then the event's metadata.
+    pub fn token_uri(env: Env, ticket_id: u32) -> Result<String, Error> {
+        Self::extend_instance_ttl(&env);
+        Self::read_ticket(&env, ticket_id)?;
+
+        if let Some(uri) = Self::read_ticket_uri(&env, ticket_id) {
+            return Ok(uri);
+        }
+
+        match env.storage().instance().get::<_, String>(&DataKey::BaseUri) {
+            Some(base_uri) => Ok(Self::join_uri(&env, &base_uri, ticket_id)),
+            None => Ok(Self::read_event_info(&env)?.event_metadata),
+        }
+    }
+
+    /// Approve a spender for a single ticket, or clear the approval with `None`.
+    /// The approver must be the owner or one of the owner's operators, and the
+    /// approval is cleared whenever the ticket changes hands.
+    pub fn approve(
+        env: Env,
+        approver: Address,
+        approved: Option<Address>,
+        ticket_id: u32,
+    ) -> Result<(), Error> {
+        approver.require_auth();
+        Self::extend_instance_ttl(&env);
+
+        let ticket = Self::read_ticket(&env, ticket_id)?;
+
+        if approver != ticket.owner && !Self::read_approval_for_all(&env, &ticket.owner, &approver)
+        {
+            return Err(Error::NotOwner);
+        }
+
+        match &approved {
+            Some(spender) => Self::write_approval(&env, ticket_id, spender),
+            None => Self::remove_approval(&env, ticket_id),
+        }
+
+        Approve {
+            ticket_id,
+            owner: ticket.owner,
+            approved,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Get the address approved to transfer a single ticket
+    pub fn get_approved(env: Env, ticket_id: u32) -> Result<Option<Address>, Error> {
+        Self::extend_instance_ttl(&env);
+        Self::read_ticket(&env, ticket_id)?;
+        Ok(Self::read_approval(&env, ticket_id))
+    }
+
+    /// Grant or revoke an operator that may transfer all of the owner's tickets
+    pub fn set_approval_for_all(env: Env, owner: Address, operator: Address, approved: bool) {
+        owner.require_auth();
+        Self::extend_instance_ttl(&env);
+
+        let key = DataKey::ApprovalForAll(owner.clone(), operator.clone());
+        if approved {
+            env.storage().persistent().set(&key, &true);
+            Self::extend_persistent_ttl(&env, &key);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+
+        ApproveForAll {
+            owner,
+            operator,
+            approved,
+        }
+        .publish(&env);
+    }
+
+    /// Check whether an operator may transfer all of the owner's tickets
+    pub fn is_approved_for_all(env: Env, owner: Address, operator: Address) -> bool {
+        Self::extend_instance_ttl(&env);
+        Self::read_approval_for_all(&env, &owner, &operator)
+    }
+
+    /// Transfer a ticket on behalf of its owner. The spender must be the owner,
+    /// the ticket's approved address or an operator of the owner. Used and
+    /// listed tickets cannot be transferred, as with `transfer_ticket`.
+    pub fn transfer_from(
+        env: Env,
+        spender: Address,
+        from: Address,
+        to: Address,
+        ticket_id: u32,
+    ) -> Result<(), Error> {
+        spender.require_auth();
+        Self::extend_instance_ttl(&env);
+
+        let ticket = Self::read_ticket(&env, ticket_id)?;
+
+        if ticket.owner != from {
+            return Err(Error::NotOwner);
+        }
+
+        if spender != from
+            && Self::read_approval(&env, ticket_id) != Some(spender.clone())
+            && !Self::read_approval_for_all(&env, &from, &spender)
+        {
+            return Err(Error::NotApproved);
+        }
+
+        Self::transfer_unlisted(&env, &ticket, &to)
+    }
+
+    // === Sale Limits ===
+
+    /// Cap how many tickets one address may buy on the primary market across
+    /// all tiers, or lift the cap with 0 (event creator only)
+    pub fn set_max_per_wallet(
+        env: Env,
+        creator: Address,
+        max_per_wallet: u32,
+    ) -> Result<(), Error> {
+        Self::require_creator(&env, &creator)?;
+
+        env.storage()
+            .instance()
+            .set(&DataKey::MaxPerWallet, &max_per_wallet);
+
+        Ok(())
+    }
+
+    /// Configure the allowlist presale, or replace its root, price or cap
+    /// (event creator only). The presale has to start before the public sale.
+    pub fn set_presale(env: Env, creator: Address, presale: PresaleConfig) -> Result<(), Error> {
+        let event_info = Self::require_creator(&env, &creator)?;
+
+        Self::read_tier(&env, presale.tier_id)?;
+
+        if presale.price <= 0 {
+            return Err(Error::InvalidPrice);
+        }
+        if presale.start >= event_info.schedule.sale_start || presale.max_per_wallet == 0 {
+            return Err(Error::InvalidPresale);
+        }
+
+        env.storage().instance().set(&DataKey::Presale, &presale);
+
+        Ok(())
+    }
+
+    /// Get the presale configuration, if any
+    pub fn get_presale(env: Env) -> Option<PresaleConfig> {
+        Self::extend_instance_ttl(&env);
+        Self::read_presale(&env)
+    }
+
+    /// Get how many presale tickets an address has bought
+    pub fn get_presale_purchased(env: Env, buyer: Address) -> u32 {
+        Self::extend_instance_ttl(&env);
+        Self::read_purchased(&env, &DataKey::PresalePurchased(buyer))
+    }
+
+    // === Dutch Auction ===
+
+    /// Sell a tier at a descending price instead of its fixed price (event
+    /// creator only). Must be set before any ticket is sold. Rebates need the
+    /// payments at hand, so they also hold all primary payments in escrow.
+    pub fn set_dutch_auction(
+        env: Env,
+        creator: Address,
+        auction: DutchAuction,
+    ) -> Result<(), Error> {
+        Self::require_creator(&env, &creator)?;
+
+        if Self::read_tickets_minted(&env) > 0 {
+            return Err(Error::TicketsAlreadySold);
+        }
+
+        Self::read_tier(&env, auction.tier_id)?;
+
+        if auction.floor_price <= 0
+            || auction.start_price < auction.floor_price
+            || auction.price_drop < 0
+        {
+            return Err(Error::InvalidPrice);
+        }
+        if auction.drop_interval == 0 {
+            return Err(Error::InvalidSchedule);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::DutchAuction, &auction);
+
+        Ok(())
+    }
+
+    /// Get the Dutch auction configuration, if any
+    pub fn get_dutch_auction(env: Env) -> Option<DutchAuction> {
+        Self::extend_instance_ttl(&env);
+        Self::read_dutch_auction(&env)
+    }
+
+    /// Get the price a ticket of a tier costs on the public sale right now
+    pub fn get_current_price(env: Env, tier_id: u32) -> Result<i128, Error> {
+        Self::extend_instance_ttl(&env);
+        let event_info = Self::read_event_info(&env)?;
+        let tier = Self::read_tier(&env, tier_id)?;
+
+        Ok(Self::dutch_price(&env, &event_info, tier_id).unwrap_or(tier.price))
+    }
+
+    /// Get the Dutch auction's clearing price, the price of the last ticket
+    /// sold. It is known once the tier sells out or the sale ends.
+    pub fn get_clearing_price(env: Env) -> Result<Option<i128>, Error> {
+        Self::extend_instance_ttl(&env);
+        let event_info = Self::read_event_info(&env)?;
+        Self::clearing_price(&env, &event_info)
+    }
+
+    /// Pay a Dutch auction buyer back what they paid for a ticket above the
+    /// clearing price (original buyer only), once. Rebates stay claimable
+    /// after the ticket is resold or checked in and after the creator has
+    /// withdrawn the proceeds.
+    pub fn claim_rebate(env: Env, ticket_id: u32) -> Result<i128, Error> {
+        Self::extend_instance_ttl(&env);
+
+        let (buyer, paid) =
+            Self::read_dutch_purchase(&env, ticket_id).ok_or(Error::NotRefundable)?;
+        buyer.require_auth();
+
+        let event_info = Self::read_event_info(&env)?;
+        let clearing_price =
+            Self::clearing_price(&env, &event_info)?.ok_or(Error::AuctionNotEnded)?;
+
+        let amount = paid - clearing_price;
+        if amount <= 0 {
+            return Err(Error::NotRefundable);
+        }
+
+        // What is left in escrow for an unused ticket is what a refund would
+        // get. A checked-in ticket only kept back enough to cover the rebate,
+        // and the rest of that goes to the creator.
+        let payment_key = DataKey::EscrowedPayment(ticket_id);
+        let held = Self::read_escrowed_payment(&env, ticket_id).unwrap_or(0);
+        if Self::read_ticket(&env, ticket_id)?.is_used {
+            env.storage().persistent().remove(&payment_key);
+            Self::add_proceeds(&env, &DataKey::ReleasedProceeds, held - amount);
+        } else {
+            env.storage()
+                .persistent()
+                .set(&payment_key, &(held - amount));
+            Self::extend_persistent_ttl(&env, &payment_key);
+        }
+        Self::remove_dutch_purchase(&env, ticket_id, paid);
+        Self::add_proceeds(&env, &DataKey::EscrowBalance, -amount);
+
+        Self::transfer_payment(
+            &env,
+            &event_info,
+            &env.current_contract_address(),
+            &buyer,
+            amount,
+        );
+
+        Rebate {
+            ticket_id,
+            buyer,
+            amount,
+        }
+        .publish(&env);
+
+        Ok(amount)
+    }
+
+    // === Escrow ===
+
+    /// Hold primary sale payments in the contract instead of paying the
+    /// creator straight away, so buyers can be refunded if the event is
+    /// cancelled (event creator only). Must be chosen before any ticket is sold.
+    /// The payment for a ticket is released when it is checked in, and
+    /// everything still held is released once the event ends.
+    pub fn set_escrow(env: Env, creator: Address, enabled: bool) -> Result<(), Error> {
+        Self::require_creator(&env, &creator)?;
+
+        if Self::read_tickets_minted(&env) > 0 {
+            return Err(Error::TicketsAlreadySold);
+        }
+
+        env.storage().instance().set(&DataKey::Escrow, &enabled);
+
+        Ok(())
+    }
+
+    /// Check whether primary sale payments are held in escrow
+    pub fn is_escrowed(env: Env) -> bool {
+        Self::extend_instance_ttl(&env);
+        Self::read_escrow(&env)
+    }
+
+    /// Cancel the event before it ends (event creator only). Minting, trading,
+    /// transfers and check-in stop for good, and holders of escrowed tickets
+    /// can claim their payment back with `claim_refund`.
+    pub fn cancel_event(env: Env, creator: Address) -> Result<(), Error> {
+        let event_info = Self::require_creator(&env, &creator)?;
+
+        Self::require_not_cancelled(&env)?;
+
+        if env.ledger().timestamp() >= event_info.schedule.event_end {
+            return Err(Error::EventEnded);
+        }
+
+        env.storage().instance().set(&DataKey::Cancelled, &true);
+
+        Cancel { creator }.publish(&env);
+
+        Ok(())
+    }
+
+    /// Check whether the event has been cancelled
+    pub fn is_cancelled(env: Env) -> bool {
+        Self::extend_instance_ttl(&env);
+        Self::read_cancelled(&env)
+    }
+
+    /// Refund the price paid for a ticket of a cancelled event to its owner.
+    /// Only unused tickets bought with escrow enabled are refundable, once each.
+    pub fn claim_refund(env: Env, ticket_id: u32) -> Result<i128, Error> {
+        Self::extend_instance_ttl(&env);
+
+        let ticket = Self::read_ticket(&env, ticket_id)?;
+        ticket.owner.require_auth();
+
+        if !Self::read_cancelled(&env) {
+            return Err(Error::EventNotCancelled);
+        }
+
+        if ticket.is_used {
+            return Err(Error::TicketUsed);
+        }
+
+        let amount = Self::read_escrowed_payment(&env, ticket_id).ok_or(Error::NotRefundable)?;
+        env.storage()
+            .persistent()
+            .remove(&DataKey::EscrowedPayment(ticket_id));
+        if let Some((_, price)) = Self::read_dutch_purchase(&env, ticket_id) {
+            Self::remove_dutch_purchase(&env, ticket_id, price);
+        }
+        Self::add_proceeds(&env, &DataKey::EscrowBalance, -amount);
+
+        let event_info = Self::read_event_info(&env)?;
+        Self::transfer_payment(
+            &env,
+            &event_info,
+            &env.current_contract_address(),
+            &ticket.owner,
+            amount,
+        );
+
+        Refund {
+            ticket_id,
+            owner: ticket.owner,
+            amount,
+        }
+        .publish(&env);
+
+        Ok(amount)
+    }
+
+    /// Withdraw the escrowed proceeds released so far (event creator only),
+    /// less the protocol's share of primary sales. Returns the amount paid to
+    /// the creator.
+    pub fn withdraw_proceeds(env: Env, creator: Address) -> Result<i128, Error> {
+        let event_info = Self::require_creator(&env, &creator)?;
+
+        let held = Self::read_proceeds(&env, &DataKey::EscrowBalance);
+        let amount = Self::withdrawable_proceeds(&env, &event_info)?;
+        if amount == 0 {
+            return Err(if held > 0 {
+                Error::ProceedsLocked
+            } else {
+                Error::NoProceeds
+            });
+        }
+
+        Self::write_proceeds(&env, &DataKey::EscrowBalance, held - amount);
+        Self::write_proceeds(&env, &DataKey::ReleasedProceeds, 0);
+
+        let contract = env.current_contract_address();
+        let mut creator_amount = amount;
+        let mut protocol_amount = 0;
+        if let Some(protocol_fee) = Self::read_protocol_fee(&env) {
+            protocol_amount = amount * protocol_fee.primary_bps as i128 / 10000;
+            if protocol_amount > 0 {
+                Self::transfer_payment(
+                    &env,
+                    &event_info,
+                    &contract,
+                    &protocol_fee.treasury,
+                    protocol_amount,
+                );
+                creator_amount -= protocol_amount;
+            }
+        }
+        if creator_amount > 0 {
+            Self::transfer_payment(&env, &event_info, &contract, &creator, creator_amount);
+        }
+
+        Withdraw {
+            creator,
+            amount: creator_amount,
+            protocol_fee: protocol_amount,
+        }
+        .publish(&env);
+
+        Ok(creator_amount)
+    }
+
+    /// Get the primary sale payments held in escrow, released or not
+    pub fn get_escrow_balance(env: Env) -> i128 {
+        Self::extend_instance_ttl(&env);
+        Self::read_proceeds(&env, &DataKey::EscrowBalance)
+    }
+
+    /// Get the escrowed proceeds the creator could withdraw now, before the
+    /// protocol fee
+    pub fn get_withdrawable_proceeds(env: Env) -> Result<i128, Error> {
+        Self::extend_instance_ttl(&env);
+        let event_info = Self::read_event_info(&env)?;
+        Self::withdrawable_proceeds(&env, &event_info)
+    }
+
+    /// Get the payment held in escrow for a ticket, if any
+    pub fn get_escrowed_payment(env: Env, ticket_id: u32) -> Option<i128> {
+        Self::extend_instance_ttl(&env);
+        Self::read_escrowed_payment(&env, ticket_id)
+    }
+
+    // === Offers ===
+
+    /// Offer to buy a ticket, listed or not, holding `price` in escrow until
+    /// the offer is accepted or cancelled. One offer per buyer and ticket,
+    /// and `OfferExists` once the ticket has `MAX_OFFERS_PER_TICKET` offers.
+    pub fn make_offer(
+        env: Env,
+        buyer: Address,
+        ticket_id: u32,
+        price: i128,
+        expiry: u64,
+    ) -> Result<(), Error> {
+        buyer.require_auth();
+        Self::extend_instance_ttl(&env);
+
+        Self::require_not_cancelled(&env)?;
+
+        if price <= 0 {
+            return Err(Error::InvalidPrice);
+        }
+        if expiry <= env.ledger().timestamp() {
+            return Err(Error::InvalidExpiry);
+        }
+        Self::require_before_event_start(&env)?;
+
+        let ticket = Self::read_ticket(&env, ticket_id)?;
+
+        if ticket.is_used {
+            return Err(Error::TicketUsed);
+        }
+
+        Self::check_resale_price(&env, &ticket, price)?;
+
+        let mut buyers = Self::read_ticket_offers(&env, ticket_id);
+        if buyers.len() >= MAX_OFFERS_PER_TICKET
+            || Self::load_offer(&env, ticket_id, &buyer).is_some()
+        {
+            return Err(Error::OfferExists);
+        }
+
+        let event_info = Self::read_event_info(&env)?;
+        Self::transfer_payment(
+            &env,
+            &event_info,
+            &buyer,
+            &env.current_contract_address(),
+            price,
+        );
+
+        let offer = TicketOffer {
+            ticket_id,
+            buyer: buyer.clone(),
+            price,
+            expiry,
+        };
+        let key = DataKey::Offer(ticket_id, buyer.clone());
+        env.storage().persistent().set(&key, &offer);
+        Self::extend_persistent_ttl(&env, &key);
+
+        buyers.push_back(buyer.clone());
+        Self::write_ticket_offers(&env, ticket_id, &buyers);
+
+        Offer {
+            ticket_id,
+            buyer,
+            price,
+            expiry,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Accept an unexpired offer for a ticket (current owner only). The
+    /// escrowed price is paid out like a secondary sale and any listing of
+    /// the ticket is withdrawn.
+    pub fn accept_offer(
+        env: Env,
+        seller: Address,
+        ticket_id: u32,
+        buyer: Address,
+    ) -> Result<(), Error> {
+        seller.require_auth();
+        Self::extend_instance_ttl(&env);
+
+        Self::require_not_cancelled(&env)?;
+
+        let ticket = Self::read_ticket(&env, ticket_id)?;
+
+        if ticket.owner != seller {
+            return Err(Error::NotOwner);
+        }
+
+        if ticket.is_used {
+            return Err(Error::TicketUsed);
+        }
+
+        if Self::load_auction(&env, ticket_id).is_some() {
+            return Err(Error::AlreadyListed);
+        }
+
+        let offer = Self::load_offer(&env, ticket_id, &buyer).ok_or(Error::OfferNotFound)?;
+
+        if env.ledger().timestamp() >= offer.expiry {
+            return Err(Error::OfferExpired);
+        }
+        Self::require_before_event_start(&env)?;
+
+        let event_info = Self::read_event_info(&env)?;
+        let (creator_fee, protocol_fee) = Self::pay_secondary_sale(
+            &env,
+            &event_info,
+            &env.current_contract_address(),
+            Some(&seller),
+            &ticket,
+            offer.price,
+        )?;
+        Self::remove_offer(&env, ticket_id, &buyer);
+
+        if let Some(listing) = Self::load_listing(&env, ticket_id) {
+            Self::remove_listing(&env, ticket_id);
+            Delist {
+                ticket_id,
+                seller: listing.seller,
+            }
+            .publish(&env);
+        }
+
+        Self::move_ticket(&env, &ticket, &buyer);
+
+        Sale {
+            ticket_id,
+            seller,
+            buyer,
+            price: offer.price,
+            creator_fee,
+            protocol_fee,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Withdraw an offer and get the escrowed price back. This is also how
+    /// buyers recover expired offers or offers on tickets that can no longer
+    /// be sold.
+    pub fn cancel_offer(env: Env, buyer: Address, ticket_id: u32) -> Result<(), Error> {
+        buyer.require_auth();
+        Self::extend_instance_ttl(&env);
+
+        let offer = Self::load_offer(&env, ticket_id, &buyer).ok_or(Error::OfferNotFound)?;
+        Self::remove_offer(&env, ticket_id, &buyer);
+
+        let event_info = Self::read_event_info(&env)?;
+        Self::transfer_payment(
+            &env,
+            &event_info,
+            &env.current_contract_address(),
+            &buyer,
+            offer.price,
+        );
+
+        OfferCancel { ticket_id, buyer }.publish(&env);
+
+        Ok(())
+    }
+
+    /// Get a buyer's offer for a ticket, if any
+    pub fn get_offer(env: Env, ticket_id: u32, buyer: Address) -> Option<TicketOffer> {
+        Self::extend_instance_ttl(&env);
+        Self::load_offer(&env, ticket_id, &buyer)
+    }
+
+    /// Get all offers for a ticket, including expired ones not yet cancelled
+    pub fn get_offers(env: Env, ticket_id: u32) -> Vec<TicketOffer> {
+        Self::extend_instance_ttl(&env);
+
+        let mut offers = Vec::new(&env);
+        for buyer in Self::read_ticket_offers(&env, ticket_id).iter() {
+            if let Some(offer) = Self::load_offer(&env, ticket_id, &buyer) {
+                offers.push_back(offer);
+            }
+        }
+        offers
+    }
+
+    // === Collection Bids ===
+
+    /// Bid for `quantity` tickets of any tier, or of `tier_id`, at `price`
+    /// each, holding the full amount in escrow. Returns the bid id.
+    pub fn place_collection_bid(
+        env: Env,
+        buyer: Address,
+        tier_id: Option<u32>,
+        price: i128,
+        quantity: u32,
+    ) -> Result<u32, Error> {
+        buyer.require_auth();
+        Self::extend_instance_ttl(&env);
+
+        Self::require_not_cancelled(&env)?;
+
+        if price <= 0 {
+            return Err(Error::InvalidPrice);
+        }
+        if quantity == 0 {
+            return Err(Error::InvalidQuantity);
+        }
+        if let Some(tier_id) = tier_id {
+            Self::read_tier(&env, tier_id)?;
+        }
+        Self::require_before_event_start(&env)?;
+
+        let total_price = price
+            .checked_mul(quantity as i128)
+            .ok_or(Error::InvalidQuantity)?;
+        let event_info = Self::read_event_info(&env)?;
+        Self::transfer_payment(
+            &env,
+            &event_info,
+            &buyer,
+            &env.current_contract_address(),
+            total_price,
+        );
+
+        let bid_id = env
+            .storage()
+            .instance()
+            .get::<_, u32>(&DataKey::BidCounter)
+            .unwrap_or(0)
+            + 1;
+        env.storage().instance().set(&DataKey::BidCounter, &bid_id);
+
+        let bid = CollectionBid {
+            bid_id,
+            buyer: buyer.clone(),
+            tier_id,
+            price,
+            quantity,
+        };
+        Self::write_collection_bid(&env, &bid);
+
+        CollectionBidPlace {
+            bid_id,
+            buyer,
+            tier_id,
+            price,
+            quantity,
+        }
+        .publish(&env);
+
+        Ok(bid_id)
+    }
+
+    /// Sell an unused, unlisted ticket into a collection bid (ticket owner
+    /// only). The escrowed price is paid out like a secondary sale.
+    pub fn fill_collection_bid(
+        env: Env,
+        seller: Address,
+        bid_id: u32,
+        ticket_id: u32,
+    ) -> Result<(), Error> {
+        seller.require_auth();
+        Self::extend_instance_ttl(&env);
+
+        Self::require_not_cancelled(&env)?;
+
+        let mut bid = Self::load_collection_bid(&env, bid_id).ok_or(Error::BidNotFound)?;
+        let ticket = Self::read_ticket(&env, ticket_id)?;
+
+        if ticket.owner != seller {
+            return Err(Error::NotOwner);
+        }
+
+        if ticket.is_used {
+            return Err(Error::TicketUsed);
+        }
+
+        if Self::is_for_sale(&env, ticket_id)? {
+            return Err(Error::AlreadyListed);
+        }
+
+        if bid.tier_id.is_some_and(|tier_id| tier_id != ticket.tier_id) {
+            return Err(Error::TierMismatch);
+        }
+
+        Self::check_resale_price(&env, &ticket, bid.price)?;
+        Self::require_before_event_start(&env)?;
+
+        let event_info = Self::read_event_info(&env)?;
+        let (creator_fee, protocol_fee) = Self::pay_secondary_sale(
+            &env,
+            &event_info,
+            &env.current_contract_address(),
+            Some(&seller),
+            &ticket,
+            bid.price,
+        )?;
+
+        bid.quantity -= 1;
+        if bid.quantity == 0 {
+            Self::remove_collection_bid(&env, bid_id);
+        } else {
+            Self::write_collection_bid(&env, &bid);
+        }
+
+        Self::move_ticket(&env, &ticket, &bid.buyer);
+
+        Sale {
+            ticket_id,
+            seller,
+            buyer: bid.buyer,
+            price: bid.price,
+            creator_fee,
+            protocol_fee,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Withdraw a collection bid and get the escrow for its unfilled tickets
+    /// back (bidder only)
+    pub fn cancel_collection_bid(env: Env, bid_id: u32) -> Result<(), Error> {
+        Self::extend_instance_ttl(&env);
+
+        let bid = Self::load_collection_bid(&env, bid_id).ok_or(Error::BidNotFound)?;
+        bid.buyer.require_auth();
+
+        Self::remove_collection_bid(&env, bid_id);
+
+        let refund = bid.price * bid.quantity as i128;
+        let event_info = Self::read_event_info(&env)?;
+        Self::transfer_payment(
+            &env,
+            &event_info,
+            &env.current_contract_address(),
+            &bid.buyer,
+            refund,
+        );
+
+        CollectionBidCancel {
+            bid_id,
+            buyer: bid.buyer,
+            refund,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Get a collection bid by id
+    pub fn get_collection_bid(env: Env, bid_id: u32) -> Result<CollectionBid, Error> {
+        Self::extend_instance_ttl(&env);
+        Self::load_collection_bid(&env, bid_id).ok_or(Error::BidNotFound)
+    }
+
+    /// Get the open collection bids among `limit` bid ids from `start`
+    pub fn get_collection_bids(env: Env, start: u32, limit: u32) -> Vec<CollectionBid> {
+        Self::extend_instance_ttl(&env);
+        let bid_counter: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::BidCounter)
+            .unwrap_or(0);
+
+        let mut bids = Vec::new(&env);
+        for bid_id in Self::page(start, limit, bid_counter) {
+            if let Some(bid) = Self::load_collection_bid(&env, bid_id) {
+                bids.push_back(bid);
+            }
+        }
+        bids
+    }
+
+    // === Auctions ===
+
+    /// Put an unused, unlisted ticket up for auction until `end_time`, which
+    /// must be no later than the start of the event (ticket owner only).
+    /// Auctioned tickets count as listed until the auction is settled.
+    pub fn start_auction(
+        env: Env,
+        ticket_id: u32,
+        reserve: i128,
+        end_time: u64,
+    ) -> Result<(), Error> {
+        Self::extend_instance_ttl(&env);
+
+        let ticket = Self::read_ticket(&env, ticket_id)?;
+        ticket.owner.require_auth();
+
+        Self::require_not_cancelled(&env)?;
+
+        if reserve <= 0 {
+            return Err(Error::InvalidPrice);
+        }
+
+        let now = env.ledger().timestamp();
+        let event_info = Self::read_event_info(&env)?;
+        if end_time <= now || end_time > event_info.schedule.event_start {
+            return Err(Error::InvalidExpiry);
+        }
+
+        if ticket.is_used {
+            return Err(Error::TicketUsed);
+        }
+
+        Self::check_resale_price(&env, &ticket, reserve)?;
+
+        if Self::is_for_sale(&env, ticket_id)? {
+            return Err(Error::AlreadyListed);
+        }
+
+        let auction = Auction {
+            ticket_id,
+            seller: ticket.owner.clone(),
+            reserve,
+            end_time,
+            highest_bidder: None,
+            highest_bid: 0,
+        };
+        Self::write_auction(&env, &auction);
+
+        AuctionStart {
+            ticket_id,
+            seller: ticket.owner,
+            reserve,
+            end_time,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Bid on an auction, holding `amount` in escrow and crediting the
+    /// previous highest bid to its bidder for `claim_balance`. Bids must
+    /// meet the reserve and beat the highest bid by `MIN_BID_INCREMENT_BPS`,
+    /// and a bid in the last `AUCTION_EXTENSION_SECONDS` pushes the end
+    /// back, up to the event start.
+    pub fn bid(env: Env, bidder: Address, ticket_id: u32, amount: i128) -> Result<(), Error> {
+        bidder.require_auth();
+        Self::extend_instance_ttl(&env);
+
+        Self::require_not_cancelled(&env)?;
+
+        let mut auction = Self::load_auction(&env, ticket_id).ok_or(Error::AuctionNotFound)?;
+
+        let now = env.ledger().timestamp();
+        if now >= auction.end_time {
+            return Err(Error::ListingExpired);
+        }
+
+        let min_bid = if auction.highest_bidder.is_some() {
+            let increment = (auction.highest_bid * MIN_BID_INCREMENT_BPS as i128 / 10000).max(1);
+            auction.highest_bid + increment
+        } else {
+            auction.reserve
+        };
+        if amount < min_bid {
+            return Err(Error::BidTooLow);
+        }
+
+        let ticket = Self::read_ticket(&env, ticket_id)?;
+        Self::check_resale_price(&env, &ticket, amount)?;
+
+        let event_info = Self::read_event_info(&env)?;
+        Self::transfer_payment(
+            &env,
+            &event_info,
+            &bidder,
+            &env.current_contract_address(),
+            amount,
+        );
+        if let Some(previous_bidder) = auction.highest_bidder {
+            Self::credit(&env, &previous_bidder, auction.highest_bid);
+        }
+
+        // Anti-sniping: late bids leave other bidders time to respond
+        if auction.end_time - now < AUCTION_EXTENSION_SECONDS {
+            auction.end_time =
+                (now + AUCTION_EXTENSION_SECONDS).min(event_info.schedule.event_start);
+        }
+
+        auction.highest_bidder = Some(bidder.clone());
+        auction.highest_bid = amount;
+        Self::write_auction(&env, &auction);
+
+        AuctionBid {
+            ticket_id,
+            bidder,
+            amount,
+            end_time: auction.end_time,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Close an auction once it has ended (callable by anyone). The ticket
+    /// goes to the highest bidder, with the winning bid paid out like a
+    /// secondary sale except that the seller's share is credited to
+    /// `claim_balance`. If there were no bids the ticket stays with the
+    /// seller, and if the event was cancelled or the ticket checked in
+    /// meanwhile the highest bid is credited back to its bidder instead.
+    pub fn settle_auction(env: Env, ticket_id: u32) -> Result<(), Error> {
+        Self::extend_instance_ttl(&env);
+
+        let auction = Self::load_auction(&env, ticket_id).ok_or(Error::AuctionNotFound)?;
+        let ticket = Self::read_ticket(&env, ticket_id)?;
+        let event_info = Self::read_event_info(&env)?;
+        let cancelled = Self::read_cancelled(&env);
+
+        if env.ledger().timestamp() < auction.end_time && !cancelled {
+            return Err(Error::AuctionNotEnded);
+        }
+
+        Self::remove_auction(&env, ticket_id);
+
+        match auction.highest_bidder {
+            Some(winner) if !cancelled && !ticket.is_used => {
+                let (creator_fee, protocol_fee) = Self::pay_secondary_sale(
+                    &env,
+                    &event_info,
+                    &env.current_contract_address(),
+                    None,
+                    &ticket,
+                    auction.highest_bid,
+                )?;
+                Self::credit(
+                    &env,
+                    &auction.seller,
+                    auction.highest_bid - creator_fee - protocol_fee,
+                );
+
+                Self::move_ticket(&env, &ticket, &winner);
+
+                Sale {
+                    ticket_id,
+                    seller: auction.seller,
+                    buyer: winner,
+                    price: auction.highest_bid,
+                    creator_fee,
+                    protocol_fee,
+                }
+                .publish(&env);
+            }
+            highest_bidder => {
+                if let Some(bidder) = highest_bidder {
+                    Self::credit(&env, &bidder, auction.highest_bid);
+                }
+
+                AuctionCancel {
+                    ticket_id,
+                    seller: auction.seller,
+                }
+                .publish(&env);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Pay out what an address is owed from auctions: bids that were outbid
+    /// or refunded, and the seller's share of settled auctions
+    pub fn claim_balance(env: Env, owner: Address) -> Result<i128, Error> {
+        owner.require_auth();
+        Self::extend_instance_ttl(&env);
+
+        let amount = Self::read_claimable(&env, &owner);
+        if amount == 0 {
+            return Err(Error::NoProceeds);
+        }
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Claimable(owner.clone()));
+
+        let event_info = Self::read_event_info(&env)?;
+        Self::transfer_payment(
+            &env,
+            &event_info,
+            &env.current_contract_address(),
+            &owner,
+            amount,
+        );
+
+        Ok(amount)
+    }
+
+    /// Get what an address can collect with `claim_balance`
+    pub fn get_claimable(env: Env, owner: Address) -> i128 {
+        Self::extend_instance_ttl(&env);
+        Self::read_claimable(&env, &owner)
+    }
+
+    /// Get the auction of a ticket
+    pub fn get_auction(env: Env, ticket_id: u32) -> Result<Auction, Error> {
+        Self::extend_instance_ttl(&env);
+        Self::load_auction(&env, ticket_id).ok_or(Error::AuctionNotFound)
+    }
+
+    /// Get the auctions that have not been settled among `limit` ticket ids
+    /// from `start`
+    pub fn get_all_auctions(env: Env, start: u32, limit: u32) -> Vec<Auction> {
+        Self::extend_instance_ttl(&env);
+        let tickets_minted = Self::read_tickets_minted(&env);
+
+        let mut auctions = Vec::new(&env);
+        for i in Self::page(start, limit, tickets_minted) {
+            if let Some(auction) = Self::load_auction(&env, i) {
+                auctions.push_back(auction);
+            }
+        }
+        auctions
+    }
+
+    // === Upgrades ===
+
+    /// Replace the contract code. Only the upgrade authority may do this: the
+    /// deploying factory by default, or the event creator for events without
+    /// a factory or that have opted out.
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+        Self::upgrade_authority_address(&env)?.require_auth();
+        Self::extend_instance_ttl(&env);
+
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+
+        Upgrade { new_wasm_hash }.publish(&env);
+
+        Ok(())
+    }
+
+    /// Hand the right to upgrade to the factory or the creator. The current
+    /// upgrade authority must authorize the change; the factory does so
+    /// through its admin-only `set_event_upgrade_authority`.
+    pub fn set_upgrade_authority(env: Env, authority: UpgradeAuthority) -> Result<(), Error> {
+        Self::upgrade_authority_address(&env)?.require_auth();
+        Self::extend_instance_ttl(&env);
+
+        if authority == UpgradeAuthority::Factory
+            && !env.storage().instance().has(&DataKey::Factory)
+        {
+            return Err(Error::NoFactory);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::UpgradeAuthority, &authority);
+
+        Ok(())
+    }
+
+    /// Get who may upgrade the contract code
+    pub fn get_upgrade_authority(env: Env) -> UpgradeAuthority {
+        Self::extend_instance_ttl(&env);
+        Self::read_upgrade_authority(&env)
+    }
+
+    /// Get the version of the contract interface
+    pub fn version(env: Env) -> u32 {
+        Self::extend_instance_ttl(&env);
+        CONTRACT_VERSION
+    }
+
+    // === Metadata ===
+
+    /// Set the base URI that ticket ids are appended to (event creator only)
+    pub fn set_base_uri(env: Env, creator: Address, base_uri: String) -> Result<(), Error> {
+        Self::require_metadata_editor(&env, &creator)?;
+
+        if base_uri.is_empty() || base_uri.len() > MAX_BASE_URI_LEN {
+            return Err(Error::InvalidUri);
+        }
+
+        env.storage().instance().set(&DataKey::BaseUri, &base_uri);
+
+        BaseUriUpdate { base_uri }.publish(&env);
+
+        Ok(())
+    }
+
+    /// Get the base URI, if one has been set
+    pub fn get_base_uri(env: Env) -> Option<String> {
+        Self::extend_instance_ttl(&env);
+        env.storage().instance().get(&DataKey::BaseUri)
+    }
+
+    /// Set or clear a ticket's own metadata URI (event creator only). Tickets
+    /// that have not been minted yet can be given seat-specific metadata too.
+    pub fn set_ticket_uri(
+        env: Env,
+        creator: Address,
+        ticket_id: u32,
+        uri: Option<String>,
+    ) -> Result<(), Error> {
+        Self::require_metadata_editor(&env, &creator)?;
+
+        let event_info = Self::read_event_info(&env)?;
+        if ticket_id == 0 || ticket_id > event_info.total_supply {
+            return Err(Error::TicketNotFound);
+        }
+
+        let key = DataKey::TicketUri(ticket_id);
+        match &uri {
+            Some(value) => {
+                if value.is_empty() {
+                    return Err(Error::InvalidUri);
+                }
+                env.storage().persistent().set(&key, value);
+                Self::extend_persistent_ttl(&env, &key);
+            }
+            None => env.storage().persistent().remove(&key),
+        }
+
+        TicketUriUpdate { ticket_id, uri }.publish(&env);
+
+        Ok(())
+    }
+
+    /// Permanently prevent further changes to ticket URIs (event creator only)
+    pub fn freeze_metadata(env: Env, creator: Address) -> Result<(), Error> {
+        Self::require_metadata_editor(&env, &creator)?;
+
+        env.storage()
+            .instance()
+            .set(&DataKey::MetadataFrozen, &true);
+
+        MetadataFreeze { creator }.publish(&env);
+
+        Ok(())
+    }
+
+    /// Check whether ticket metadata has been frozen
+    pub fn is_metadata_frozen(env: Env) -> bool {
+        Self::extend_instance_ttl(&env);
+        Self::read_metadata_frozen(&env)
+    }
+
+    // === Storage Lifetime ===
+
+    /// Set how long event storage is kept alive (event creator only)
+    pub fn set_ttl_policy(env: Env, creator: Address, policy: TtlPolicy) -> Result<(), Error> {
+        Self::require_creator(&env, &creator)?;
+
+        if policy.min_extension < DAY_IN_LEDGERS || policy.min_extension > env.storage().max_ttl() {
+            return Err(Error::InvalidTtlPolicy);
+        }
+
+        env.storage().instance().set(&DataKey::TtlPolicy, &policy);
+        Self::extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Extend the TTL of the contract instance, code and event information.
+    /// Anyone may call this and pay for the extension.
+    pub fn extend_event_ttl(env: Env) -> Result<(), Error> {
+        Self::read_event_info(&env)?;
+        Self::extend_instance_ttl(&env);
+        Ok(())
+    }
+
+    /// Extend the TTL of a ticket and the entries tied to it: its listing,
+    /// auction, offers, approval, metadata URI, escrowed payment, rebate and
+    /// the owner's ticket index.
+    /// Anyone may call this and pay for the extension.
+    pub fn extend_ticket_ttl(env: Env, ticket_id: u32) -> Result<(), Error> {
+        Self::extend_instance_ttl(&env);
+
+        // Reading through the storage helpers extends each entry they touch
+        let ticket = Self::read_ticket(&env, ticket_id)?;
+        Self::load_listing(&env, ticket_id);
+        Self::load_auction(&env, ticket_id);
+        for buyer in Self::read_ticket_offers(&env, ticket_id).iter() {
+            Self::load_offer(&env, ticket_id, &buyer);
+        }
+        Self::read_approval(&env, ticket_id);
+        Self::read_ticket_uri(&env, ticket_id);
+        Self::read_escrowed_payment(&env, ticket_id);
+        Self::read_dutch_purchase(&env, ticket_id);
+        Self::read_user_tickets(&env, &ticket.owner);
+
+        Ok(())
+    }
+
+    /// Get the storage TTL policy
+    pub fn get_ttl_policy(env: Env) -> TtlPolicy {
+        Self::read_ttl_policy(&env)
+    }
+
+    // === Query Functions ===
+
+    /// Get the name of the event/ticket collection
+    pub fn name(env: Env) -> Result<String, Error> {
+        Self::extend_instance_ttl(&env);
+        Ok(Self::read_event_info(&env)?.name)
+    }
+
+    /// Get the symbol of the ticket
+    pub fn symbol(env: Env) -> Result<String, Error> {
+        Self::extend_instance_ttl(&env);
+        Ok(Self::read_event_info(&env)?.symbol)
+    }
+
+    /// Get ticket information
+    pub fn get_ticket(env: Env, ticket_id: u32) -> Result<TicketData, Error> {
+        Self::extend_instance_ttl(&env);
+        Self::read_ticket(&env, ticket_id)
+    }
+
+    /// Get event information
+    pub fn get_event_info(env: Env) -> Result<EventInfo, Error> {
+        Self::extend_instance_ttl(&env);
+        Self::read_event_info(&env)
+    }
+
+    /// Get tickets owned by user
+    pub fn get_user_tickets(env: Env, user: Address) -> Vec<u32> {
+        Self::extend_instance_ttl(&env);
+        Self::read_user_tickets(&env, &user)
+    }
+
+    /// Get secondary listing
+    pub fn get_secondary_listing(env: Env, ticket_id: u32) -> Option<SecondaryListing> {
+        Self::extend_instance_ttl(&env);
+        Self::load_listing(&env, ticket_id)
+    }
+
+    /// Get the secondary listings that can still be bought among `limit`
+    /// ticket ids from `start`. A cancelled event has none.
+    pub fn get_all_secondary_listings(env: Env, start: u32, limit: u32) -> Vec<SecondaryListing> {
+        Self::extend_instance_ttl(&env);
+        let tickets_minted = Self::read_tickets_minted(&env);
+
+        let mut listings = Vec::new(&env);
+        let Ok(event_info) = Self::read_event_info(&env) else {
+            return listings;
+        };
+        if Self::read_cancelled(&env) {
+            return listings;
+        }
+        for i in Self::page(start, limit, tickets_minted) {
+            if let Some(listing) = Self::load_listing(&env, i) {
+                if Self::is_listing_active(&env, &event_info, &listing) {
+                    listings.push_back(listing);
+                }
+            }
+        }
+        listings
+    }
+
+    /// Get number of tickets minted
+    pub fn get_tickets_minted(env: Env) -> u32 {
+        Self::extend_instance_ttl(&env);
+        Self::read_tickets_minted(&env)
+    }
+
+    /// Check whether the primary sale is open at the current ledger time
+    pub fn is_on_sale(env: Env) -> Result<bool, Error> {
+        Self::extend_instance_ttl(&env);
+        let schedule = Self::read_event_info(&env)?.schedule;
+        let now = env.ledger().timestamp();
+
+        Ok(now >= schedule.sale_start && now < schedule.sale_end && !Self::read_cancelled(&env))
+    }
+
+    /// Get the factory that deployed this event, if any
+    pub fn get_factory(env: Env) -> Option<Address> {
+        Self::extend_instance_ttl(&env);
+        env.storage().instance().get(&DataKey::Factory)
+    }
+
+    /// Get the protocol fee charged by the deploying factory, if any
+    pub fn get_protocol_fee(env: Env) -> Option<ProtocolFee> {
+        Self::extend_instance_ttl(&env);
+        Self::read_protocol_fee(&env)
+    }
+
+    /// Get all tiers with their primary sales so far
+    pub fn get_tiers(env: Env) -> Result<Vec<Tier>, Error> {
+        Self::extend_instance_ttl(&env);
+        Self::read_tiers(&env)
+    }
+
+    /// Get a single tier
+    pub fn get_tier(env: Env, tier_id: u32) -> Result<Tier, Error> {
+        Self::extend_instance_ttl(&env);
+        Self::read_tier(&env, tier_id)
+    }
+
+    /// Get how many tickets of a tier the buyer may still buy on the primary
+    /// market, given the wallet caps and the tier's remaining supply
+    pub fn get_wallet_allowance(env: Env, buyer: Address, tier_id: u32) -> Result<u32, Error> {
+        Self::extend_instance_ttl(&env);
+        let tier = Self::read_tier(&env, tier_id)?;
+
+        Ok(Self::wallet_allowance(&env, &buyer, &tier))
+    }
+
+    /// Get how many tickets an address has bought on the primary market.
+    /// Transfers and resales do not reduce this count.
+    pub fn get_purchased(env: Env, buyer: Address) -> u32 {
+        Self::extend_instance_ttl(&env);
+        Self::read_purchased(&env, &DataKey::Purchased(buyer))
+    }
+
+    /// Get the event-wide cap on primary purchases per address (0 for no cap)
+    pub fn get_max_per_wallet(env: Env) -> u32 {
+        Self::extend_instance_ttl(&env);
+        Self::read_max_per_wallet(&env)
+    }
+
+    /// Get number of tickets of a tier still available in primary market
+    pub fn get_tier_available(env: Env, tier_id: u32) -> Result<u32, Error> {
+        Self::extend_instance_ttl(&env);
+        let tier = Self::read_tier(&env, tier_id)?;
+
+        Ok(tier.supply - tier.minted)
+    }
+
+    /// Get number of tickets available in primary market
+    pub fn get_tickets_available(env: Env) -> Result<u32, Error> {
+        Self::extend_instance_ttl(&env);
+        let event_info = Self::read_event_info(&env)?;
+        let minted = Self::read_tickets_minted(&env);
+
+        Ok(event_info.total_supply - minted)
+    }
+
+    // === Helper Functions ===
+
+    fn read_event_info(env: &Env) -> Result<EventInfo, Error> {
+        env.storage()
+            .instance()
+            .get(&DataKey::EventInfo)
+            .ok_or(Error::NotInitialized)
+    }
+
+    /// Validate the tier configuration and number the tiers
+    fn build_tiers(
+        env: &Env,
+        total_supply: u32,
+        configs: Vec<TierConfig>,
+    ) -> Result<Vec<Tier>, Error> {
+        if configs.len() > MAX_TIERS {
+            return Err(Error::TooManyTiers);
+        }
+
+        let mut tiers = Vec::new(env);
+        let mut tier_supply: u32 = 0;
+        for (tier_id, config) in configs.iter().enumerate() {
+            if config.supply == 0 {
+                return Err(Error::InvalidSupply);
+            }
+            if config.price <= 0 {
+                return Err(Error::InvalidPrice);
+            }
+            if config.creator_fee_bps > 10000 {
+                return Err(Error::InvalidFeeBps);
+            }
+            tier_supply = tier_supply
+                .checked_add(config.supply)
+                .ok_or(Error::InvalidSupply)?;
+
+            tiers.push_back(Tier {
+                tier_id: tier_id as u32,
+                name: config.name,
+                supply: config.supply,
+                price: config.price,
+                creator_fee_bps: config.creator_fee_bps,
+                max_per_wallet: config.max_per_wallet,
+                minted: 0,
+            });
+        }
+
+        if tier_supply != total_supply {
+            return Err(Error::InvalidSupply);
+        }
+
+        Ok(tiers)
+    }
+
+    fn read_tiers(env: &Env) -> Result<Vec<Tier>, Error> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Tiers)
+            .ok_or(Error::NotInitialized)
+    }
+
+    fn read_tier(env: &Env, tier_id: u32) -> Result<Tier, Error> {
+        Self::read_tiers(env)?
+            .get(tier_id)
+            .ok_or(Error::TierNotFound)
+    }
+
+    fn read_tickets_minted(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TicketsMinted)
+            .unwrap_or(0)
+    }
+
+    fn read_ticket(env: &Env, ticket_id: u32) -> Result<TicketData, Error> {
+        let key = DataKey::Ticket(ticket_id);
+        let ticket = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(Error::TicketNotFound)?;
+        Self::extend_persistent_ttl(env, &key);
+        Ok(ticket)
+    }
+
+    fn write_ticket(env: &Env, ticket: &TicketData) {
+        let key = DataKey::Ticket(ticket.ticket_id);
+        env.storage().persistent().set(&key, ticket);
+        Self::extend_persistent_ttl(env, &key);
+    }
+
+    /// Whether the ticket has a listing that has not lapsed
+    fn has_active_listing(env: &Env, ticket_id: u32) -> Result<bool, Error> {
+        match Self::load_listing(env, ticket_id) {
+            Some(listing) => Ok(Self::is_listing_active(
+                env,
+                &Self::read_event_info(env)?,
+                &listing,
+            )),
+            None => Ok(false),
+        }
+    }
+
+    /// Whether the ticket has an unexpired listing or an auction
+    fn is_for_sale(env: &Env, ticket_id: u32) -> Result<bool, Error> {
+        Ok(Self::load_auction(env, ticket_id).is_some()
+            || Self::has_active_listing(env, ticket_id)?)
+    }
+
+    fn load_auction(env: &Env, ticket_id: u32) -> Option<Auction> {
+        let key = DataKey::Auction(ticket_id);
+        let auction = env.storage().persistent().get(&key);
+        if auction.is_some() {
+            Self::extend_persistent_ttl(env, &key);
+        }
+        auction
+    }
+
+    fn write_auction(env: &Env, auction: &Auction) {
+        let key = DataKey::Auction(auction.ticket_id);
+        env.storage().persistent().set(&key, auction);
+        Self::extend_persistent_ttl(env, &key);
+    }
+
+    fn remove_auction(env: &Env, ticket_id: u32) {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Auction(ticket_id));
+    }
+
+    fn is_listing_active(env: &Env, event_info: &EventInfo, listing: &SecondaryListing) -> bool {
+        let now = env.ledger().timestamp();
+        now < event_info.schedule.event_start
+            && listing.expires_at.is_none_or(|expires_at| now < expires_at)
+    }
+
+    fn load_listing(env: &Env, ticket_id: u32) -> Option<SecondaryListing> {
+        let key = DataKey::SecondaryListing(ticket_id);
+        let mut fields: Map<Symbol, Val> = env.storage().persistent().get(&key)?;
+        Self::extend_persistent_ttl(env, &key);
+
+        // Version 1 contracts built before listings could expire stored no
+        // expiry field, and those listings never expire
+        let expires_at = Symbol::new(env, "expires_at");
+        if !fields.contains_key(expires_at.clone()) {
+            fields.set(expires_at, Val::VOID.to_val());
+        }
+        SecondaryListing::try_from_val(env, &fields.to_val()).ok()
+    }
+
+    fn read_listing(env: &Env, ticket_id: u32) -> Result<SecondaryListing, Error> {
+        Self::load_listing(env, ticket_id).ok_or(Error::NotListed)
+    }
+
+    fn write_listing(env: &Env, listing: &SecondaryListing) {
+        let key = DataKey::SecondaryListing(listing.ticket_id);
+        env.storage().persistent().set(&key, listing);
+        Self::extend_persistent_ttl(env, &key);
+    }
+
+    fn remove_listing(env: &Env, ticket_id: u32) {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::SecondaryListing(ticket_id));
+    }
+
+    fn load_collection_bid(env: &Env, bid_id: u32) -> Option<CollectionBid> {
+        let key = DataKey::CollectionBid(bid_id);
+        let bid = env.storage().persistent().get(&key);
+        if bid.is_some() {
+            Self::extend_persistent_ttl(env, &key);
+        }
+        bid
+    }
+
+    fn write_collection_bid(env: &Env, bid: &CollectionBid) {
+        let key = DataKey::CollectionBid(bid.bid_id);
+        env.storage().persistent().set(&key, bid);
+        Self::extend_persistent_ttl(env, &key);
+    }
+
+    fn remove_collection_bid(env: &Env, bid_id: u32) {
         env.storage()
-            .instance()
-            .set(&DataKey::Ticket(ticket_id), &ticket);
-        Self::add_ticket_to_user(&env, &to, ticket_id);
+            .persistent()
+            .remove(&DataKey::CollectionBid(bid_id));
     }
 
-    /// Mark ticket as used (check-in at event)
-    pub fn mark_ticket_used(env: Env, creator: Address, ticket_id: u32) {
-        creator.require_auth();
+    fn load_offer(env: &Env, ticket_id: u32, buyer: &Address) -> Option<TicketOffer> {
+        let key = DataKey::Offer(ticket_id, buyer.clone());
+        let offer = env.storage().persistent().get(&key);
+        if offer.is_some() {
+            Self::extend_persistent_ttl(env, &key);
+        }
+        offer
+    }
 
-        let event_info: EventInfo = env
-            .storage()
-            .instance()
-            .get(&DataKey::EventInfo)
-            .expect("Contract not initialized");
+    fn remove_offer(env: &Env, ticket_id: u32, buyer: &Address) {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Offer(ticket_id, buyer.clone()));
 
-        if creator != event_info.event_creator {
-            panic!("Only event creator can mark tickets as used");
+        let mut buyers = Self::read_ticket_offers(env, ticket_id);
+        if let Some(index) = buyers.first_index_of(buyer) {
+            buyers.remove(index);
         }
+        Self::write_ticket_offers(env, ticket_id, &buyers);
+    }
 
-        let mut ticket: TicketData = env
-            .storage()
-            .instance()
-            .get(&DataKey::Ticket(ticket_id))
-            .expect("Ticket does not exist");
+    fn read_ticket_offers(env: &Env, ticket_id: u32) -> Vec<Address> {
+        let key = DataKey::TicketOffers(ticket_id);
+        match env.storage().persistent().get(&key) {
+            Some(buyers) => {
+                Self::extend_persistent_ttl(env, &key);
+                buyers
+            }
+            None => Vec::new(env),
+        }
+    }
+
+    fn write_ticket_offers(env: &Env, ticket_id: u32, buyers: &Vec<Address>) {
+        let key = DataKey::TicketOffers(ticket_id);
+        if buyers.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, buyers);
+            Self::extend_persistent_ttl(env, &key);
+        }
+    }
+
+    /// Move an unused, unlisted ticket to a new owner and emit `Transfer`
+    fn transfer_unlisted(env: &Env, ticket: &TicketData, to: &Address) -> Result<(), Error> {
+        Self::require_not_cancelled(env)?;
 
         if ticket.is_used {
-            panic!("Ticket already used");
+            return Err(Error::TicketUsed);
         }
 
-        ticket.is_used = true;
+        // Check if listed or auctioned in secondary market
+        if Self::is_for_sale(env, ticket.ticket_id)? {
+            return Err(Error::AlreadyListed);
+        }
+
+        Self::move_ticket(env, ticket, to);
+
+        Transfer {
+            ticket_id: ticket.ticket_id,
+            from: ticket.owner.clone(),
+            to: to.clone(),
+        }
+        .publish(env);
+
+        Ok(())
+    }
+
+    /// Update ownership and both users' indexes, clearing any approval and
+    /// any lapsed listing
+    fn move_ticket(env: &Env, ticket: &TicketData, to: &Address) {
+        Self::remove_ticket_from_user(env, &ticket.owner, ticket.ticket_id);
+        Self::remove_approval(env, ticket.ticket_id);
+        Self::remove_listing(env, ticket.ticket_id);
+
+        let mut moved = ticket.clone();
+        moved.owner = to.clone();
+        Self::write_ticket(env, &moved);
+        Self::add_ticket_to_user(env, to, ticket.ticket_id);
+    }
+
+    fn read_approval(env: &Env, ticket_id: u32) -> Option<Address> {
+        let key = DataKey::Approval(ticket_id);
+        let approved = env.storage().persistent().get(&key);
+        if approved.is_some() {
+            Self::extend_persistent_ttl(env, &key);
+        }
+        approved
+    }
+
+    fn write_approval(env: &Env, ticket_id: u32, approved: &Address) {
+        let key = DataKey::Approval(ticket_id);
+        env.storage().persistent().set(&key, approved);
+        Self::extend_persistent_ttl(env, &key);
+    }
+
+    fn remove_approval(env: &Env, ticket_id: u32) {
         env.storage()
-            .instance()
-            .set(&DataKey::Ticket(ticket_id), &ticket);
+            .persistent()
+            .remove(&DataKey::Approval(ticket_id));
+    }
 
-        // Remove from secondary market if listed
-        if env
-            .storage()
-            .instance()
-            .has(&DataKey::SecondaryListing(ticket_id))
-        {
-            env.storage()
-                .instance()
-                .remove(&DataKey::SecondaryListing(ticket_id));
+    fn read_approval_for_all(env: &Env, owner: &Address, operator: &Address) -> bool {
+        let key = DataKey::ApprovalForAll(owner.clone(), operator.clone());
+        let approved = env.storage().persistent().has(&key);
+        if approved {
+            Self::extend_persistent_ttl(env, &key);
         }
+        approved
     }
 
-    // === Query Functions ===
+    /// Authorize `creator`, check they created the event and return the event
+    fn require_creator(env: &Env, creator: &Address) -> Result<EventInfo, Error> {
+        creator.require_auth();
+        Self::extend_instance_ttl(env);
 
-    // --- NEW: Name and Symbol Getters ---
+        let event_info = Self::read_event_info(env)?;
 
-    /// Get the name of the event/ticket collection
-    pub fn name(env: Env) -> String {
-        let event_info: EventInfo = env
-            .storage()
-            .instance()
-            .get(&DataKey::EventInfo)
-            .expect("Contract not initialized");
-        event_info.name
+        if *creator != event_info.event_creator {
+            return Err(Error::NotCreator);
+        }
+
+        Ok(event_info)
     }
 
-    /// Get the symbol of the ticket
-    pub fn symbol(env: Env) -> String {
-        let event_info: EventInfo = env
-            .storage()
+    fn require_metadata_editor(env: &Env, creator: &Address) -> Result<(), Error> {
+        Self::require_creator(env, creator)?;
+
+        if Self::read_metadata_frozen(env) {
+            return Err(Error::MetadataFrozen);
+        }
+
+        Ok(())
+    }
+
+    fn read_escrow(env: &Env) -> bool {
+        env.storage()
             .instance()
-            .get(&DataKey::EventInfo)
-            .expect("Contract not initialized");
-        event_info.symbol
+            .get(&DataKey::Escrow)
+            .unwrap_or(false)
+            || Self::read_dutch_auction(env).is_some_and(|auction| auction.rebate)
+    }
+
+    fn read_dutch_auction(env: &Env) -> Option<DutchAuction> {
+        env.storage().instance().get(&DataKey::DutchAuction)
     }
 
-    // ------------------------------------
+    /// The current Dutch auction price, if the tier is sold that way
+    fn dutch_price(env: &Env, event_info: &EventInfo, tier_id: u32) -> Option<i128> {
+        let auction = Self::read_dutch_auction(env).filter(|auction| auction.tier_id == tier_id)?;
 
-    /// Get ticket information
-    pub fn get_ticket(env: Env, ticket_id: u32) -> TicketData {
+        let elapsed = env
+            .ledger()
+            .timestamp()
+            .saturating_sub(event_info.schedule.sale_start);
+        let drops = (elapsed / auction.drop_interval) as i128;
+        let price = auction
+            .start_price
+            .saturating_sub(auction.price_drop.saturating_mul(drops));
+
+        Some(price.max(auction.floor_price))
+    }
+
+    /// Remember the latest Dutch auction price, and who to rebate for what
+    fn record_dutch_sale(
+        env: &Env,
+        buyer: &Address,
+        first_id: u32,
+        quantity: u32,
+        unit_price: Option<i128>,
+    ) {
+        let Some(price) = unit_price else {
+            return;
+        };
         env.storage()
             .instance()
-            .get(&DataKey::Ticket(ticket_id))
-            .expect("Ticket does not exist")
+            .set(&DataKey::DutchLastPrice, &price);
+
+        if Self::read_dutch_auction(env).is_some_and(|auction| auction.rebate) {
+            for ticket_id in first_id..first_id + quantity {
+                let key = DataKey::DutchBuyer(ticket_id);
+                env.storage()
+                    .persistent()
+                    .set(&key, &(buyer.clone(), price));
+                Self::extend_persistent_ttl(env, &key);
+            }
+            Self::add_proceeds(env, &DataKey::RebatePaid, price * quantity as i128);
+            env.storage().instance().set(
+                &DataKey::RebateTickets,
+                &(Self::read_rebate_tickets(env) + quantity),
+            );
+        }
     }
 
-    /// Get event information
-    pub fn get_event_info(env: Env) -> EventInfo {
+    /// The original buyer of a Dutch auction ticket and the price they paid,
+    /// while its rebate is unclaimed
+    fn read_dutch_purchase(env: &Env, ticket_id: u32) -> Option<(Address, i128)> {
+        let key = DataKey::DutchBuyer(ticket_id);
+        let purchase = env.storage().persistent().get(&key);
+        if purchase.is_some() {
+            Self::extend_persistent_ttl(env, &key);
+        }
+        purchase
+    }
+
+    fn remove_dutch_purchase(env: &Env, ticket_id: u32, price: i128) {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::DutchBuyer(ticket_id));
+        Self::add_proceeds(env, &DataKey::RebatePaid, -price);
+        env.storage().instance().set(
+            &DataKey::RebateTickets,
+            &(Self::read_rebate_tickets(env) - 1),
+        );
+    }
+
+    fn read_rebate_tickets(env: &Env) -> u32 {
         env.storage()
             .instance()
-            .get(&DataKey::EventInfo)
-            .expect("Contract not initialized")
+            .get(&DataKey::RebateTickets)
+            .unwrap_or(0)
     }
 
-    /// Get tickets owned by user
-    pub fn get_user_tickets(env: Env, user: Address) -> Vec<u32> {
+    /// The lowest price a Dutch auction ticket can end up costing: the
+    /// clearing price once it is known, otherwise the floor
+    fn rebate_floor(env: &Env, event_info: &EventInfo) -> Result<i128, Error> {
+        let Some(auction) = Self::read_dutch_auction(env) else {
+            return Ok(0);
+        };
+        Ok(Self::clearing_price(env, event_info)?.unwrap_or(auction.floor_price))
+    }
+
+    /// The most that unclaimed rebates can still take out of escrow
+    fn rebate_reserve(env: &Env, event_info: &EventInfo) -> Result<i128, Error> {
+        let tickets = Self::read_rebate_tickets(env);
+        if tickets == 0 {
+            return Ok(0);
+        }
+        Ok(Self::read_proceeds(env, &DataKey::RebatePaid)
+            - Self::rebate_floor(env, event_info)? * tickets as i128)
+    }
+
+    fn clearing_price(env: &Env, event_info: &EventInfo) -> Result<Option<i128>, Error> {
+        let Some(auction) = Self::read_dutch_auction(env) else {
+            return Ok(None);
+        };
+        let tier = Self::read_tier(env, auction.tier_id)?;
+
+        if tier.minted < tier.supply && env.ledger().timestamp() < event_info.schedule.sale_end {
+            return Ok(None);
+        }
+
+        Ok(env.storage().instance().get(&DataKey::DutchLastPrice))
+    }
+
+    /// Ids from `start` (at least 1) covered by a page of `limit`, up to
+    /// `count`, so queries read a bounded number of entries
+    fn page(start: u32, limit: u32, count: u32) -> core::ops::RangeInclusive<u32> {
+        let first = start.max(1);
+        first..=first.saturating_add(limit).saturating_sub(1).min(count)
+    }
+
+    fn read_cancelled(env: &Env) -> bool {
         env.storage()
             .instance()
-            .get(&DataKey::UserTickets(user))
-            .unwrap_or(Vec::new(&env))
+            .get(&DataKey::Cancelled)
+            .unwrap_or(false)
     }
 
-    /// Get secondary listing
-    pub fn get_secondary_listing(env: Env, ticket_id: u32) -> Option<SecondaryListing> {
+    /// Secondary sales, like listings, close when the event starts
+    fn require_before_event_start(env: &Env) -> Result<(), Error> {
+        if env.ledger().timestamp() >= Self::read_event_info(env)?.schedule.event_start {
+            return Err(Error::ListingExpired);
+        }
+        Ok(())
+    }
+
+    fn require_not_cancelled(env: &Env) -> Result<(), Error> {
+        if Self::read_cancelled(env) {
+            return Err(Error::EventCancelled);
+        }
+        Ok(())
+    }
+
+    fn read_proceeds(env: &Env, key: &DataKey) -> i128 {
+        env.storage().instance().get(key).unwrap_or(0)
+    }
+
+    fn write_proceeds(env: &Env, key: &DataKey, amount: i128) {
+        env.storage().instance().set(key, &amount);
+    }
+
+    fn add_proceeds(env: &Env, key: &DataKey, amount: i128) {
+        Self::write_proceeds(env, key, Self::read_proceeds(env, key) + amount);
+    }
+
+    fn transfer_payment(
+        env: &Env,
+        event_info: &EventInfo,
+        from: &Address,
+        to: &Address,
+        amount: i128,
+    ) {
+        token::Client::new(env, &event_info.payment_token).transfer(from, to, &amount);
+    }
+
+    /// Everything held once the event has ended, less unclaimed rebates,
+    /// otherwise only the payments of checked-in tickets. Unused tickets of a
+    /// cancelled event stay refundable.
+    fn withdrawable_proceeds(env: &Env, event_info: &EventInfo) -> Result<i128, Error> {
+        if !Self::read_cancelled(env) && env.ledger().timestamp() >= event_info.schedule.event_end {
+            Ok(Self::read_proceeds(env, &DataKey::EscrowBalance)
+                - Self::rebate_reserve(env, event_info)?)
+        } else {
+            Ok(Self::read_proceeds(env, &DataKey::ReleasedProceeds))
+        }
+    }
+
+    fn read_claimable(env: &Env, owner: &Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Claimable(owner.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Add to what `owner` can collect with `claim_balance`, so a payee that
+    /// cannot receive the token does not block bids or settlement
+    fn credit(env: &Env, owner: &Address, amount: i128) {
+        let key = DataKey::Claimable(owner.clone());
+        env.storage()
+            .persistent()
+            .set(&key, &(Self::read_claimable(env, owner) + amount));
+        Self::extend_persistent_ttl(env, &key);
+    }
+
+    fn read_escrowed_payment(env: &Env, ticket_id: u32) -> Option<i128> {
+        let key = DataKey::EscrowedPayment(ticket_id);
+        let amount = env.storage().persistent().get(&key);
+        if amount.is_some() {
+            Self::extend_persistent_ttl(env, &key);
+        }
+        amount
+    }
+
+    fn read_metadata_frozen(env: &Env) -> bool {
         env.storage()
             .instance()
-            .get(&DataKey::SecondaryListing(ticket_id))
+            .get(&DataKey::MetadataFrozen)
+            .unwrap_or(false)
     }
 
-    /// Get all secondary listings
-    pub fn get_all_secondary_listings(env: Env) -> Vec<SecondaryListing> {
-        let tickets_minted: u32 = env
-            .storage()
+    fn read_ticket_uri(env: &Env, ticket_id: u32) -> Option<String> {
+        let key = DataKey::TicketUri(ticket_id);
+        let uri = env.storage().persistent().get(&key);
+        if uri.is_some() {
+            Self::extend_persistent_ttl(env, &key);
+        }
+        uri
+    }
+
+    /// Append the decimal ticket id to the base URI
+    fn join_uri(env: &Env, base_uri: &String, ticket_id: u32) -> String {
+        let mut buf = [0u8; MAX_BASE_URI_LEN as usize + 10];
+        let base_len = base_uri.len() as usize;
+        base_uri.copy_into_slice(&mut buf[..base_len]);
+
+        let mut digits = [0u8; 10];
+        let mut n = ticket_id;
+        let mut count = 0;
+        loop {
+            digits[count] = b'0' + (n % 10) as u8;
+            count += 1;
+            n /= 10;
+            if n == 0 {
+                break;
+            }
+        }
+        for i in 0..count {
+            buf[base_len + i] = digits[count - 1 - i];
+        }
+
+        String::from_bytes(env, &buf[..base_len + count])
+    }
+
+    fn read_user_tickets(env: &Env, user: &Address) -> Vec<u32> {
+        let key = DataKey::UserTickets(user.clone());
+        match env.storage().persistent().get(&key) {
+            Some(user_tickets) => {
+                Self::extend_persistent_ttl(env, &key);
+                user_tickets
+            }
+            None => Vec::new(env),
+        }
+    }
+
+    fn write_user_tickets(env: &Env, user: &Address, user_tickets: &Vec<u32>) {
+        let key = DataKey::UserTickets(user.clone());
+        env.storage().persistent().set(&key, user_tickets);
+        Self::extend_persistent_ttl(env, &key);
+    }
+
+    fn require_public_sale(env: &Env, event_info: &EventInfo) -> Result<(), Error> {
+        let now = env.ledger().timestamp();
+        if now < event_info.schedule.sale_start {
+            return Err(Error::SaleNotStarted);
+        }
+        if now >= event_info.schedule.sale_end {
+            return Err(Error::SaleEnded);
+        }
+        Ok(())
+    }
+
+    /// Sell `quantity` tickets of a tier to the buyer and return the first id.
+    /// Tickets cost the tier price unless a different `unit_price` is given.
+    fn mint(
+        env: &Env,
+        event_info: &EventInfo,
+        buyer: &Address,
+        tier_id: u32,
+        quantity: u32,
+        unit_price: Option<i128>,
+    ) -> Result<u32, Error> {
+        Self::require_not_cancelled(env)?;
+
+        if quantity == 0 {
+            return Err(Error::InvalidQuantity);
+        }
+
+        let mut tiers = Self::read_tiers(env)?;
+        let mut tier = tiers.get(tier_id).ok_or(Error::TierNotFound)?;
+        let tickets_minted = Self::read_tickets_minted(env);
+        let price = unit_price.unwrap_or(tier.price);
+
+        // Check the tier can cover the whole quantity
+        if quantity > tier.supply - tier.minted {
+            return Err(Error::SoldOut);
+        }
+
+        // Check the buyer stays within the wallet caps
+        if quantity > Self::wallet_allowance(env, buyer, &tier) {
+            return Err(Error::WalletLimitReached);
+        }
+        Self::record_purchase(env, buyer, &tier, quantity);
+
+        // Transfer payment from buyer to event creator in one go, less the
+        // protocol's share, or hold all of it in escrow
+        let total_price = price
+            .checked_mul(quantity as i128)
+            .ok_or(Error::InvalidQuantity)?;
+        let escrow = Self::read_escrow(env);
+        if escrow {
+            Self::transfer_payment(
+                env,
+                event_info,
+                buyer,
+                &env.current_contract_address(),
+                total_price,
+            );
+            Self::add_proceeds(env, &DataKey::EscrowBalance, total_price);
+        } else {
+            let mut creator_amount = total_price;
+            if let Some(protocol_fee) = Self::read_protocol_fee(env) {
+                let protocol_amount = total_price * protocol_fee.primary_bps as i128 / 10000;
+                if protocol_amount > 0 {
+                    Self::transfer_payment(
+                        env,
+                        event_info,
+                        buyer,
+                        &protocol_fee.treasury,
+                        protocol_amount,
+                    );
+                    creator_amount -= protocol_amount;
+                }
+            }
+            if creator_amount > 0 {
+                Self::transfer_payment(
+                    env,
+                    event_info,
+                    buyer,
+                    &event_info.event_creator,
+                    creator_amount,
+                );
+            }
+        }
+
+        // Create and store the new tickets
+        let first_id = tickets_minted + 1;
+        let mut user_tickets = Self::read_user_tickets(env, buyer);
+        for ticket_id in first_id..first_id + quantity {
+            let ticket = TicketData {
+                owner: buyer.clone(),
+                ticket_id,
+                tier_id,
+                is_used: false,
+            };
+            Self::write_ticket(env, &ticket);
+            user_tickets.push_back(ticket_id);
+
+            if escrow {
+                let key = DataKey::EscrowedPayment(ticket_id);
+                env.storage().persistent().set(&key, &price);
+                Self::extend_persistent_ttl(env, &key);
+            }
+
+            Mint {
+                ticket_id,
+                buyer: buyer.clone(),
+                tier_id,
+                price,
+            }
+            .publish(env);
+        }
+
+        env.storage()
             .instance()
-            .get(&DataKey::TicketsMinted)
-            .unwrap_or(0);
+            .set(&DataKey::TicketsMinted, &(tickets_minted + quantity));
 
-        let mut listings = Vec::new(&env);
-        for i in 1..=tickets_minted {
-            if let Some(listing) = env
+        tier.minted += quantity;
+        tiers.set(tier_id, tier);
+        env.storage().instance().set(&DataKey::Tiers, &tiers);
+
+        // Add to user's ticket list
+        Self::write_user_tickets(env, buyer, &user_tickets);
+
+        Ok(first_id)
+    }
+
+    /// Pay for a secondary sale from `payer`: the creator fee at the ticket's
+    /// tier rate is split between the royalty recipients, the protocol fee
+    /// goes to the treasury and the rest goes to the seller, if given.
+    /// Returns the creator and protocol fees.
+    fn pay_secondary_sale(
+        env: &Env,
+        event_info: &EventInfo,
+        payer: &Address,
+        seller: Option<&Address>,
+        ticket: &TicketData,
+        price: i128,
+    ) -> Result<(i128, i128), Error> {
+        let tier = Self::read_tier(env, ticket.tier_id)?;
+
+        // Calculate creator fee at the ticket's tier rate
+        let creator_fee = (price * tier.creator_fee_bps as i128) / 10000;
+
+        // Transfer the protocol's share
+        let mut protocol_amount = 0;
+        if let Some(protocol_fee) = Self::read_protocol_fee(env) {
+            protocol_amount = price * protocol_fee.secondary_bps as i128 / 10000;
+            if protocol_amount > 0 {
+                Self::transfer_payment(
+                    env,
+                    event_info,
+                    payer,
+                    &protocol_fee.treasury,
+                    protocol_amount,
+                );
+            }
+        }
+
+        let seller_amount = price - creator_fee - protocol_amount;
+
+        // Transfer creator fee, rounding each share down
+        if creator_fee > 0 {
+            let mut remainder = creator_fee;
+            for split in event_info.royalty_splits.iter() {
+                let share = creator_fee * split.bps as i128 / 10000;
+                if share > 0 {
+                    Self::transfer_payment(env, event_info, payer, &split.recipient, share);
+                    remainder -= share;
+                }
+            }
+            if remainder > 0 {
+                Self::transfer_payment(
+                    env,
+                    event_info,
+                    payer,
+                    &event_info.royalty_remainder_recipient,
+                    remainder,
+                );
+            }
+        }
+
+        // Transfer payment to seller
+        if let Some(seller) = seller.filter(|_| seller_amount > 0) {
+            Self::transfer_payment(env, event_info, payer, seller, seller_amount);
+        }
+
+        Ok((creator_fee, protocol_amount))
+    }
+
+    fn read_upgrade_authority(env: &Env) -> UpgradeAuthority {
+        match env.storage().instance().get(&DataKey::UpgradeAuthority) {
+            Some(authority) => authority,
+            None if env.storage().instance().has(&DataKey::Factory) => UpgradeAuthority::Factory,
+            None => UpgradeAuthority::Creator,
+        }
+    }
+
+    fn upgrade_authority_address(env: &Env) -> Result<Address, Error> {
+        match Self::read_upgrade_authority(env) {
+            UpgradeAuthority::Factory => env
                 .storage()
                 .instance()
-                .get::<DataKey, SecondaryListing>(&DataKey::SecondaryListing(i))
-            {
-                listings.push_back(listing);
+                .get(&DataKey::Factory)
+                .ok_or(Error::NoFactory),
+            UpgradeAuthority::Creator => Ok(Self::read_event_info(env)?.event_creator),
+        }
+    }
+
+    fn read_protocol_fee(env: &Env) -> Option<ProtocolFee> {
+        env.storage().instance().get(&DataKey::ProtocolFee)
+    }
+
+    fn read_resale_policy(env: &Env) -> ResalePolicy {
+        env.storage()
+            .instance()
+            .get(&DataKey::ResalePolicy)
+            .unwrap_or(ResalePolicy {
+                max_price: 0,
+                max_markup_bps: 0,
+                min_price: 0,
+            })
+    }
+
+    fn max_resale_price(env: &Env, ticket: &TicketData) -> Result<Option<i128>, Error> {
+        let policy = Self::read_resale_policy(env);
+
+        let mut cap = if policy.max_price > 0 {
+            Some(policy.max_price)
+        } else {
+            None
+        };
+
+        if policy.max_markup_bps > 0 {
+            let tier = Self::read_tier(env, ticket.tier_id)?;
+            let markup_cap = tier.price * policy.max_markup_bps as i128 / 10000;
+            cap = Some(cap.map_or(markup_cap, |max_price| max_price.min(markup_cap)));
+        }
+
+        Ok(cap)
+    }
+
+    fn check_resale_price(env: &Env, ticket: &TicketData, price: i128) -> Result<(), Error> {
+        if let Some(cap) = Self::max_resale_price(env, ticket)? {
+            if price > cap {
+                return Err(Error::PriceAboveCap);
             }
         }
-        listings
+
+        if price < Self::read_resale_policy(env).min_price {
+            return Err(Error::PriceBelowFloor);
+        }
+
+        Ok(())
     }
 
-    /// Get number of tickets minted
-    pub fn get_tickets_minted(env: Env) -> u32 {
+    fn ticket_range(env: &Env, first_id: u32, quantity: u32) -> Vec<u32> {
+        let mut ticket_ids = Vec::new(env);
+        for ticket_id in first_id..first_id + quantity {
+            ticket_ids.push_back(ticket_id);
+        }
+        ticket_ids
+    }
+
+    fn read_presale(env: &Env) -> Option<PresaleConfig> {
+        env.storage().instance().get(&DataKey::Presale)
+    }
+
+    /// Walk a Merkle proof from `leaf`, hashing each pair in sorted order
+    fn verify_proof(
+        env: &Env,
+        root: &BytesN<32>,
+        leaf: BytesN<32>,
+        proof: &Vec<BytesN<32>>,
+    ) -> bool {
+        let mut computed = leaf;
+        for sibling in proof.iter() {
+            computed = Self::hash_pair(env, &computed, &sibling);
+        }
+        computed == *root
+    }
+
+    fn hash_pair(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+        let (first, second) = if a <= b { (a, b) } else { (b, a) };
+        let mut data = Bytes::from_array(env, &first.to_array());
+        data.extend_from_array(&second.to_array());
+        env.crypto().sha256(&data).to_bytes()
+    }
+
+    fn read_max_per_wallet(env: &Env) -> u32 {
         env.storage()
             .instance()
-            .get(&DataKey::TicketsMinted)
+            .get(&DataKey::MaxPerWallet)
             .unwrap_or(0)
     }
 
-    /// Get number of tickets available in primary market
-    pub fn get_tickets_available(env: Env) -> u32 {
-        let event_info: EventInfo = env
-            .storage()
-            .instance()
-            .get(&DataKey::EventInfo)
-            .expect("Contract not initialized");
+    fn read_purchased(env: &Env, key: &DataKey) -> u32 {
+        match env.storage().persistent().get(key) {
+            Some(purchased) => {
+                Self::extend_persistent_ttl(env, key);
+                purchased
+            }
+            None => 0,
+        }
+    }
 
-        let minted: u32 = env
-            .storage()
-            .instance()
-            .get(&DataKey::TicketsMinted)
-            .unwrap_or(0);
+    fn write_purchased(env: &Env, key: &DataKey, purchased: u32) {
+        env.storage().persistent().set(key, &purchased);
+        Self::extend_persistent_ttl(env, key);
+    }
+
+    /// Tickets of the tier the buyer can still buy on the primary market
+    fn wallet_allowance(env: &Env, buyer: &Address, tier: &Tier) -> u32 {
+        let mut allowance = tier.supply - tier.minted;
+
+        let max_per_wallet = Self::read_max_per_wallet(env);
+        if max_per_wallet > 0 {
+            let purchased = Self::read_purchased(env, &DataKey::Purchased(buyer.clone()));
+            allowance = allowance.min(max_per_wallet.saturating_sub(purchased));
+        }
+
+        if tier.max_per_wallet > 0 {
+            let key = DataKey::TierPurchased(buyer.clone(), tier.tier_id);
+            let purchased = Self::read_purchased(env, &key);
+            allowance = allowance.min(tier.max_per_wallet.saturating_sub(purchased));
+        }
 
-        event_info.total_supply - minted
+        allowance
     }
 
-    // === Helper Functions ===
+    /// Add to the buyer's primary purchase counts. The event-wide count is
+    /// always kept so a cap set mid-sale still sees earlier purchases; the
+    /// per-tier count only matters for capped tiers.
+    fn record_purchase(env: &Env, buyer: &Address, tier: &Tier, quantity: u32) {
+        let key = DataKey::Purchased(buyer.clone());
+        let purchased = Self::read_purchased(env, &key);
+        Self::write_purchased(env, &key, purchased + quantity);
+
+        if tier.max_per_wallet > 0 {
+            let key = DataKey::TierPurchased(buyer.clone(), tier.tier_id);
+            let purchased = Self::read_purchased(env, &key);
+            Self::write_purchased(env, &key, purchased + quantity);
+        }
+    }
 
     fn add_ticket_to_user(env: &Env, user: &Address, ticket_id: u32) {
-        let mut user_tickets: Vec<u32> = env
-            .storage()
-            .instance()
-            .get(&DataKey::UserTickets(user.clone()))
-            .unwrap_or(Vec::new(env));
+        let mut user_tickets = Self::read_user_tickets(env, user);
 
         user_tickets.push_back(ticket_id);
-        env.storage()
-            .instance()
-            .set(&DataKey::UserTickets(user.clone()), &user_tickets);
+        Self::write_user_tickets(env, user, &user_tickets);
     }
 
     fn remove_ticket_from_user(env: &Env, user: &Address, ticket_id: u32) {
-        let mut user_tickets: Vec<u32> = env
-            .storage()
-            .instance()
-            .get(&DataKey::UserTickets(user.clone()))
-            .unwrap_or(Vec::new(env));
+        let user_tickets = Self::read_user_tickets(env, user);
 
         // Find and remove ticket_id
         let mut new_tickets = Vec::new(env);
@@ -489,9 +3428,49 @@ impl TicketMarketplace {
             }
         }
 
+        Self::write_user_tickets(env, user, &new_tickets);
+    }
+
+    /// The creator's policy, or else the default extension and the end of
+    /// the event
+    fn read_ttl_policy(env: &Env) -> TtlPolicy {
+        env.storage()
+            .instance()
+            .get(&DataKey::TtlPolicy)
+            .unwrap_or_else(|| TtlPolicy {
+                min_extension: DEFAULT_MIN_TTL_EXTENSION,
+                retain_until: Self::read_event_info(env)
+                    .map_or(0, |event_info| event_info.schedule.event_end),
+            })
+    }
+
+    /// Number of ledgers to keep an entry alive for under the current policy
+    fn ttl_extension(env: &Env) -> u32 {
+        let policy = Self::read_ttl_policy(env);
+        let max_ttl = env.storage().max_ttl();
+
+        let now = env.ledger().timestamp();
+        let until_retain =
+            policy.retain_until.saturating_sub(now) / LEDGER_CLOSE_SECONDS + DAY_IN_LEDGERS as u64;
+        let extension = until_retain.min(max_ttl as u64) as u32;
+
+        extension.max(policy.min_extension).min(max_ttl)
+    }
+
+    fn extend_instance_ttl(env: &Env) {
+        let extend_to = Self::ttl_extension(env);
         env.storage()
             .instance()
-            .set(&DataKey::UserTickets(user.clone()), &new_tickets);
+            .extend_ttl(extend_to.saturating_sub(DAY_IN_LEDGERS), extend_to);
+    }
+
+    fn extend_persistent_ttl(env: &Env, key: &DataKey) {
+        let extend_to = Self::ttl_extension(env);
+        env.storage().persistent().extend_ttl(
+            key,
+            extend_to.saturating_sub(DAY_IN_LEDGERS),
+            extend_to,
+        );
     }
 }
 
@@ -1,320 +1,3220 @@
-#[cfg(test)]
-mod test {
-    use super::*;
-    use soroban_sdk::{testutils::{Address as _, Events}, token, Address, Env, String};
-     use crate::TicketMarketplaceClient;
-     use crate::TicketMarketplace;
-
-    // Helper function to setup the test environment
-    fn setup_test<'a>(
-        env: &Env,
-    ) -> (TicketMarketplaceClient<'a>, token::Client<'a>, token::StellarAssetClient<'a>, Address, Address, Address, Address) {
-        env.mock_all_auths();
-
-        // 1. Register the Marketplace Contract
-        let contract_id = env.register_contract(None, TicketMarketplace);
-        let client = TicketMarketplaceClient::new(env, &contract_id);
-
-        // 2. Register a Mock Payment Token (like USDC or XLM)
-        let token_admin = Address::generate(env);
-        let token_id = env.register_stellar_asset_contract(token_admin.clone());
-        let token = token::Client::new(env, &token_id);
-        let token_admin_client = token::StellarAssetClient::new(env, &token_id);
-
-        // 3. Generate Users
-        let creator = Address::generate(env);
-        let seller = Address::generate(env);
-        let buyer = Address::generate(env);
-
-        (client, token, token_admin_client, token_admin, creator, seller, buyer)
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use soroban_sdk::{
+    testutils::{
+        storage::Instance as _, storage::Persistent as _, Address as _, AuthorizedFunction,
+        AuthorizedInvocation, Events, IssuerFlags, Ledger,
+    },
+    token, vec,
+    xdr::ToXdr,
+    Address, BytesN, Env, Event, IntoVal, String, Symbol, Val, Vec,
+};
+
+// Helper function to setup the test environment
+fn setup_test<'a>(
+    env: &Env,
+) -> (
+    TicketMarketplaceClient<'a>,
+    token::Client<'a>,
+    token::StellarAssetClient<'a>,
+    Address,
+    Address,
+    Address,
+) {
+    env.mock_all_auths();
+
+    // 1. Register the Marketplace Contract
+    let contract_id = env.register(TicketMarketplace, ());
+    let client = TicketMarketplaceClient::new(env, &contract_id);
+
+    // 2. Register a Mock Payment Token (like USDC or XLM)
+    let token_admin = Address::generate(env);
+    let token_id = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    let token = token::Client::new(env, &token_id);
+    let token_admin_client = token::StellarAssetClient::new(env, &token_id);
+
+    // 3. Generate Users
+    let creator = Address::generate(env);
+    let seller = Address::generate(env);
+    let buyer = Address::generate(env);
+
+    (client, token, token_admin_client, creator, seller, buyer)
+}
+
+const DAY_SECONDS: u64 = 86_400;
+
+// Sale open from genesis for 30 days, show the evening after it closes
+fn schedule() -> EventSchedule {
+    EventSchedule {
+        sale_start: 0,
+        sale_end: 30 * DAY_SECONDS,
+        event_start: 30 * DAY_SECONDS + DAY_SECONDS / 2,
+        event_end: 30 * DAY_SECONDS + DAY_SECONDS * 3 / 4,
     }
+}
+
+// Helper function to initialize an event priced at 10.0 units per ticket
+fn init_event(
+    env: &Env,
+    client: &TicketMarketplaceClient,
+    creator: &Address,
+    token: &token::Client,
+    total_supply: u32,
+    creator_fee_bps: u32,
+) {
+    client.init(
+        creator,
+        &total_supply,
+        &10_000_000,
+        &creator_fee_bps,
+        &String::from_str(env, "Test Event"),
+        &String::from_str(env, "Event Tickets"),
+        &String::from_str(env, "EVNT"),
+        &token.address,
+        &Vec::new(env),
+        &schedule(),
+    );
+}
+
+#[test]
+fn test_initialize() {
+    let env = Env::default();
+    let (client, token, _, creator, _, _) = setup_test(&env);
+
+    client.init(
+        &creator,
+        &100,
+        &10_000_000, // 1 Unit
+        &250,
+        &String::from_str(&env, "Test Event Metadata"),
+        &String::from_str(&env, "Concert Tickets"),
+        &String::from_str(&env, "CNCT"),
+        &token.address,
+        &Vec::new(&env),
+        &schedule(),
+    );
+
+    let event_info = client.get_event_info();
+    assert_eq!(event_info.total_supply, 100);
+    assert_eq!(event_info.primary_price, 10_000_000);
+    assert_eq!(event_info.creator_fee_bps, 250);
+    assert_eq!(client.name(), String::from_str(&env, "Concert Tickets"));
+    assert_eq!(client.symbol(), String::from_str(&env, "CNCT"));
+}
+
+#[test]
+fn test_mint_ticket() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, _, buyer) = setup_test(&env);
+
+    // Mint tokens to buyer so they can pay
+    token_admin.mint(&buyer, &100_000_000);
+
+    init_event(&env, &client, &creator, &token, 100, 250);
+
+    let ticket_id = client.mint_ticket(&buyer, &0);
+    assert_eq!(ticket_id, 1);
+
+    let ticket = client.get_ticket(&ticket_id);
+    assert_eq!(ticket.owner, buyer);
+    assert!(!ticket.is_used);
+
+    assert_eq!(client.get_tickets_minted(), 1);
+    assert_eq!(client.get_tickets_available(), 99);
+
+    // Verify payment was transferred
+    assert_eq!(token.balance(&creator), 10_000_000);
+}
+
+#[test]
+fn test_secondary_listing() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, seller, buyer) = setup_test(&env);
+
+    // Fund seller (to mint) and buyer (to buy secondary)
+    token_admin.mint(&seller, &100_000_000);
+    token_admin.mint(&buyer, &100_000_000);
+
+    init_event(&env, &client, &creator, &token, 100, 250);
+
+    // Seller mints ticket
+    let ticket_id = client.mint_ticket(&seller, &0);
+
+    // List on secondary market for 15.0 units
+    client.list_ticket(&seller, &ticket_id, &15_000_000, &None);
+
+    let listing = client.get_secondary_listing(&ticket_id);
+    assert!(listing.is_some());
+    assert_eq!(listing.unwrap().price, 15_000_000);
+
+    // Buyer purchases from secondary
+    client.buy_secondary_ticket(&buyer, &ticket_id);
+
+    let ticket = client.get_ticket(&ticket_id);
+    assert_eq!(ticket.owner, buyer);
+
+    // Listing should be removed
+    let listing_after = client.get_secondary_listing(&ticket_id);
+    assert!(listing_after.is_none());
+}
+
+#[test]
+fn test_delist_ticket() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, seller, _) = setup_test(&env);
+
+    token_admin.mint(&seller, &100_000_000);
+
+    init_event(&env, &client, &creator, &token, 100, 250);
+
+    let ticket_id = client.mint_ticket(&seller, &0);
+    client.list_ticket(&seller, &ticket_id, &15_000_000, &None);
+
+    // Delist
+    client.delist_ticket(&seller, &ticket_id);
+
+    let listing = client.get_secondary_listing(&ticket_id);
+    assert!(listing.is_none());
+}
+
+#[test]
+fn test_mark_ticket_used() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, _, buyer) = setup_test(&env);
+
+    token_admin.mint(&buyer, &100_000_000);
+
+    init_event(&env, &client, &creator, &token, 100, 250);
+
+    let ticket_id = client.mint_ticket(&buyer, &0);
+
+    // Check-in opens when the event starts
+    assert_eq!(
+        client.try_mark_ticket_used(&creator, &ticket_id),
+        Err(Ok(Error::ProceedsLocked))
+    );
+    set_timestamp(&env, schedule().event_start);
+
+    // Mark as used
+    client.mark_ticket_used(&creator, &ticket_id);
+
+    let ticket = client.get_ticket(&ticket_id);
+    assert!(ticket.is_used);
+}
+
+#[test]
+fn test_transfer_ticket() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, from, to) = setup_test(&env);
+
+    token_admin.mint(&from, &100_000_000);
+
+    init_event(&env, &client, &creator, &token, 100, 250);
+
+    let ticket_id = client.mint_ticket(&from, &0);
+
+    // Transfer ticket
+    client.transfer_ticket(&from, &to, &ticket_id);
+
+    let ticket = client.get_ticket(&ticket_id);
+    assert_eq!(ticket.owner, to);
+
+    let to_tickets = client.get_user_tickets(&to);
+    assert_eq!(to_tickets.len(), 1);
+}
+
+#[test]
+fn test_update_listing_price() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, seller, _) = setup_test(&env);
+
+    token_admin.mint(&seller, &100_000_000);
+
+    init_event(&env, &client, &creator, &token, 100, 250);
+
+    let ticket_id = client.mint_ticket(&seller, &0);
+    client.list_ticket(&seller, &ticket_id, &15_000_000, &None);
+
+    // Update price to 20.0
+    client.update_listing_price(&seller, &ticket_id, &20_000_000);
+
+    let listing = client.get_secondary_listing(&ticket_id);
+    assert_eq!(listing.unwrap().price, 20_000_000);
+}
 
-    #[test]
-    fn test_initialize() {
-        let env = Env::default();
-        let (client, _, _, _, creator, _, _) = setup_test(&env);
-        
-        // We need a dummy token address for initialization
-        let token_id = env.register_stellar_asset_contract(Address::generate(&env));
+#[test]
+fn test_mint_ticket_sold_out() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, _, buyer) = setup_test(&env);
 
-        client.initialize(
+    token_admin.mint(&buyer, &100_000_000);
+
+    // Initialize with only 1 ticket
+    init_event(&env, &client, &creator, &token, 1, 250);
+
+    client.mint_ticket(&buyer, &0);
+    assert_eq!(client.try_mint_ticket(&buyer, &0), Err(Ok(Error::SoldOut)));
+}
+
+#[test]
+fn test_creator_fee_calculation() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, seller, buyer) = setup_test(&env);
+
+    // Fund everyone
+    token_admin.mint(&seller, &100_000_000); // 100 to mint
+    token_admin.mint(&buyer, &500_000_000); // 500 to buy secondary
+
+    // 500 bps = 5% creator fee
+    init_event(&env, &client, &creator, &token, 100, 500);
+
+    let ticket_id = client.mint_ticket(&seller, &0);
+
+    // List for 200.00
+    let secondary_price = 200_000_000i128;
+    client.list_ticket(&seller, &ticket_id, &secondary_price, &None);
+
+    // Capture balances before trade
+    let creator_initial_bal = token.balance(&creator); // Should be 10.0 from the initial mint
+    let seller_initial_bal = token.balance(&seller);
+
+    // Buyer purchases
+    client.buy_secondary_ticket(&buyer, &ticket_id);
+
+    let ticket = client.get_ticket(&ticket_id);
+    assert_eq!(ticket.owner, buyer);
+
+    // Verify Balances
+    // Fee = 5% of 200 = 10
+    // Seller gets = 190
+
+    // Creator check: 10 (initial mint) + 10 (fee) = 20
+    let creator_final_bal = token.balance(&creator);
+    assert_eq!(creator_final_bal, creator_initial_bal + 10_000_000);
+
+    // Seller check: Initial - 10 (mint cost) + 190 (secondary sale)
+    // Since we captured balance AFTER mint cost, it's just Initial + 190
+    let seller_final_bal = token.balance(&seller);
+    assert_eq!(seller_final_bal, seller_initial_bal + 190_000_000);
+}
+
+// === Error Codes ===
+
+#[test]
+fn test_init_errors() {
+    let env = Env::default();
+    let (client, token, _, creator, _, _) = setup_test(&env);
+    let metadata = String::from_str(&env, "Test Event");
+    let name = String::from_str(&env, "Event Tickets");
+    let symbol = String::from_str(&env, "EVNT");
+
+    assert_eq!(
+        client.try_init(
+            &creator,
+            &0,
+            &10_000_000,
+            &250,
+            &metadata,
+            &name,
+            &symbol,
+            &token.address,
+            &Vec::new(&env),
+            &schedule()
+        ),
+        Err(Ok(Error::InvalidSupply))
+    );
+    assert_eq!(
+        client.try_init(
             &creator,
             &100,
-            &10_000_000, // 1 Unit
+            &0,
             &250,
-            &String::from_str(&env, "Test Event Metadata"),
-            &token_id,   // <--- Added Payment Token Address
-            &String::from_str(&env, "Concert Tickets"),
-            &String::from_str(&env, "CNCT"),
-        );
-
-        let event_info = client.get_event_info();
-        assert_eq!(event_info.total_supply, 100);
-        assert_eq!(event_info.primary_price, 10_000_000);
-        assert_eq!(event_info.creator_fee_bps, 250);
-        assert_eq!(client.name(), String::from_str(&env, "Concert Tickets"));
-        assert_eq!(client.symbol(), String::from_str(&env, "CNCT"));
-    }
-
-    #[test]
-    fn test_mint_ticket() {
-        let env = Env::default();
-        let (client, token, token_admin, token_admin_addr, creator, _, buyer) = setup_test(&env);
-
-        // Mint tokens to buyer so they can pay
-        token_admin.mint(&buyer, &100_000_000);
+            &metadata,
+            &name,
+            &symbol,
+            &token.address,
+            &Vec::new(&env),
+            &schedule()
+        ),
+        Err(Ok(Error::InvalidPrice))
+    );
+    assert_eq!(
+        client.try_init(
+            &creator,
+            &100,
+            &10_000_000,
+            &10_001,
+            &metadata,
+            &name,
+            &symbol,
+            &token.address,
+            &Vec::new(&env),
+            &schedule()
+        ),
+        Err(Ok(Error::InvalidFeeBps))
+    );
 
-        client.initialize(
+    init_event(&env, &client, &creator, &token, 100, 250);
+    assert_eq!(
+        client.try_init(
             &creator,
             &100,
             &10_000_000,
             &250,
-            &String::from_str(&env, "Test Event"),
-            &token.address, // Pass the token address
-            &String::from_str(&env, "Event Tickets"),
-            &String::from_str(&env, "EVNT"),
-        );
+            &metadata,
+            &name,
+            &symbol,
+            &token.address,
+            &Vec::new(&env),
+            &schedule()
+        ),
+        Err(Ok(Error::AlreadyInitialized))
+    );
+}
 
-        let ticket_id = client.mint_ticket(&buyer);
-        assert_eq!(ticket_id, 1);
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (client, _, _, creator, _, buyer) = setup_test(&env);
 
-        let ticket = client.get_ticket(&ticket_id);
-        assert_eq!(ticket.owner, buyer);
-        assert_eq!(ticket.is_used, false);
+    assert_eq!(
+        client.try_mint_ticket(&buyer, &0),
+        Err(Ok(Error::NotInitialized))
+    );
+    assert_eq!(client.try_get_event_info(), Err(Ok(Error::NotInitialized)));
+    assert_eq!(client.try_name(), Err(Ok(Error::NotInitialized)));
+    assert_eq!(
+        client.try_get_tickets_available(),
+        Err(Ok(Error::NotInitialized))
+    );
+    assert_eq!(
+        client.try_mark_ticket_used(&creator, &1),
+        Err(Ok(Error::NotInitialized))
+    );
+}
 
-        assert_eq!(client.get_tickets_minted(), 1);
-        assert_eq!(client.get_tickets_available(), 99);
-        
-        // Verify payment was transferred
-        assert_eq!(token.balance(&creator), 10_000_000);
-    }
+#[test]
+fn test_ticket_not_found() {
+    let env = Env::default();
+    let (client, token, _, creator, seller, buyer) = setup_test(&env);
+
+    init_event(&env, &client, &creator, &token, 100, 250);
+
+    assert_eq!(client.try_get_ticket(&1), Err(Ok(Error::TicketNotFound)));
+    assert_eq!(
+        client.try_list_ticket(&seller, &1, &15_000_000, &None),
+        Err(Ok(Error::TicketNotFound))
+    );
+    assert_eq!(
+        client.try_transfer_ticket(&seller, &buyer, &1),
+        Err(Ok(Error::TicketNotFound))
+    );
+    set_timestamp(&env, schedule().event_start);
+    assert_eq!(
+        client.try_mark_ticket_used(&creator, &1),
+        Err(Ok(Error::TicketNotFound))
+    );
+}
+
+#[test]
+fn test_listing_errors() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, seller, buyer) = setup_test(&env);
+
+    token_admin.mint(&seller, &100_000_000);
+    init_event(&env, &client, &creator, &token, 100, 250);
+
+    let ticket_id = client.mint_ticket(&seller, &0);
+
+    assert_eq!(
+        client.try_list_ticket(&seller, &ticket_id, &0, &None),
+        Err(Ok(Error::InvalidPrice))
+    );
+    assert_eq!(
+        client.try_list_ticket(&buyer, &ticket_id, &15_000_000, &None),
+        Err(Ok(Error::NotOwner))
+    );
+    assert_eq!(
+        client.try_buy_secondary_ticket(&buyer, &ticket_id),
+        Err(Ok(Error::NotListed))
+    );
+    assert_eq!(
+        client.try_delist_ticket(&seller, &ticket_id),
+        Err(Ok(Error::NotListed))
+    );
+    assert_eq!(
+        client.try_update_listing_price(&seller, &ticket_id, &20_000_000),
+        Err(Ok(Error::NotListed))
+    );
+
+    client.list_ticket(&seller, &ticket_id, &15_000_000, &None);
+
+    assert_eq!(
+        client.try_list_ticket(&seller, &ticket_id, &15_000_000, &None),
+        Err(Ok(Error::AlreadyListed))
+    );
+    assert_eq!(
+        client.try_transfer_ticket(&seller, &buyer, &ticket_id),
+        Err(Ok(Error::AlreadyListed))
+    );
+    assert_eq!(
+        client.try_delist_ticket(&buyer, &ticket_id),
+        Err(Ok(Error::NotSeller))
+    );
+    assert_eq!(
+        client.try_update_listing_price(&buyer, &ticket_id, &20_000_000),
+        Err(Ok(Error::NotSeller))
+    );
+    assert_eq!(
+        client.try_update_listing_price(&seller, &ticket_id, &-1),
+        Err(Ok(Error::InvalidPrice))
+    );
+}
+
+#[test]
+fn test_used_ticket_errors() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, seller, buyer) = setup_test(&env);
+
+    token_admin.mint(&seller, &100_000_000);
+    init_event(&env, &client, &creator, &token, 100, 250);
+
+    let ticket_id = client.mint_ticket(&seller, &0);
+    set_timestamp(&env, schedule().event_start);
+
+    assert_eq!(
+        client.try_mark_ticket_used(&seller, &ticket_id),
+        Err(Ok(Error::NotCreator))
+    );
+
+    client.mark_ticket_used(&creator, &ticket_id);
+
+    assert_eq!(
+        client.try_mark_ticket_used(&creator, &ticket_id),
+        Err(Ok(Error::TicketUsed))
+    );
+    assert_eq!(
+        client.try_list_ticket(&seller, &ticket_id, &15_000_000, &None),
+        Err(Ok(Error::TicketUsed))
+    );
+    assert_eq!(
+        client.try_transfer_ticket(&seller, &buyer, &ticket_id),
+        Err(Ok(Error::TicketUsed))
+    );
+    assert_eq!(
+        client.try_transfer_ticket(&buyer, &seller, &ticket_id),
+        Err(Ok(Error::NotOwner))
+    );
+}
+
+// === Sale Window ===
+
+fn set_timestamp(env: &Env, timestamp: u64) {
+    env.ledger().with_mut(|li| li.timestamp = timestamp);
+}
+
+#[test]
+fn test_sale_window() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, _, buyer) = setup_test(&env);
+
+    token_admin.mint(&buyer, &100_000_000);
+    let mut window = schedule();
+    window.sale_start = DAY_SECONDS;
+    client.init(
+        &creator,
+        &100,
+        &10_000_000,
+        &250,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Event Tickets"),
+        &String::from_str(&env, "EVNT"),
+        &token.address,
+        &Vec::new(&env),
+        &window,
+    );
+    assert_eq!(client.get_event_info().schedule, window);
+
+    assert!(!client.is_on_sale());
+    assert_eq!(
+        client.try_mint_ticket(&buyer, &0),
+        Err(Ok(Error::SaleNotStarted))
+    );
+
+    set_timestamp(&env, window.sale_start);
+    assert!(client.is_on_sale());
+    client.mint_ticket(&buyer, &0);
+
+    set_timestamp(&env, window.sale_end - 1);
+    client.mint_tickets(&buyer, &0, &2);
 
-    #[test]
-    fn test_secondary_listing() {
-        let env = Env::default();
-        let (client, token, token_admin, token_admin_addr, creator, seller, buyer) = setup_test(&env);
+    set_timestamp(&env, window.sale_end);
+    assert!(!client.is_on_sale());
+    assert_eq!(
+        client.try_mint_tickets(&buyer, &0, &2),
+        Err(Ok(Error::SaleEnded))
+    );
+    assert_eq!(client.get_tickets_minted(), 3);
+}
 
-        // Fund seller (to mint) and buyer (to buy secondary)
-        token_admin.mint( &seller, &100_000_000);
-        token_admin.mint( &buyer, &100_000_000);
+#[test]
+fn test_init_schedule_errors() {
+    let env = Env::default();
+    let (client, token, _, creator, _, _) = setup_test(&env);
 
-        client.initialize(
+    let try_init = |window: EventSchedule| {
+        client.try_init(
             &creator,
             &100,
             &10_000_000,
             &250,
             &String::from_str(&env, "Test Event"),
+            &String::from_str(&env, "Event Tickets"),
+            &String::from_str(&env, "EVNT"),
             &token.address,
-            &String::from_str(&env, "Festival Pass"),
-            &String::from_str(&env, "FEST"),
-        );
+            &Vec::new(&env),
+            &window,
+        )
+    };
+
+    let mut empty_sale = schedule();
+    empty_sale.sale_start = empty_sale.sale_end;
+    assert_eq!(try_init(empty_sale), Err(Ok(Error::InvalidSchedule)));
 
-        // Seller mints ticket
-        let ticket_id = client.mint_ticket(&seller);
+    let mut ends_before_start = schedule();
+    ends_before_start.event_end = ends_before_start.event_start - 1;
+    assert_eq!(try_init(ends_before_start), Err(Ok(Error::InvalidSchedule)));
 
-        // List on secondary market for 15.0 units
-        client.list_ticket(&seller, &ticket_id, &15_000_000);
+    let mut sale_after_event = schedule();
+    sale_after_event.sale_end = sale_after_event.event_end + 1;
+    assert_eq!(try_init(sale_after_event), Err(Ok(Error::InvalidSchedule)));
+}
 
-        let listing = client.get_secondary_listing(&ticket_id);
-        assert!(listing.is_some());
-        assert_eq!(listing.unwrap().price, 15_000_000);
+// === Presale ===
 
-        // Buyer purchases from secondary
-        client.buy_secondary_ticket(&buyer, &ticket_id);
+fn merkle_leaf(env: &Env, address: &Address) -> BytesN<32> {
+    env.crypto().sha256(&address.clone().to_xdr(env)).to_bytes()
+}
 
-        let ticket = client.get_ticket(&ticket_id);
-        assert_eq!(ticket.owner, buyer);
+// Allowlist of four fans, returning the root and each fan's proof
+fn allowlist(env: &Env, fans: &[Address; 4]) -> (BytesN<32>, [Vec<BytesN<32>>; 4]) {
+    let leaves = fans.each_ref().map(|fan| merkle_leaf(env, fan));
+    let left = TicketMarketplace::hash_pair(env, &leaves[0], &leaves[1]);
+    let right = TicketMarketplace::hash_pair(env, &leaves[2], &leaves[3]);
+    let root = TicketMarketplace::hash_pair(env, &left, &right);
 
-        // Listing should be removed
-        let listing_after = client.get_secondary_listing(&ticket_id);
-        assert!(listing_after.is_none());
+    let proofs = [
+        vec![env, leaves[1].clone(), right.clone()],
+        vec![env, leaves[0].clone(), right.clone()],
+        vec![env, leaves[3].clone(), left.clone()],
+        vec![env, leaves[2].clone(), left.clone()],
+    ];
+    (root, proofs)
+}
+
+// Single-tier event whose public sale opens on day two
+fn init_presale_event(
+    env: &Env,
+    client: &TicketMarketplaceClient,
+    creator: &Address,
+    token: &token::Client,
+) {
+    let mut window = schedule();
+    window.sale_start = 2 * DAY_SECONDS;
+    client.init(
+        creator,
+        &100,
+        &10_000_000,
+        &250,
+        &String::from_str(env, "Test Event"),
+        &String::from_str(env, "Event Tickets"),
+        &String::from_str(env, "EVNT"),
+        &token.address,
+        &Vec::new(env),
+        &window,
+    );
+}
+
+#[test]
+fn test_presale_mint() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, _, _) = setup_test(&env);
+    let fans = [
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    let outsider = Address::generate(&env);
+    for fan in fans.iter() {
+        token_admin.mint(fan, &100_000_000);
     }
+    token_admin.mint(&outsider, &100_000_000);
 
-    #[test]
-    fn test_delist_ticket() {
-        let env = Env::default();
-        let (client, token, token_admin, token_admin_addr, creator, seller, _) = setup_test(&env);
+    init_presale_event(&env, &client, &creator, &token);
+    let (merkle_root, proofs) = allowlist(&env, &fans);
+    let presale = PresaleConfig {
+        merkle_root,
+        tier_id: 0,
+        start: DAY_SECONDS,
+        price: 8_000_000,
+        max_per_wallet: 2,
+    };
+    client.set_presale(&creator, &presale);
+    assert_eq!(client.get_presale(), Some(presale));
 
-        token_admin.mint( &seller, &100_000_000);
+    assert_eq!(
+        client.try_mint_presale(&fans[0], &1, &proofs[0]),
+        Err(Ok(Error::PresaleNotActive))
+    );
 
-        client.initialize(
-            &creator,
-            &100,
-            &10_000_000,
-            &250,
-            &String::from_str(&env, "Test Event"),
-            &token.address,
-            &String::from_str(&env, "Sports Event"),
-            &String::from_str(&env, "SPRT"),
-        );
+    set_timestamp(&env, DAY_SECONDS);
+    assert_eq!(
+        client.mint_presale(&fans[2], &2, &proofs[2]),
+        vec![&env, 1, 2]
+    );
+    assert_eq!(client.mint_presale(&fans[1], &1, &proofs[1]), vec![&env, 3]);
+    assert_eq!(token.balance(&creator), 24_000_000);
+    assert_eq!(client.get_presale_purchased(&fans[2]), 2);
+    assert_eq!(client.get_purchased(&fans[2]), 2);
+
+    assert_eq!(
+        client.try_mint_presale(&fans[2], &1, &proofs[2]),
+        Err(Ok(Error::WalletLimitReached))
+    );
+    assert_eq!(
+        client.try_mint_presale(&outsider, &1, &proofs[0]),
+        Err(Ok(Error::NotOnAllowlist))
+    );
+    assert_eq!(
+        client.try_mint_presale(&fans[3], &1, &proofs[2]),
+        Err(Ok(Error::NotOnAllowlist))
+    );
+    assert_eq!(
+        client.try_mint_ticket(&outsider, &0),
+        Err(Ok(Error::SaleNotStarted))
+    );
+
+    // Once the public sale opens the presale closes
+    set_timestamp(&env, 2 * DAY_SECONDS);
+    assert_eq!(
+        client.try_mint_presale(&fans[3], &1, &proofs[3]),
+        Err(Ok(Error::PresaleNotActive))
+    );
+    client.mint_ticket(&outsider, &0);
+}
+
+#[test]
+fn test_set_presale_errors() {
+    let env = Env::default();
+    let (client, token, _, creator, _, buyer) = setup_test(&env);
+
+    init_presale_event(&env, &client, &creator, &token);
+    let presale = PresaleConfig {
+        merkle_root: BytesN::from_array(&env, &[0; 32]),
+        tier_id: 0,
+        start: DAY_SECONDS,
+        price: 8_000_000,
+        max_per_wallet: 2,
+    };
+
+    assert_eq!(
+        client.try_mint_presale(&buyer, &1, &Vec::new(&env)),
+        Err(Ok(Error::PresaleNotActive))
+    );
+    assert_eq!(
+        client.try_set_presale(&buyer, &presale),
+        Err(Ok(Error::NotCreator))
+    );
 
-        let ticket_id = client.mint_ticket(&seller);
-        client.list_ticket(&seller, &ticket_id, &15_000_000);
+    let mut late = presale.clone();
+    late.start = 2 * DAY_SECONDS;
+    assert_eq!(
+        client.try_set_presale(&creator, &late),
+        Err(Ok(Error::InvalidPresale))
+    );
 
-        // Delist
-        client.delist_ticket(&seller, &ticket_id);
+    let mut free = presale.clone();
+    free.price = 0;
+    assert_eq!(
+        client.try_set_presale(&creator, &free),
+        Err(Ok(Error::InvalidPrice))
+    );
 
-        let listing = client.get_secondary_listing(&ticket_id);
-        assert!(listing.is_none());
+    let mut no_tier = presale;
+    no_tier.tier_id = 1;
+    assert_eq!(
+        client.try_set_presale(&creator, &no_tier),
+        Err(Ok(Error::TierNotFound))
+    );
+}
+
+// === Tiers ===
+
+fn tier(env: &Env, name: &str, supply: u32, price: i128, creator_fee_bps: u32) -> TierConfig {
+    TierConfig {
+        name: String::from_str(env, name),
+        supply,
+        price,
+        creator_fee_bps,
+        max_per_wallet: 0,
     }
+}
 
-    #[test]
-    fn test_mark_ticket_used() {
-        let env = Env::default();
-        let (client, token, token_admin, token_admin_addr, creator, _, buyer) = setup_test(&env);
+// Initialize an event with GA, VIP and backstage tiers
+fn init_tiered_event(
+    env: &Env,
+    client: &TicketMarketplaceClient,
+    creator: &Address,
+    token: &token::Client,
+) {
+    client.init(
+        creator,
+        &13,
+        &10_000_000,
+        &250,
+        &String::from_str(env, "Test Event"),
+        &String::from_str(env, "Event Tickets"),
+        &String::from_str(env, "EVNT"),
+        &token.address,
+        &vec![
+            env,
+            tier(env, "GA", 10, 10_000_000, 250),
+            tier(env, "VIP", 2, 50_000_000, 1_000),
+            tier(env, "Backstage", 1, 100_000_000, 2_000),
+        ],
+        &schedule(),
+    );
+}
 
-        token_admin.mint( &buyer, &100_000_000);
+#[test]
+fn test_default_tier() {
+    let env = Env::default();
+    let (client, token, _, creator, _, _) = setup_test(&env);
 
-        client.initialize(
-            &creator,
-            &100,
-            &10_000_000,
-            &250,
-            &String::from_str(&env, "Test Event"),
-            &token.address,
-            &String::from_str(&env, "Theater Show"),
-            &String::from_str(&env, "SHOW"),
-        );
+    init_event(&env, &client, &creator, &token, 100, 250);
+
+    assert_eq!(
+        client.get_tiers(),
+        vec![
+            &env,
+            Tier {
+                tier_id: 0,
+                name: String::from_str(&env, "General Admission"),
+                supply: 100,
+                price: 10_000_000,
+                creator_fee_bps: 250,
+                max_per_wallet: 0,
+                minted: 0,
+            }
+        ]
+    );
+}
+
+#[test]
+fn test_mint_tiered_tickets() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, _, buyer) = setup_test(&env);
+
+    token_admin.mint(&buyer, &1_000_000_000);
+    init_tiered_event(&env, &client, &creator, &token);
+
+    let ga = client.mint_ticket(&buyer, &0);
+    let vip = client.mint_ticket(&buyer, &1);
+    let backstage = client.mint_ticket(&buyer, &2);
 
-        let ticket_id = client.mint_ticket(&buyer);
+    assert_eq!(client.get_ticket(&ga).tier_id, 0);
+    assert_eq!(client.get_ticket(&vip).tier_id, 1);
+    assert_eq!(client.get_ticket(&backstage).tier_id, 2);
+    assert_eq!(token.balance(&creator), 160_000_000);
 
-        // Mark as used
-        client.mark_ticket_used(&creator, &ticket_id);
+    assert_eq!(client.get_tier_available(&0), 9);
+    assert_eq!(client.get_tier_available(&1), 1);
+    assert_eq!(client.get_tier_available(&2), 0);
+    assert_eq!(client.get_tier(&1).minted, 1);
+    assert_eq!(client.get_tickets_available(), 10);
 
+    // One tier selling out leaves the others on sale
+    assert_eq!(client.try_mint_ticket(&buyer, &2), Err(Ok(Error::SoldOut)));
+    client.mint_ticket(&buyer, &1);
+    assert_eq!(client.try_mint_ticket(&buyer, &1), Err(Ok(Error::SoldOut)));
+    client.mint_ticket(&buyer, &0);
+
+    assert_eq!(
+        client.try_mint_ticket(&buyer, &3),
+        Err(Ok(Error::TierNotFound))
+    );
+    assert_eq!(client.try_get_tier(&3), Err(Ok(Error::TierNotFound)));
+}
+
+#[test]
+fn test_mint_tickets_batch() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, seller, buyer) = setup_test(&env);
+
+    token_admin.mint(&seller, &100_000_000);
+    token_admin.mint(&buyer, &1_000_000_000);
+    init_tiered_event(&env, &client, &creator, &token);
+
+    client.mint_ticket(&seller, &0);
+    let ticket_ids = client.mint_tickets(&buyer, &0, &4);
+    assert_eq!(ticket_ids, vec![&env, 2, 3, 4, 5]);
+
+    // A single payment for the whole batch
+    let payments: u32 = env
+        .events()
+        .all()
+        .iter()
+        .filter(|event| event.0 == token.address)
+        .count() as u32;
+    assert_eq!(payments, 1);
+    assert_eq!(token.balance(&creator), 50_000_000);
+
+    assert_eq!(client.get_user_tickets(&buyer), ticket_ids);
+    assert_eq!(client.get_tickets_minted(), 5);
+    assert_eq!(client.get_tier_available(&0), 5);
+    for ticket_id in ticket_ids.iter() {
         let ticket = client.get_ticket(&ticket_id);
-        assert_eq!(ticket.is_used, true);
+        assert_eq!(ticket.owner, buyer);
+        assert_eq!(ticket.tier_id, 0);
     }
+}
+
+#[test]
+fn test_mint_tickets_all_or_nothing() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, _, buyer) = setup_test(&env);
+
+    token_admin.mint(&buyer, &1_000_000_000);
+    init_tiered_event(&env, &client, &creator, &token);
+
+    client.mint_ticket(&buyer, &1);
+
+    // One VIP ticket is left, so a pair cannot be bought
+    assert_eq!(
+        client.try_mint_tickets(&buyer, &1, &2),
+        Err(Ok(Error::SoldOut))
+    );
+    assert_eq!(
+        client.try_mint_tickets(&buyer, &1, &0),
+        Err(Ok(Error::InvalidQuantity))
+    );
+    assert_eq!(client.get_tier_available(&1), 1);
+    assert_eq!(client.get_tickets_minted(), 1);
+    assert_eq!(client.balance(&buyer), 1);
+    assert_eq!(token.balance(&creator), 50_000_000);
+
+    assert_eq!(client.mint_tickets(&buyer, &1, &1), vec![&env, 2]);
+}
+
+#[test]
+fn test_max_per_wallet() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, seller, buyer) = setup_test(&env);
+
+    token_admin.mint(&buyer, &1_000_000_000);
+    init_tiered_event(&env, &client, &creator, &token);
+
+    assert_eq!(
+        client.try_set_max_per_wallet(&buyer, &3),
+        Err(Ok(Error::NotCreator))
+    );
+    client.set_max_per_wallet(&creator, &3);
+    assert_eq!(client.get_max_per_wallet(), 3);
+    assert_eq!(client.get_wallet_allowance(&buyer, &0), 3);
+
+    let ticket_ids = client.mint_tickets(&buyer, &0, &2);
+    assert_eq!(client.get_purchased(&buyer), 2);
+    assert_eq!(client.get_wallet_allowance(&buyer, &0), 1);
+
+    // Passing tickets on does not free up allowance
+    client.transfer_ticket(&buyer, &seller, &ticket_ids.get(0).unwrap());
+    assert_eq!(client.get_purchased(&buyer), 2);
+    assert_eq!(
+        client.try_mint_tickets(&buyer, &0, &2),
+        Err(Ok(Error::WalletLimitReached))
+    );
+
+    // The cap spans tiers
+    client.mint_ticket(&buyer, &1);
+    assert_eq!(client.get_wallet_allowance(&buyer, &0), 0);
+    assert_eq!(
+        client.try_mint_ticket(&buyer, &0),
+        Err(Ok(Error::WalletLimitReached))
+    );
+
+    // Lifting the cap leaves only the tier's supply as a limit
+    client.set_max_per_wallet(&creator, &0);
+    assert_eq!(client.get_wallet_allowance(&buyer, &0), 8);
+}
+
+#[test]
+fn test_tier_max_per_wallet() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, _, buyer) = setup_test(&env);
 
-    #[test]
-    fn test_transfer_ticket() {
-        let env = Env::default();
-        let (client, token, token_admin, token_admin_addr, creator, from, to) = setup_test(&env);
+    token_admin.mint(&buyer, &1_000_000_000);
+    let mut vip = tier(&env, "VIP", 10, 50_000_000, 1_000);
+    vip.max_per_wallet = 2;
+    client.init(
+        &creator,
+        &110,
+        &10_000_000,
+        &250,
+        &String::from_str(&env, "Test Event"),
+        &String::from_str(&env, "Event Tickets"),
+        &String::from_str(&env, "EVNT"),
+        &token.address,
+        &vec![&env, tier(&env, "GA", 100, 10_000_000, 250), vip],
+        &schedule(),
+    );
 
-        token_admin.mint( &from, &100_000_000);
+    assert_eq!(client.get_wallet_allowance(&buyer, &1), 2);
+    assert_eq!(
+        client.try_mint_tickets(&buyer, &1, &3),
+        Err(Ok(Error::WalletLimitReached))
+    );
+    client.mint_tickets(&buyer, &1, &2);
+    assert_eq!(client.get_wallet_allowance(&buyer, &1), 0);
+    assert_eq!(
+        client.try_mint_ticket(&buyer, &1),
+        Err(Ok(Error::WalletLimitReached))
+    );
 
-        client.initialize(
+    // Uncapped tiers are unaffected
+    client.mint_tickets(&buyer, &0, &5);
+    assert_eq!(client.get_purchased(&buyer), 7);
+}
+
+#[test]
+fn test_secondary_fee_uses_ticket_tier() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, seller, buyer) = setup_test(&env);
+
+    token_admin.mint(&seller, &100_000_000);
+    token_admin.mint(&buyer, &100_000_000);
+    init_tiered_event(&env, &client, &creator, &token);
+
+    let vip = client.mint_ticket(&seller, &1);
+    client.list_ticket(&seller, &vip, &60_000_000, &None);
+
+    let creator_before = token.balance(&creator);
+    let seller_before = token.balance(&seller);
+    client.buy_secondary_ticket(&buyer, &vip);
+
+    // VIP resales carry a 10% creator fee
+    assert_eq!(token.balance(&creator), creator_before + 6_000_000);
+    assert_eq!(token.balance(&seller), seller_before + 54_000_000);
+}
+
+#[test]
+fn test_init_tier_errors() {
+    let env = Env::default();
+    let (client, token, _, creator, _, _) = setup_test(&env);
+    let metadata = String::from_str(&env, "Test Event");
+    let name = String::from_str(&env, "Event Tickets");
+    let symbol = String::from_str(&env, "EVNT");
+
+    let try_init = |total_supply: u32, tiers: Vec<TierConfig>| {
+        client.try_init(
             &creator,
-            &100,
+            &total_supply,
             &10_000_000,
             &250,
-            &String::from_str(&env, "Test Event"),
+            &metadata,
+            &name,
+            &symbol,
             &token.address,
-            &String::from_str(&env, "VIP Pass"),
-            &String::from_str(&env, "VIP"),
-        );
+            &tiers,
+            &schedule(),
+        )
+    };
+
+    assert_eq!(
+        try_init(11, vec![&env, tier(&env, "GA", 10, 10_000_000, 250)]),
+        Err(Ok(Error::InvalidSupply))
+    );
+    assert_eq!(
+        try_init(0, vec![&env, tier(&env, "GA", 0, 10_000_000, 250)]),
+        Err(Ok(Error::InvalidSupply))
+    );
+    assert_eq!(
+        try_init(10, vec![&env, tier(&env, "GA", 10, 0, 250)]),
+        Err(Ok(Error::InvalidPrice))
+    );
+    assert_eq!(
+        try_init(10, vec![&env, tier(&env, "GA", 10, 10_000_000, 10_001)]),
+        Err(Ok(Error::InvalidFeeBps))
+    );
 
-        let ticket_id = client.mint_ticket(&from);
+    let mut too_many = Vec::new(&env);
+    for _ in 0..=MAX_TIERS {
+        too_many.push_back(tier(&env, "GA", 1, 10_000_000, 250));
+    }
+    assert_eq!(
+        try_init(MAX_TIERS + 1, too_many),
+        Err(Ok(Error::TooManyTiers))
+    );
+}
 
-        // Transfer ticket
-        client.transfer_ticket(&from, &to, &ticket_id);
+// === Protocol Fee ===
 
-        let ticket = client.get_ticket(&ticket_id);
-        assert_eq!(ticket.owner, to);
+#[test]
+fn test_set_factory() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, _, buyer) = setup_test(&env);
+    let factory = Address::generate(&env);
+    let treasury = Address::generate(&env);
+
+    token_admin.mint(&buyer, &100_000_000);
+    init_event(&env, &client, &creator, &token, 100, 9_000);
+
+    let protocol_fee = ProtocolFee {
+        primary_bps: 250,
+        secondary_bps: 1_001,
+        treasury: treasury.clone(),
+    };
+    assert_eq!(
+        client.try_set_factory(&factory, &protocol_fee),
+        Err(Ok(Error::InvalidFeeBps))
+    );
+    assert_eq!(
+        client.try_set_factory(
+            &factory,
+            &ProtocolFee {
+                secondary_bps: u32::MAX,
+                ..protocol_fee.clone()
+            }
+        ),
+        Err(Ok(Error::InvalidFeeBps))
+    );
+
+    let protocol_fee = ProtocolFee {
+        secondary_bps: 500,
+        ..protocol_fee
+    };
+    client.set_factory(&factory, &protocol_fee);
+    assert_eq!(client.get_factory(), Some(factory.clone()));
+    assert_eq!(client.get_protocol_fee(), Some(protocol_fee.clone()));
+    assert_eq!(
+        client.try_set_factory(&factory, &protocol_fee),
+        Err(Ok(Error::FactoryAlreadySet))
+    );
+
+    client.mint_tickets(&buyer, &0, &4);
+    assert_eq!(token.balance(&treasury), 1_000_000);
+    assert_eq!(token.balance(&creator), 39_000_000);
+}
+
+#[test]
+fn test_set_factory_after_sales() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, _, buyer) = setup_test(&env);
+
+    token_admin.mint(&buyer, &100_000_000);
+    init_event(&env, &client, &creator, &token, 100, 250);
+    client.mint_ticket(&buyer, &0);
+
+    let factory = Address::generate(&env);
+    assert_eq!(
+        client.try_set_factory(
+            &factory,
+            &ProtocolFee {
+                primary_bps: 250,
+                secondary_bps: 250,
+                treasury: factory.clone(),
+            }
+        ),
+        Err(Ok(Error::TicketsAlreadySold))
+    );
+    assert_eq!(client.get_protocol_fee(), None);
+}
+
+#[test]
+fn test_upgrade_authority() {
+    let env = Env::default();
+    let (client, token, _, creator, _, _) = setup_test(&env);
+
+    init_event(&env, &client, &creator, &token, 100, 250);
+    assert_eq!(client.version(), CONTRACT_VERSION);
+
+    // Without a factory only the creator can upgrade
+    assert_eq!(client.get_upgrade_authority(), UpgradeAuthority::Creator);
+    assert_eq!(
+        client.try_set_upgrade_authority(&UpgradeAuthority::Factory),
+        Err(Ok(Error::NoFactory))
+    );
+
+    let factory = Address::generate(&env);
+    client.set_factory(
+        &factory,
+        &ProtocolFee {
+            primary_bps: 0,
+            secondary_bps: 0,
+            treasury: factory.clone(),
+        },
+    );
+    assert_eq!(client.get_upgrade_authority(), UpgradeAuthority::Factory);
+
+    client.set_upgrade_authority(&UpgradeAuthority::Creator);
+    assert_eq!(
+        env.auths(),
+        std::vec![(
+            factory.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    client.address.clone(),
+                    Symbol::new(&env, "set_upgrade_authority"),
+                    (UpgradeAuthority::Creator,).into_val(&env),
+                )),
+                sub_invocations: std::vec![],
+            }
+        )]
+    );
+    assert_eq!(client.get_upgrade_authority(), UpgradeAuthority::Creator);
+}
+
+// === Royalty Splits ===
+
+#[test]
+fn test_royalty_splits() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, seller, buyer) = setup_test(&env);
+    let artist = Address::generate(&env);
+    let venue = Address::generate(&env);
+    let promoter = Address::generate(&env);
 
-        let to_tickets = client.get_user_tickets(&to);
-        assert_eq!(to_tickets.len(), 1);
+    token_admin.mint(&seller, &100_000_000);
+    token_admin.mint(&buyer, &100_000_000);
+    init_event(&env, &client, &creator, &token, 100, 1_000);
+
+    // Artist 50%, venue 33.33%, the promoter takes the rest and the dust
+    let splits = vec![
+        &env,
+        RoyaltySplit {
+            recipient: artist.clone(),
+            bps: 5_000,
+        },
+        RoyaltySplit {
+            recipient: venue.clone(),
+            bps: 3_333,
+        },
+    ];
+    client.set_royalty_splits(&creator, &splits, &promoter);
+    let event_info = client.get_event_info();
+    assert_eq!(event_info.royalty_splits, splits);
+    assert_eq!(event_info.royalty_remainder_recipient, promoter);
+
+    let ticket_id = client.mint_ticket(&seller, &0);
+    client.list_ticket(&seller, &ticket_id, &12_345_679, &None);
+    let creator_before = token.balance(&creator);
+    let seller_before = token.balance(&seller);
+    client.buy_secondary_ticket(&buyer, &ticket_id);
+
+    // Fee is 10% of 12_345_679 = 1_234_567
+    assert_eq!(token.balance(&artist), 617_283);
+    assert_eq!(token.balance(&venue), 411_481);
+    assert_eq!(token.balance(&promoter), 1_234_567 - 617_283 - 411_481);
+    assert_eq!(token.balance(&creator), creator_before);
+    assert_eq!(
+        token.balance(&seller),
+        seller_before + 12_345_679 - 1_234_567
+    );
+}
+
+#[test]
+fn test_royalty_split_errors() {
+    let env = Env::default();
+    let (client, token, _, creator, seller, _) = setup_test(&env);
+
+    init_event(&env, &client, &creator, &token, 100, 250);
+
+    let split = |bps: u32| RoyaltySplit {
+        recipient: seller.clone(),
+        bps,
+    };
+    assert_eq!(
+        client.try_set_royalty_splits(&seller, &vec![&env, split(100)], &seller),
+        Err(Ok(Error::NotCreator))
+    );
+    assert_eq!(
+        client.try_set_royalty_splits(&creator, &vec![&env, split(6_000), split(4_001)], &creator),
+        Err(Ok(Error::InvalidRoyaltySplits))
+    );
+    assert_eq!(
+        client.try_set_royalty_splits(&creator, &vec![&env, split(0)], &creator),
+        Err(Ok(Error::InvalidRoyaltySplits))
+    );
+
+    let mut too_many = Vec::new(&env);
+    for _ in 0..=MAX_ROYALTY_SPLITS {
+        too_many.push_back(split(1));
     }
+    assert_eq!(
+        client.try_set_royalty_splits(&creator, &too_many, &creator),
+        Err(Ok(Error::InvalidRoyaltySplits))
+    );
+
+    client.set_royalty_splits(&creator, &vec![&env, split(6_000), split(4_000)], &creator);
+    assert_eq!(client.get_event_info().royalty_splits.len(), 2);
+}
+
+// === Resale Price Controls ===
+
+#[test]
+fn test_resale_cap_relative_to_tier_price() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, seller, _) = setup_test(&env);
+
+    token_admin.mint(&seller, &1_000_000_000);
+    init_tiered_event(&env, &client, &creator, &token);
+
+    let ga = client.mint_ticket(&seller, &0);
+    let vip = client.mint_ticket(&seller, &1);
+
+    // Resales capped at 120% of face value
+    let policy = ResalePolicy {
+        max_price: 0,
+        max_markup_bps: 12_000,
+        min_price: 0,
+    };
+    client.set_resale_policy(&creator, &policy);
+    assert_eq!(client.get_resale_policy(), policy);
+    assert_eq!(client.get_max_resale_price(&ga), Some(12_000_000));
+    assert_eq!(client.get_max_resale_price(&vip), Some(60_000_000));
+
+    assert_eq!(
+        client.try_list_ticket(&seller, &ga, &12_000_001, &None),
+        Err(Ok(Error::PriceAboveCap))
+    );
+    client.list_ticket(&seller, &ga, &12_000_000, &None);
+    client.list_ticket(&seller, &vip, &60_000_000, &None);
 
-    #[test]
-    fn test_update_listing_price() {
-        let env = Env::default();
-        let (client, token, token_admin, token_admin_addr, creator, seller, _) = setup_test(&env);
+    assert_eq!(
+        client.try_update_listing_price(&seller, &vip, &60_000_001),
+        Err(Ok(Error::PriceAboveCap))
+    );
+    client.update_listing_price(&seller, &vip, &55_000_000);
 
-        token_admin.mint( &seller, &100_000_000);
+    // An absolute cap applies when it is the lower of the two
+    client.set_resale_policy(
+        &creator,
+        &ResalePolicy {
+            max_price: 50_000_000,
+            max_markup_bps: 12_000,
+            min_price: 0,
+        },
+    );
+    assert_eq!(client.get_max_resale_price(&ga), Some(12_000_000));
+    assert_eq!(client.get_max_resale_price(&vip), Some(50_000_000));
+    assert_eq!(
+        client.try_update_listing_price(&seller, &vip, &55_000_000),
+        Err(Ok(Error::PriceAboveCap))
+    );
+}
+
+#[test]
+fn test_resale_floor_and_policy_errors() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, seller, _) = setup_test(&env);
+
+    token_admin.mint(&seller, &100_000_000);
+    init_event(&env, &client, &creator, &token, 100, 250);
+
+    let ticket_id = client.mint_ticket(&seller, &0);
+    assert_eq!(client.get_max_resale_price(&ticket_id), None);
 
-        client.initialize(
+    let policy = ResalePolicy {
+        max_price: 15_000_000,
+        max_markup_bps: 0,
+        min_price: 5_000_000,
+    };
+    assert_eq!(
+        client.try_set_resale_policy(&seller, &policy),
+        Err(Ok(Error::NotCreator))
+    );
+    assert_eq!(
+        client.try_set_resale_policy(
             &creator,
-            &100,
-            &10_000_000,
-            &250,
-            &String::from_str(&env, "Test Event"),
-            &token.address,
-            &String::from_str(&env, "Conference Pass"),
-            &String::from_str(&env, "CONF"),
-        );
+            &ResalePolicy {
+                max_price: 5_000_000,
+                max_markup_bps: 0,
+                min_price: 15_000_000,
+            }
+        ),
+        Err(Ok(Error::InvalidResalePolicy))
+    );
+    client.set_resale_policy(&creator, &policy);
 
-        let ticket_id = client.mint_ticket(&seller);
-        client.list_ticket(&seller, &ticket_id, &15_000_000);
+    assert_eq!(
+        client.try_list_ticket(&seller, &ticket_id, &4_999_999, &None),
+        Err(Ok(Error::PriceBelowFloor))
+    );
+    client.list_ticket(&seller, &ticket_id, &5_000_000, &None);
+    assert_eq!(
+        client.try_update_listing_price(&seller, &ticket_id, &1),
+        Err(Ok(Error::PriceBelowFloor))
+    );
+    assert_eq!(
+        client.try_update_listing_price(&seller, &ticket_id, &15_000_001),
+        Err(Ok(Error::PriceAboveCap))
+    );
+}
 
-        // Update price to 20.0
-        client.update_listing_price(&seller, &ticket_id, &20_000_000);
+// === Dutch Auction ===
 
-        let listing = client.get_secondary_listing(&ticket_id);
-        assert_eq!(listing.unwrap().price, 20_000_000);
+fn dutch_auction(rebate: bool) -> DutchAuction {
+    DutchAuction {
+        tier_id: 0,
+        start_price: 20_000_000,
+        floor_price: 10_000_000,
+        price_drop: 2_000_000,
+        drop_interval: DAY_SECONDS,
+        rebate,
     }
+}
+
+#[test]
+fn test_dutch_auction_pricing() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, _, buyer) = setup_test(&env);
+
+    token_admin.mint(&buyer, &100_000_000);
+    init_tiered_event(&env, &client, &creator, &token);
+    client.set_dutch_auction(&creator, &dutch_auction(false));
+    assert_eq!(client.get_dutch_auction(), Some(dutch_auction(false)));
+    assert!(!client.is_escrowed());
+
+    // The price steps down once a day until it reaches the floor
+    assert_eq!(client.get_current_price(&0), 20_000_000);
+    set_timestamp(&env, DAY_SECONDS - 1);
+    assert_eq!(client.get_current_price(&0), 20_000_000);
+    set_timestamp(&env, 2 * DAY_SECONDS);
+    assert_eq!(client.get_current_price(&0), 16_000_000);
+    set_timestamp(&env, 20 * DAY_SECONDS);
+    assert_eq!(client.get_current_price(&0), 10_000_000);
+
+    // Other tiers keep their fixed price
+    assert_eq!(client.get_current_price(&1), 50_000_000);
+
+    set_timestamp(&env, 3 * DAY_SECONDS);
+    assert_eq!(client.mint_tickets(&buyer, &0, &2), vec![&env, 1, 2]);
+    assert_eq!(token.balance(&creator), 28_000_000);
+    assert_eq!(client.get_clearing_price(), None);
+}
+
+#[test]
+fn test_dutch_auction_rebates() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, seller, buyer) = setup_test(&env);
+
+    token_admin.mint(&seller, &100_000_000);
+    token_admin.mint(&buyer, &100_000_000);
+    init_tiered_event(&env, &client, &creator, &token);
+    client.set_dutch_auction(&creator, &dutch_auction(true));
+    assert!(client.is_escrowed());
+
+    let early_ticket = client.mint_ticket(&buyer, &0);
+    set_timestamp(&env, 2 * DAY_SECONDS);
+    let late_ticket = client.mint_ticket(&seller, &0);
+    assert_eq!(client.get_escrow_balance(), 36_000_000);
+
+    // The clearing price is only known once the sale is over
+    assert_eq!(
+        client.try_claim_rebate(&early_ticket),
+        Err(Ok(Error::AuctionNotEnded))
+    );
+
+    set_timestamp(&env, schedule().sale_end);
+    assert_eq!(client.get_clearing_price(), Some(16_000_000));
+    assert_eq!(client.claim_rebate(&early_ticket), 4_000_000);
+    assert_eq!(
+        marketplace_events(&env, &client),
+        vec![
+            &env,
+            published(
+                &env,
+                &client,
+                &Rebate {
+                    ticket_id: early_ticket,
+                    buyer: buyer.clone(),
+                    amount: 4_000_000,
+                }
+            )
+        ]
+    );
+    assert_eq!(token.balance(&buyer), 84_000_000);
+    assert_eq!(client.get_escrowed_payment(&early_ticket), Some(16_000_000));
+    assert_eq!(
+        client.try_claim_rebate(&early_ticket),
+        Err(Ok(Error::NotRefundable))
+    );
+    assert_eq!(
+        client.try_claim_rebate(&late_ticket),
+        Err(Ok(Error::NotRefundable))
+    );
+
+    // The creator gets the clearing price for every ticket
+    set_timestamp(&env, schedule().event_end);
+    assert_eq!(client.withdraw_proceeds(&creator), 32_000_000);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+fn test_dutch_rebates_survive_withdrawal_and_check_in() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, seller, buyer) = setup_test(&env);
+    let offerer = Address::generate(&env);
+
+    token_admin.mint(&seller, &100_000_000);
+    token_admin.mint(&buyer, &100_000_000);
+    token_admin.mint(&offerer, &100_000_000);
+    init_tiered_event(&env, &client, &creator, &token);
+    client.set_dutch_auction(&creator, &dutch_auction(true));
+
+    assert_eq!(client.mint_tickets(&buyer, &0, &2), vec![&env, 1, 2]);
+    set_timestamp(&env, 2 * DAY_SECONDS);
+    let late_ticket = client.mint_ticket(&seller, &0);
+    client.make_offer(&offerer, &late_ticket, &30_000_000, &(3 * DAY_SECONDS));
+
+    // Check-in releases the clearing price and keeps back the rebate
+    set_timestamp(&env, schedule().event_start);
+    client.mark_ticket_used(&creator, &2);
+    assert_eq!(client.get_escrowed_payment(&2), Some(4_000_000));
+
+    // The creator takes everything but the rebates still owed
+    set_timestamp(&env, schedule().event_end);
+    assert_eq!(client.get_withdrawable_proceeds(), 48_000_000);
+    assert_eq!(client.withdraw_proceeds(&creator), 48_000_000);
+    assert_eq!(client.get_escrow_balance(), 8_000_000);
+    assert_eq!(
+        client.try_withdraw_proceeds(&creator),
+        Err(Ok(Error::ProceedsLocked))
+    );
+
+    assert_eq!(client.claim_rebate(&1), 4_000_000);
+    assert_eq!(client.claim_rebate(&2), 4_000_000);
+    assert_eq!(
+        client.try_claim_rebate(&late_ticket),
+        Err(Ok(Error::NotRefundable))
+    );
+    assert_eq!(client.get_escrow_balance(), 0);
+    assert_eq!(client.get_escrowed_payment(&2), None);
+    assert_eq!(token.balance(&buyer), 68_000_000);
+
+    // Other escrowed funds were never touched
+    assert_eq!(token.balance(&client.address), 30_000_000);
+    client.cancel_offer(&offerer, &late_ticket);
+    assert_eq!(token.balance(&offerer), 100_000_000);
+    assert_eq!(token.balance(&client.address), 0);
+}
 
-    #[test]
-    #[should_panic(expected = "All tickets sold out")]
-    fn test_mint_ticket_sold_out() {
-        let env = Env::default();
-        let (client, token, token_admin, token_admin_addr, creator, _, buyer) = setup_test(&env);
+#[test]
+fn test_set_dutch_auction_errors() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, _, buyer) = setup_test(&env);
 
-        token_admin.mint( &buyer, &100_000_000);
+    token_admin.mint(&buyer, &100_000_000);
+    init_tiered_event(&env, &client, &creator, &token);
 
-        // Initialize with only 1 ticket
-        client.initialize(
+    assert_eq!(
+        client.try_set_dutch_auction(&buyer, &dutch_auction(false)),
+        Err(Ok(Error::NotCreator))
+    );
+    assert_eq!(
+        client.try_set_dutch_auction(
             &creator,
-            &1,
-            &10_000_000,
-            &250,
-            &String::from_str(&env, "Test Event"),
-            &token.address,
-            &String::from_str(&env, "Limited Edition"),
-            &String::from_str(&env, "LTD"),
-        );
+            &DutchAuction {
+                tier_id: 3,
+                ..dutch_auction(false)
+            }
+        ),
+        Err(Ok(Error::TierNotFound))
+    );
+    assert_eq!(
+        client.try_set_dutch_auction(
+            &creator,
+            &DutchAuction {
+                start_price: 9_999_999,
+                ..dutch_auction(false)
+            }
+        ),
+        Err(Ok(Error::InvalidPrice))
+    );
+    assert_eq!(
+        client.try_set_dutch_auction(
+            &creator,
+            &DutchAuction {
+                drop_interval: 0,
+                ..dutch_auction(false)
+            }
+        ),
+        Err(Ok(Error::InvalidSchedule))
+    );
 
-        client.mint_ticket(&buyer);
-        client.mint_ticket(&buyer); // Should panic
-    }
+    client.mint_ticket(&buyer, &0);
+    assert_eq!(
+        client.try_set_dutch_auction(&creator, &dutch_auction(false)),
+        Err(Ok(Error::TicketsAlreadySold))
+    );
+    assert_eq!(client.get_dutch_auction(), None);
+}
+
+// === Escrow ===
+
+#[test]
+fn test_cancel_event_refunds() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, seller, buyer) = setup_test(&env);
+
+    token_admin.mint(&seller, &100_000_000);
+    token_admin.mint(&buyer, &100_000_000);
+    init_tiered_event(&env, &client, &creator, &token);
+    client.set_escrow(&creator, &true);
+    assert!(client.is_escrowed());
+
+    // Payments stay in the contract
+    let ga_ticket = client.mint_ticket(&seller, &0);
+    let vip_ticket = client.mint_ticket(&buyer, &1);
+    let used_ticket = client.mint_ticket(&buyer, &0);
+    assert_eq!(token.balance(&client.address), 70_000_000);
+    assert_eq!(token.balance(&creator), 0);
+    assert_eq!(client.get_escrowed_payment(&vip_ticket), Some(50_000_000));
+
+    // Resales do not touch the escrow, so the new holder is refunded the
+    // original price
+    client.list_ticket(&seller, &ga_ticket, &15_000_000, &None);
+    client.buy_secondary_ticket(&buyer, &ga_ticket);
+
+    // The event starts, one ticket is checked in, and then it is called off
+    set_timestamp(&env, schedule().event_start);
+    client.mark_ticket_used(&creator, &used_ticket);
+
+    assert_eq!(
+        client.try_claim_refund(&vip_ticket),
+        Err(Ok(Error::EventNotCancelled))
+    );
+    assert_eq!(client.try_cancel_event(&seller), Err(Ok(Error::NotCreator)));
+
+    client.cancel_event(&creator);
+    assert_eq!(
+        marketplace_events(&env, &client),
+        vec![
+            &env,
+            published(
+                &env,
+                &client,
+                &Cancel {
+                    creator: creator.clone(),
+                }
+            )
+        ]
+    );
+    assert!(client.is_cancelled());
+    assert!(!client.is_on_sale());
+
+    let balance = token.balance(&buyer);
+    assert_eq!(client.claim_refund(&vip_ticket), 50_000_000);
+    assert_eq!(
+        marketplace_events(&env, &client),
+        vec![
+            &env,
+            published(
+                &env,
+                &client,
+                &Refund {
+                    ticket_id: vip_ticket,
+                    owner: buyer.clone(),
+                    amount: 50_000_000,
+                }
+            )
+        ]
+    );
+    assert_eq!(client.claim_refund(&ga_ticket), 10_000_000);
+    assert_eq!(token.balance(&buyer), balance + 60_000_000);
+    assert_eq!(client.get_escrowed_payment(&vip_ticket), None);
+
+    // Each ticket is refunded once, and used tickets not at all
+    assert_eq!(
+        client.try_claim_refund(&vip_ticket),
+        Err(Ok(Error::NotRefundable))
+    );
+    assert_eq!(
+        client.try_claim_refund(&used_ticket),
+        Err(Ok(Error::TicketUsed))
+    );
+    assert_eq!(token.balance(&client.address), 10_000_000);
+}
+
+#[test]
+fn test_cancelled_event_is_frozen() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, seller, buyer) = setup_test(&env);
+
+    token_admin.mint(&seller, &100_000_000);
+    init_event(&env, &client, &creator, &token, 100, 250);
+    let listed = client.mint_ticket(&seller, &0);
+    let unlisted = client.mint_ticket(&seller, &0);
+    client.list_ticket(&seller, &listed, &15_000_000, &None);
+
+    client.cancel_event(&creator);
+    assert_eq!(
+        client.try_cancel_event(&creator),
+        Err(Ok(Error::EventCancelled))
+    );
+
+    assert_eq!(
+        client.try_mint_ticket(&buyer, &0),
+        Err(Ok(Error::EventCancelled))
+    );
+    assert_eq!(
+        client.try_list_ticket(&seller, &unlisted, &15_000_000, &None),
+        Err(Ok(Error::EventCancelled))
+    );
+    assert_eq!(
+        client.try_update_listing_price(&seller, &listed, &12_000_000),
+        Err(Ok(Error::EventCancelled))
+    );
+    assert_eq!(
+        client.try_buy_secondary_ticket(&buyer, &listed),
+        Err(Ok(Error::EventCancelled))
+    );
+    assert_eq!(
+        client.try_transfer_ticket(&seller, &buyer, &unlisted),
+        Err(Ok(Error::EventCancelled))
+    );
+    assert_eq!(
+        client.try_mark_ticket_used(&creator, &unlisted),
+        Err(Ok(Error::EventCancelled))
+    );
+    assert_eq!(client.get_all_secondary_listings(&1, &100).len(), 0);
+
+    // Without escrow the creator was paid at mint, so there is nothing to refund
+    assert_eq!(
+        client.try_claim_refund(&unlisted),
+        Err(Ok(Error::NotRefundable))
+    );
+}
+
+#[test]
+fn test_cancellation_errors() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, _, buyer) = setup_test(&env);
+
+    token_admin.mint(&buyer, &100_000_000);
+    init_event(&env, &client, &creator, &token, 100, 250);
+    assert!(!client.is_escrowed());
+
+    assert_eq!(
+        client.try_set_escrow(&buyer, &true),
+        Err(Ok(Error::NotCreator))
+    );
+
+    // Escrow has to be chosen before the first sale
+    client.mint_ticket(&buyer, &0);
+    assert_eq!(
+        client.try_set_escrow(&creator, &true),
+        Err(Ok(Error::TicketsAlreadySold))
+    );
+
+    // A show that has already happened cannot be cancelled
+    set_timestamp(&env, schedule().event_end);
+    assert_eq!(
+        client.try_cancel_event(&creator),
+        Err(Ok(Error::EventEnded))
+    );
+    assert!(!client.is_cancelled());
+}
+
+#[test]
+fn test_withdraw_proceeds() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, seller, buyer) = setup_test(&env);
+    let factory = Address::generate(&env);
+    let treasury = Address::generate(&env);
+
+    token_admin.mint(&buyer, &100_000_000);
+    init_tiered_event(&env, &client, &creator, &token);
+    client.set_factory(
+        &factory,
+        &ProtocolFee {
+            primary_bps: 250,
+            secondary_bps: 0,
+            treasury: treasury.clone(),
+        },
+    );
+    client.set_escrow(&creator, &true);
+
+    let checked_in = client.mint_ticket(&buyer, &0);
+    client.mint_ticket(&buyer, &0);
+    client.mint_ticket(&buyer, &1);
+    assert_eq!(client.get_escrow_balance(), 70_000_000);
+
+    // Nothing is released before the event
+    assert_eq!(client.get_withdrawable_proceeds(), 0);
+    assert_eq!(
+        client.try_withdraw_proceeds(&creator),
+        Err(Ok(Error::ProceedsLocked))
+    );
+    assert_eq!(
+        client.try_withdraw_proceeds(&seller),
+        Err(Ok(Error::NotCreator))
+    );
+
+    // Checking a ticket in at the event releases its payment
+    set_timestamp(&env, schedule().event_start);
+    client.mark_ticket_used(&creator, &checked_in);
+    assert_eq!(client.get_escrowed_payment(&checked_in), None);
+    assert_eq!(client.get_withdrawable_proceeds(), 10_000_000);
+    assert_eq!(client.withdraw_proceeds(&creator), 9_750_000);
+    assert_eq!(
+        marketplace_events(&env, &client),
+        vec![
+            &env,
+            published(
+                &env,
+                &client,
+                &Withdraw {
+                    creator: creator.clone(),
+                    amount: 9_750_000,
+                    protocol_fee: 250_000,
+                }
+            )
+        ]
+    );
+    assert_eq!(client.get_escrow_balance(), 60_000_000);
+    assert_eq!(
+        client.try_withdraw_proceeds(&creator),
+        Err(Ok(Error::ProceedsLocked))
+    );
+
+    // The rest is released once the event ends
+    set_timestamp(&env, schedule().event_end);
+    assert_eq!(client.get_withdrawable_proceeds(), 60_000_000);
+    assert_eq!(client.withdraw_proceeds(&creator), 58_500_000);
+    assert_eq!(token.balance(&creator), 68_250_000);
+    assert_eq!(token.balance(&treasury), 1_750_000);
+    assert_eq!(token.balance(&client.address), 0);
+    assert_eq!(
+        client.try_withdraw_proceeds(&creator),
+        Err(Ok(Error::NoProceeds))
+    );
+}
+
+#[test]
+fn test_withdraw_proceeds_after_cancellation() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, seller, buyer) = setup_test(&env);
+
+    token_admin.mint(&seller, &100_000_000);
+    token_admin.mint(&buyer, &100_000_000);
+    init_event(&env, &client, &creator, &token, 100, 250);
+    client.set_escrow(&creator, &true);
+
+    let checked_in = client.mint_ticket(&seller, &0);
+    let refunded = client.mint_ticket(&buyer, &0);
+    set_timestamp(&env, schedule().event_start);
+    client.mark_ticket_used(&creator, &checked_in);
+    client.cancel_event(&creator);
+
+    // Only checked-in tickets are paid out, even after the event ends, while
+    // the other holders keep their refunds
+    set_timestamp(&env, schedule().event_end);
+    assert_eq!(client.get_withdrawable_proceeds(), 10_000_000);
+    assert_eq!(client.withdraw_proceeds(&creator), 10_000_000);
+    assert_eq!(
+        client.try_withdraw_proceeds(&creator),
+        Err(Ok(Error::ProceedsLocked))
+    );
+
+    client.claim_refund(&refunded);
+    assert_eq!(client.get_escrow_balance(), 0);
+    assert_eq!(token.balance(&client.address), 0);
+    assert_eq!(
+        client.try_withdraw_proceeds(&creator),
+        Err(Ok(Error::NoProceeds))
+    );
+}
+
+#[test]
+fn test_check_in_before_event_keeps_refunds() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, _, buyer) = setup_test(&env);
+
+    token_admin.mint(&buyer, &100_000_000);
+    init_event(&env, &client, &creator, &token, 100, 250);
+    client.set_escrow(&creator, &true);
+
+    let ticket_id = client.mint_ticket(&buyer, &0);
+
+    // The creator cannot check tickets in during the sale to free the escrow
+    assert_eq!(
+        client.try_mark_ticket_used(&creator, &ticket_id),
+        Err(Ok(Error::ProceedsLocked))
+    );
+    assert_eq!(
+        client.try_withdraw_proceeds(&creator),
+        Err(Ok(Error::ProceedsLocked))
+    );
 
-    #[test]
-    fn test_creator_fee_calculation() {
-        let env = Env::default();
-        let (client, token, token_admin, token_admin_addr, creator, seller, buyer) = setup_test(&env);
+    client.cancel_event(&creator);
+    assert_eq!(client.claim_refund(&ticket_id), 10_000_000);
+    assert_eq!(token.balance(&buyer), 100_000_000);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+// === Listing Expiry ===
+
+#[test]
+fn test_listing_expiry() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, seller, buyer) = setup_test(&env);
+
+    token_admin.mint(&seller, &100_000_000);
+    token_admin.mint(&buyer, &100_000_000);
+    init_event(&env, &client, &creator, &token, 100, 250);
+    let expiring = client.mint_ticket(&seller, &0);
+    let open_ended = client.mint_ticket(&seller, &0);
+
+    set_timestamp(&env, 100);
+    assert_eq!(
+        client.try_list_ticket(&seller, &expiring, &15_000_000, &Some(100)),
+        Err(Ok(Error::InvalidExpiry))
+    );
+    client.list_ticket(&seller, &expiring, &15_000_000, &Some(DAY_SECONDS));
+    client.list_ticket(&seller, &open_ended, &15_000_000, &None);
+    assert_eq!(client.get_all_secondary_listings(&1, &100).len(), 2);
+    assert_eq!(
+        client.get_all_secondary_listings(&open_ended, &1),
+        vec![&env, client.get_secondary_listing(&open_ended).unwrap()]
+    );
+    assert_eq!(client.get_all_secondary_listings(&0, &0).len(), 0);
+
+    // Lapsed listings cannot be bought or repriced, and are no longer shown
+    set_timestamp(&env, DAY_SECONDS);
+    assert_eq!(
+        client.try_buy_secondary_ticket(&buyer, &expiring),
+        Err(Ok(Error::ListingExpired))
+    );
+    assert_eq!(
+        client.try_update_listing_price(&seller, &expiring, &12_000_000),
+        Err(Ok(Error::ListingExpired))
+    );
+    assert_eq!(
+        client.get_all_secondary_listings(&1, &100),
+        vec![&env, client.get_secondary_listing(&open_ended).unwrap()]
+    );
+
+    // ...nor do they block the ticket, and moving it clears them
+    client.list_ticket(&seller, &expiring, &14_000_000, &Some(2 * DAY_SECONDS));
+    set_timestamp(&env, 2 * DAY_SECONDS);
+    client.transfer_ticket(&seller, &buyer, &expiring);
+    assert_eq!(client.get_secondary_listing(&expiring), None);
+
+    // Every listing lapses once the event starts
+    set_timestamp(&env, schedule().event_start);
+    assert_eq!(
+        client.try_buy_secondary_ticket(&buyer, &open_ended),
+        Err(Ok(Error::ListingExpired))
+    );
+    assert_eq!(client.get_all_secondary_listings(&1, &100).len(), 0);
+    assert_eq!(
+        client.try_list_ticket(&buyer, &expiring, &15_000_000, &None),
+        Err(Ok(Error::ListingExpired))
+    );
+}
+
+#[test]
+fn test_listing_from_before_expiry() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, seller, buyer) = setup_test(&env);
+
+    token_admin.mint(&seller, &100_000_000);
+    token_admin.mint(&buyer, &100_000_000);
+    init_event(&env, &client, &creator, &token, 100, 250);
+    let ticket_id = client.mint_ticket(&seller, &0);
+
+    // A listing left behind by code from before listings could expire
+    let mut legacy: Map<Symbol, Val> = Map::new(&env);
+    legacy.set(Symbol::new(&env, "ticket_id"), ticket_id.into_val(&env));
+    legacy.set(Symbol::new(&env, "seller"), seller.into_val(&env));
+    legacy.set(Symbol::new(&env, "price"), 15_000_000_i128.into_val(&env));
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::SecondaryListing(ticket_id), &legacy);
+    });
+
+    let listing = SecondaryListing {
+        ticket_id,
+        seller: seller.clone(),
+        price: 15_000_000,
+        expires_at: None,
+    };
+    assert_eq!(
+        client.get_secondary_listing(&ticket_id),
+        Some(listing.clone())
+    );
+    assert_eq!(
+        client.get_all_secondary_listings(&1, &100),
+        vec![&env, listing]
+    );
+
+    client.buy_secondary_ticket(&buyer, &ticket_id);
+    assert_eq!(client.get_ticket(&ticket_id).owner, buyer);
+}
+
+#[test]
+fn test_offers_and_bids_close_at_event_start() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, seller, buyer) = setup_test(&env);
 
-        // Fund everyone
-        token_admin.mint(&seller, &100_000_000); // 100 to mint
-        token_admin.mint(&buyer, &500_000_000);  // 500 to buy secondary
+    token_admin.mint(&seller, &100_000_000);
+    token_admin.mint(&buyer, &100_000_000);
+    init_event(&env, &client, &creator, &token, 100, 250);
+    let ticket_id = client.mint_ticket(&seller, &0);
 
-        // 500 bps = 5% creator fee
-        client.initialize(
+    let event_start = schedule().event_start;
+    client.make_offer(
+        &buyer,
+        &ticket_id,
+        &15_000_000,
+        &(event_start + DAY_SECONDS),
+    );
+    let bid_id = client.place_collection_bid(&buyer, &None, &12_000_000, &1);
+
+    // Neither can be filled once the event starts, and no new ones are taken
+    set_timestamp(&env, event_start);
+    assert_eq!(
+        client.try_accept_offer(&seller, &ticket_id, &buyer),
+        Err(Ok(Error::ListingExpired))
+    );
+    assert_eq!(
+        client.try_fill_collection_bid(&seller, &bid_id, &ticket_id),
+        Err(Ok(Error::ListingExpired))
+    );
+    assert_eq!(
+        client.try_make_offer(
             &creator,
-            &100,
-            &10_000_000,
-            &500, // 5%
-            &String::from_str(&env, "Test Event"),
-            &token.address,
-            &String::from_str(&env, "Music Fest"),
-            &String::from_str(&env, "MFST"),
-        );
+            &ticket_id,
+            &15_000_000,
+            &(event_start + DAY_SECONDS)
+        ),
+        Err(Ok(Error::ListingExpired))
+    );
+    assert_eq!(
+        client.try_place_collection_bid(&buyer, &None, &12_000_000, &1),
+        Err(Ok(Error::ListingExpired))
+    );
 
-        let ticket_id = client.mint_ticket(&seller);
-        
-        // List for 200.00
-        let secondary_price = 200_000_000i128;
-        client.list_ticket(&seller, &ticket_id, &secondary_price);
+    // The buyer gets the escrow back
+    client.cancel_offer(&buyer, &ticket_id);
+    client.cancel_collection_bid(&bid_id);
+    assert_eq!(token.balance(&buyer), 100_000_000);
+    assert_eq!(client.get_ticket(&ticket_id).owner, seller);
+}
 
-        // Capture balances before trade
-        let creator_initial_bal = token.balance(&creator); // Should be 10.0 from the initial mint
-        let seller_initial_bal = token.balance(&seller);
+// === Offers ===
 
-        // Buyer purchases
-        client.buy_secondary_ticket(&buyer, &ticket_id);
+#[test]
+fn test_accept_offer() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, seller, buyer) = setup_test(&env);
+    let other_buyer = Address::generate(&env);
 
-        let ticket = client.get_ticket(&ticket_id);
-        assert_eq!(ticket.owner, buyer);
+    token_admin.mint(&seller, &100_000_000);
+    token_admin.mint(&buyer, &100_000_000);
+    token_admin.mint(&other_buyer, &100_000_000);
+    init_event(&env, &client, &creator, &token, 100, 500);
+
+    let ticket_id = client.mint_ticket(&seller, &0);
+    client.list_ticket(&seller, &ticket_id, &20_000_000, &None);
+
+    // Offers are escrowed by the contract
+    client.make_offer(&buyer, &ticket_id, &15_000_000, &DAY_SECONDS);
+    assert_eq!(
+        marketplace_events(&env, &client),
+        vec![
+            &env,
+            published(
+                &env,
+                &client,
+                &Offer {
+                    ticket_id,
+                    buyer: buyer.clone(),
+                    price: 15_000_000,
+                    expiry: DAY_SECONDS,
+                }
+            )
+        ]
+    );
+    client.make_offer(&other_buyer, &ticket_id, &12_000_000, &DAY_SECONDS);
+    assert_eq!(token.balance(&buyer), 85_000_000);
+    assert_eq!(token.balance(&client.address), 27_000_000);
+    assert_eq!(client.get_offers(&ticket_id).len(), 2);
+    assert_eq!(
+        client.try_make_offer(&buyer, &ticket_id, &16_000_000, &DAY_SECONDS),
+        Err(Ok(Error::OfferExists))
+    );
+
+    assert_eq!(
+        client.try_accept_offer(&buyer, &ticket_id, &buyer),
+        Err(Ok(Error::NotOwner))
+    );
+
+    // Accepting pays out like a secondary sale and withdraws the listing
+    client.accept_offer(&seller, &ticket_id, &buyer);
+    assert_eq!(
+        marketplace_events(&env, &client),
+        vec![
+            &env,
+            published(
+                &env,
+                &client,
+                &Delist {
+                    ticket_id,
+                    seller: seller.clone(),
+                }
+            ),
+            published(
+                &env,
+                &client,
+                &Sale {
+                    ticket_id,
+                    seller: seller.clone(),
+                    buyer: buyer.clone(),
+                    price: 15_000_000,
+                    creator_fee: 750_000,
+                    protocol_fee: 0,
+                }
+            )
+        ]
+    );
+    assert_eq!(client.get_ticket(&ticket_id).owner, buyer);
+    assert_eq!(client.get_secondary_listing(&ticket_id), None);
+    assert_eq!(client.get_offer(&ticket_id, &buyer), None);
+    assert_eq!(token.balance(&seller), 90_000_000 + 14_250_000);
+    assert_eq!(token.balance(&creator), 10_000_000 + 750_000);
+
+    // Other offers stay open to the new owner
+    assert_eq!(client.get_offers(&ticket_id).len(), 1);
+    assert_eq!(token.balance(&client.address), 12_000_000);
+}
+
+#[test]
+fn test_offer_expiry_and_cancel() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, seller, buyer) = setup_test(&env);
+
+    token_admin.mint(&seller, &100_000_000);
+    token_admin.mint(&buyer, &100_000_000);
+    init_event(&env, &client, &creator, &token, 100, 250);
+    let ticket_id = client.mint_ticket(&seller, &0);
+
+    set_timestamp(&env, 100);
+    assert_eq!(
+        client.try_make_offer(&buyer, &ticket_id, &0, &DAY_SECONDS),
+        Err(Ok(Error::InvalidPrice))
+    );
+    assert_eq!(
+        client.try_make_offer(&buyer, &ticket_id, &15_000_000, &100),
+        Err(Ok(Error::InvalidExpiry))
+    );
+    assert_eq!(
+        client.try_make_offer(&buyer, &99, &15_000_000, &DAY_SECONDS),
+        Err(Ok(Error::TicketNotFound))
+    );
+    assert_eq!(
+        client.try_accept_offer(&seller, &ticket_id, &buyer),
+        Err(Ok(Error::OfferNotFound))
+    );
+
+    client.make_offer(&buyer, &ticket_id, &15_000_000, &DAY_SECONDS);
+
+    // Expired offers cannot be accepted, only refunded
+    set_timestamp(&env, DAY_SECONDS);
+    assert_eq!(
+        client.try_accept_offer(&seller, &ticket_id, &buyer),
+        Err(Ok(Error::OfferExpired))
+    );
+
+    client.cancel_offer(&buyer, &ticket_id);
+    assert_eq!(
+        marketplace_events(&env, &client),
+        vec![
+            &env,
+            published(
+                &env,
+                &client,
+                &OfferCancel {
+                    ticket_id,
+                    buyer: buyer.clone(),
+                }
+            )
+        ]
+    );
+    assert_eq!(token.balance(&buyer), 100_000_000);
+    assert_eq!(client.get_offers(&ticket_id).len(), 0);
+    assert_eq!(
+        client.try_cancel_offer(&buyer, &ticket_id),
+        Err(Ok(Error::OfferNotFound))
+    );
+}
+
+#[test]
+fn test_offers_per_ticket_are_capped() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, seller, buyer) = setup_test(&env);
+
+    token_admin.mint(&seller, &100_000_000);
+    token_admin.mint(&buyer, &100_000_000);
+    init_event(&env, &client, &creator, &token, 100, 250);
+    let ticket_id = client.mint_ticket(&seller, &0);
+
+    let mut offerers = std::vec::Vec::new();
+    for _ in 0..MAX_OFFERS_PER_TICKET {
+        let offerer = Address::generate(&env);
+        token_admin.mint(&offerer, &15_000_000);
+        client.make_offer(&offerer, &ticket_id, &15_000_000, &DAY_SECONDS);
+        offerers.push(offerer);
+    }
+    assert_eq!(client.get_offers(&ticket_id).len(), MAX_OFFERS_PER_TICKET);
+
+    // A full ticket takes no more offers until one is withdrawn
+    assert_eq!(
+        client.try_make_offer(&buyer, &ticket_id, &16_000_000, &DAY_SECONDS),
+        Err(Ok(Error::OfferExists))
+    );
+    client.cancel_offer(&offerers[0], &ticket_id);
+    client.make_offer(&buyer, &ticket_id, &16_000_000, &DAY_SECONDS);
+    assert_eq!(client.get_offers(&ticket_id).len(), MAX_OFFERS_PER_TICKET);
+}
+
+// === Collection Bids ===
+
+#[test]
+fn test_fill_collection_bid() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, seller, buyer) = setup_test(&env);
+
+    token_admin.mint(&seller, &100_000_000);
+    token_admin.mint(&buyer, &200_000_000);
+    init_tiered_event(&env, &client, &creator, &token);
+    let ga_ticket = client.mint_ticket(&seller, &0);
+    let listed_ticket = client.mint_ticket(&seller, &0);
+    let vip_ticket = client.mint_ticket(&seller, &1);
+    client.list_ticket(&seller, &listed_ticket, &15_000_000, &None);
+
+    // Any two tickets at 12.0 each, escrowed up front
+    let bid_id = client.place_collection_bid(&buyer, &None, &12_000_000, &2);
+    assert_eq!(
+        marketplace_events(&env, &client),
+        vec![
+            &env,
+            published(
+                &env,
+                &client,
+                &CollectionBidPlace {
+                    bid_id,
+                    buyer: buyer.clone(),
+                    tier_id: None,
+                    price: 12_000_000,
+                    quantity: 2,
+                }
+            )
+        ]
+    );
+    assert_eq!(token.balance(&client.address), 24_000_000);
+
+    assert_eq!(
+        client.try_fill_collection_bid(&seller, &bid_id, &listed_ticket),
+        Err(Ok(Error::AlreadyListed))
+    );
+    assert_eq!(
+        client.try_fill_collection_bid(&buyer, &bid_id, &ga_ticket),
+        Err(Ok(Error::NotOwner))
+    );
+
+    let seller_balance = token.balance(&seller);
+    client.fill_collection_bid(&seller, &bid_id, &ga_ticket);
+    assert_eq!(
+        marketplace_events(&env, &client),
+        vec![
+            &env,
+            published(
+                &env,
+                &client,
+                &Sale {
+                    ticket_id: ga_ticket,
+                    seller: seller.clone(),
+                    buyer: buyer.clone(),
+                    price: 12_000_000,
+                    creator_fee: 300_000,
+                    protocol_fee: 0,
+                }
+            )
+        ]
+    );
+    assert_eq!(client.get_ticket(&ga_ticket).owner, buyer);
+    assert_eq!(token.balance(&seller), seller_balance + 11_700_000);
+    assert_eq!(client.get_collection_bid(&bid_id).quantity, 1);
+
+    // A tier bid only takes tickets of that tier
+    let vip_bid = client.place_collection_bid(&buyer, &Some(1), &60_000_000, &1);
+    assert_eq!(
+        client.try_fill_collection_bid(&buyer, &vip_bid, &ga_ticket),
+        Err(Ok(Error::TierMismatch))
+    );
+    client.fill_collection_bid(&seller, &vip_bid, &vip_ticket);
+    assert_eq!(client.get_ticket(&vip_ticket).owner, buyer);
+    assert_eq!(
+        client.try_get_collection_bid(&vip_bid),
+        Err(Ok(Error::BidNotFound))
+    );
+    assert_eq!(client.get_collection_bids(&1, &100).len(), 1);
+    assert_eq!(token.balance(&client.address), 12_000_000);
+}
+
+#[test]
+fn test_cancel_collection_bid() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, seller, buyer) = setup_test(&env);
+
+    token_admin.mint(&seller, &100_000_000);
+    token_admin.mint(&buyer, &100_000_000);
+    init_event(&env, &client, &creator, &token, 100, 250);
+    let ticket_id = client.mint_ticket(&seller, &0);
+    let used_ticket = client.mint_ticket(&seller, &0);
+
+    assert_eq!(
+        client.try_place_collection_bid(&buyer, &None, &0, &1),
+        Err(Ok(Error::InvalidPrice))
+    );
+    assert_eq!(
+        client.try_place_collection_bid(&buyer, &None, &12_000_000, &0),
+        Err(Ok(Error::InvalidQuantity))
+    );
+    assert_eq!(
+        client.try_place_collection_bid(&buyer, &Some(5), &12_000_000, &1),
+        Err(Ok(Error::TierNotFound))
+    );
+
+    let bid_id = client.place_collection_bid(&buyer, &Some(0), &12_000_000, &3);
+    client.fill_collection_bid(&seller, &bid_id, &ticket_id);
+
+    set_timestamp(&env, schedule().event_start);
+    client.mark_ticket_used(&creator, &used_ticket);
+    assert_eq!(
+        client.try_fill_collection_bid(&seller, &bid_id, &used_ticket),
+        Err(Ok(Error::TicketUsed))
+    );
+
+    // Only the unfilled part of the bid is refunded
+    client.cancel_collection_bid(&bid_id);
+    assert_eq!(
+        marketplace_events(&env, &client),
+        vec![
+            &env,
+            published(
+                &env,
+                &client,
+                &CollectionBidCancel {
+                    bid_id,
+                    buyer: buyer.clone(),
+                    refund: 24_000_000,
+                }
+            )
+        ]
+    );
+    assert_eq!(token.balance(&buyer), 88_000_000);
+    assert_eq!(token.balance(&client.address), 0);
+    assert_eq!(
+        client.try_cancel_collection_bid(&bid_id),
+        Err(Ok(Error::BidNotFound))
+    );
+    assert_eq!(client.get_collection_bids(&1, &100).len(), 0);
+}
+
+// === Auctions ===
+
+#[test]
+fn test_auction() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, seller, buyer) = setup_test(&env);
+    let rival = Address::generate(&env);
+
+    token_admin.mint(&seller, &100_000_000);
+    token_admin.mint(&buyer, &100_000_000);
+    token_admin.mint(&rival, &100_000_000);
+    init_event(&env, &client, &creator, &token, 100, 500);
+    let ticket_id = client.mint_ticket(&seller, &0);
+
+    set_timestamp(&env, 100);
+    client.start_auction(&ticket_id, &10_000_000, &DAY_SECONDS);
+    assert_eq!(
+        marketplace_events(&env, &client),
+        vec![
+            &env,
+            published(
+                &env,
+                &client,
+                &AuctionStart {
+                    ticket_id,
+                    seller: seller.clone(),
+                    reserve: 10_000_000,
+                    end_time: DAY_SECONDS,
+                }
+            )
+        ]
+    );
+
+    // The ticket is locked while it is being auctioned
+    assert_eq!(
+        client.try_start_auction(&ticket_id, &10_000_000, &DAY_SECONDS),
+        Err(Ok(Error::AlreadyListed))
+    );
+    assert_eq!(
+        client.try_list_ticket(&seller, &ticket_id, &15_000_000, &None),
+        Err(Ok(Error::AlreadyListed))
+    );
+    assert_eq!(
+        client.try_transfer_ticket(&seller, &buyer, &ticket_id),
+        Err(Ok(Error::AlreadyListed))
+    );
+
+    // Bids start at the reserve and then go up by at least 5%
+    assert_eq!(
+        client.try_bid(&buyer, &ticket_id, &9_999_999),
+        Err(Ok(Error::BidTooLow))
+    );
+    client.bid(&buyer, &ticket_id, &10_000_000);
+    assert_eq!(
+        client.try_bid(&rival, &ticket_id, &10_499_999),
+        Err(Ok(Error::BidTooLow))
+    );
+    client.bid(&rival, &ticket_id, &10_500_000);
 
-        // Verify Balances
-        // Fee = 5% of 200 = 10
-        // Seller gets = 190
-        
-        // Creator check: 10 (initial mint) + 10 (fee) = 20
-        let creator_final_bal = token.balance(&creator);
-        assert_eq!(creator_final_bal, creator_initial_bal + 10_000_000);
-
-        // Seller check: Initial - 10 (mint cost) + 190 (secondary sale)
-        // Since we captured balance AFTER mint cost, it's just Initial + 190
-        let seller_final_bal = token.balance(&seller);
-        assert_eq!(seller_final_bal, seller_initial_bal + 190_000_000);
+    // Outbid escrow waits for its bidder to claim it
+    assert_eq!(token.balance(&buyer), 90_000_000);
+    assert_eq!(client.get_claimable(&buyer), 10_000_000);
+    assert_eq!(token.balance(&client.address), 20_500_000);
+    assert_eq!(
+        client.try_settle_auction(&ticket_id),
+        Err(Ok(Error::AuctionNotEnded))
+    );
+
+    // A bid in the closing minutes extends the auction
+    set_timestamp(&env, DAY_SECONDS - 60);
+    client.bid(&buyer, &ticket_id, &12_000_000);
+    let end_time = DAY_SECONDS - 60 + AUCTION_EXTENSION_SECONDS;
+    assert_eq!(
+        marketplace_events(&env, &client),
+        vec![
+            &env,
+            published(
+                &env,
+                &client,
+                &AuctionBid {
+                    ticket_id,
+                    bidder: buyer.clone(),
+                    amount: 12_000_000,
+                    end_time,
+                }
+            )
+        ]
+    );
+    assert_eq!(client.get_claimable(&rival), 10_500_000);
+
+    set_timestamp(&env, end_time);
+    assert_eq!(
+        client.try_bid(&rival, &ticket_id, &20_000_000),
+        Err(Ok(Error::ListingExpired))
+    );
+
+    client.settle_auction(&ticket_id);
+    assert_eq!(
+        marketplace_events(&env, &client),
+        vec![
+            &env,
+            published(
+                &env,
+                &client,
+                &Sale {
+                    ticket_id,
+                    seller: seller.clone(),
+                    buyer: buyer.clone(),
+                    price: 12_000_000,
+                    creator_fee: 600_000,
+                    protocol_fee: 0,
+                }
+            )
+        ]
+    );
+    assert_eq!(client.get_ticket(&ticket_id).owner, buyer);
+    assert_eq!(client.get_claimable(&seller), 11_400_000);
+
+    assert_eq!(client.claim_balance(&seller), 11_400_000);
+    assert_eq!(client.claim_balance(&buyer), 10_000_000);
+    assert_eq!(client.claim_balance(&rival), 10_500_000);
+    assert_eq!(token.balance(&seller), 90_000_000 + 11_400_000);
+    assert_eq!(token.balance(&buyer), 88_000_000);
+    assert_eq!(token.balance(&rival), 100_000_000);
+    assert_eq!(token.balance(&client.address), 0);
+    assert_eq!(
+        client.try_claim_balance(&seller),
+        Err(Ok(Error::NoProceeds))
+    );
+    assert_eq!(
+        client.try_get_auction(&ticket_id),
+        Err(Ok(Error::AuctionNotFound))
+    );
+}
+
+#[test]
+fn test_auction_without_sale() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, seller, buyer) = setup_test(&env);
+
+    token_admin.mint(&seller, &100_000_000);
+    token_admin.mint(&buyer, &100_000_000);
+    init_event(&env, &client, &creator, &token, 100, 250);
+    let ticket_id = client.mint_ticket(&seller, &0);
+
+    set_timestamp(&env, 100);
+    assert_eq!(
+        client.try_start_auction(&ticket_id, &0, &DAY_SECONDS),
+        Err(Ok(Error::InvalidPrice))
+    );
+    assert_eq!(
+        client.try_start_auction(&ticket_id, &10_000_000, &100),
+        Err(Ok(Error::InvalidExpiry))
+    );
+    assert_eq!(
+        client.try_start_auction(&ticket_id, &10_000_000, &(schedule().event_start + 1)),
+        Err(Ok(Error::InvalidExpiry))
+    );
+
+    // An auction without bids leaves the ticket with the seller
+    client.start_auction(&ticket_id, &10_000_000, &DAY_SECONDS);
+    set_timestamp(&env, DAY_SECONDS);
+    client.settle_auction(&ticket_id);
+    assert_eq!(
+        marketplace_events(&env, &client),
+        vec![
+            &env,
+            published(
+                &env,
+                &client,
+                &AuctionCancel {
+                    ticket_id,
+                    seller: seller.clone(),
+                }
+            )
+        ]
+    );
+
+    assert_eq!(client.get_ticket(&ticket_id).owner, seller);
+
+    client.start_auction(&ticket_id, &10_000_000, &(2 * DAY_SECONDS));
+    client.bid(&buyer, &ticket_id, &10_000_000);
+    assert_eq!(client.get_all_auctions(&1, &100).len(), 1);
+
+    // Cancelling the event closes the auction early and refunds the bidder
+    client.cancel_event(&creator);
+    assert_eq!(
+        client.try_bid(&buyer, &ticket_id, &11_000_000),
+        Err(Ok(Error::EventCancelled))
+    );
+    client.settle_auction(&ticket_id);
+    assert_eq!(client.get_ticket(&ticket_id).owner, seller);
+    assert_eq!(client.get_all_auctions(&1, &100).len(), 0);
+    assert_eq!(client.claim_balance(&buyer), 10_000_000);
+    assert_eq!(token.balance(&buyer), 100_000_000);
+}
+
+#[test]
+fn test_auction_payees_that_cannot_receive() {
+    let env = Env::default();
+    let (client, _, _, creator, seller, buyer) = setup_test(&env);
+    let rival = Address::generate(&env);
+
+    // A token whose admin can freeze accounts
+    let asset = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    asset.issuer().set_flag(IssuerFlags::RevocableFlag);
+    let token = token::Client::new(&env, &asset.address());
+    let token_admin = token::StellarAssetClient::new(&env, &asset.address());
+
+    token_admin.mint(&seller, &100_000_000);
+    token_admin.mint(&buyer, &100_000_000);
+    token_admin.mint(&rival, &100_000_000);
+    init_event(&env, &client, &creator, &token, 100, 500);
+    let ticket_id = client.mint_ticket(&seller, &0);
+
+    set_timestamp(&env, 100);
+    client.start_auction(&ticket_id, &10_000_000, &DAY_SECONDS);
+    client.bid(&buyer, &ticket_id, &10_000_000);
+
+    // A frozen bidder is still outbid, and a frozen seller still settles
+    token_admin.set_authorized(&buyer, &false);
+    token_admin.set_authorized(&seller, &false);
+    client.bid(&rival, &ticket_id, &10_500_000);
+    set_timestamp(&env, DAY_SECONDS);
+    client.settle_auction(&ticket_id);
+    assert_eq!(client.get_ticket(&ticket_id).owner, rival);
+    assert_eq!(client.get_claimable(&buyer), 10_000_000);
+    assert_eq!(client.get_claimable(&seller), 9_975_000);
+
+    // They collect once they can receive the token again
+    assert!(client.try_claim_balance(&buyer).is_err());
+    token_admin.set_authorized(&buyer, &true);
+    token_admin.set_authorized(&seller, &true);
+    assert_eq!(client.claim_balance(&buyer), 10_000_000);
+    assert_eq!(client.claim_balance(&seller), 9_975_000);
+    assert_eq!(token.balance(&buyer), 100_000_000);
+    assert_eq!(token.balance(&seller), 90_000_000 + 9_975_000);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+// === NFT Interface ===
+
+#[test]
+fn test_nft_queries() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, seller, buyer) = setup_test(&env);
+
+    token_admin.mint(&seller, &100_000_000);
+    init_event(&env, &client, &creator, &token, 100, 250);
+
+    let first = client.mint_ticket(&seller, &0);
+    let second = client.mint_ticket(&seller, &0);
+    assert_eq!(client.balance(&seller), 2);
+    assert_eq!(client.balance(&buyer), 0);
+    assert_eq!(client.owner_of(&first), seller);
+    assert_eq!(
+        client.token_uri(&first),
+        String::from_str(&env, "Test Event")
+    );
+
+    client.transfer_ticket(&seller, &buyer, &second);
+    assert_eq!(client.balance(&seller), 1);
+    assert_eq!(client.balance(&buyer), 1);
+    assert_eq!(client.owner_of(&second), buyer);
+
+    assert_eq!(client.try_owner_of(&3), Err(Ok(Error::TicketNotFound)));
+    assert_eq!(client.try_token_uri(&3), Err(Ok(Error::TicketNotFound)));
+}
+
+#[test]
+fn test_approve_and_transfer_from() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, seller, buyer) = setup_test(&env);
+    let spender = Address::generate(&env);
+
+    token_admin.mint(&seller, &100_000_000);
+    init_event(&env, &client, &creator, &token, 100, 250);
+
+    let ticket_id = client.mint_ticket(&seller, &0);
+    assert_eq!(client.get_approved(&ticket_id), None);
+
+    client.approve(&seller, &Some(spender.clone()), &ticket_id);
+    assert_eq!(
+        marketplace_events(&env, &client),
+        vec![
+            &env,
+            published(
+                &env,
+                &client,
+                &Approve {
+                    ticket_id,
+                    owner: seller.clone(),
+                    approved: Some(spender.clone()),
+                }
+            )
+        ]
+    );
+    assert_eq!(client.get_approved(&ticket_id), Some(spender.clone()));
+
+    client.transfer_from(&spender, &seller, &buyer, &ticket_id);
+    assert_eq!(
+        marketplace_events(&env, &client),
+        vec![
+            &env,
+            published(
+                &env,
+                &client,
+                &Transfer {
+                    ticket_id,
+                    from: seller.clone(),
+                    to: buyer.clone(),
+                }
+            )
+        ]
+    );
+    assert_eq!(client.owner_of(&ticket_id), buyer);
+    assert_eq!(client.get_user_tickets(&buyer), vec![&env, ticket_id]);
+
+    // The approval does not survive the change of owner
+    assert_eq!(client.get_approved(&ticket_id), None);
+    assert_eq!(
+        client.try_transfer_from(&spender, &buyer, &seller, &ticket_id),
+        Err(Ok(Error::NotApproved))
+    );
+}
+
+#[test]
+fn test_approval_for_all() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, seller, buyer) = setup_test(&env);
+    let operator = Address::generate(&env);
+    let spender = Address::generate(&env);
+
+    token_admin.mint(&seller, &100_000_000);
+    init_event(&env, &client, &creator, &token, 100, 250);
+
+    let first = client.mint_ticket(&seller, &0);
+    let second = client.mint_ticket(&seller, &0);
+
+    client.set_approval_for_all(&seller, &operator, &true);
+    assert!(client.is_approved_for_all(&seller, &operator));
+    assert!(!client.is_approved_for_all(&buyer, &operator));
+
+    // An operator can move any of the owner's tickets and approve others
+    client.transfer_from(&operator, &seller, &buyer, &first);
+    assert_eq!(client.owner_of(&first), buyer);
+
+    client.approve(&operator, &Some(spender.clone()), &second);
+    assert_eq!(client.get_approved(&second), Some(spender));
+
+    client.set_approval_for_all(&seller, &operator, &false);
+    assert!(!client.is_approved_for_all(&seller, &operator));
+    assert_eq!(
+        client.try_transfer_from(&operator, &seller, &buyer, &second),
+        Err(Ok(Error::NotApproved))
+    );
+    assert_eq!(
+        client.try_approve(&operator, &None, &second),
+        Err(Ok(Error::NotOwner))
+    );
+}
+
+#[test]
+fn test_transfer_from_respects_ticket_restrictions() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, seller, buyer) = setup_test(&env);
+    let spender = Address::generate(&env);
+
+    token_admin.mint(&seller, &100_000_000);
+    init_event(&env, &client, &creator, &token, 100, 250);
+
+    let ticket_id = client.mint_ticket(&seller, &0);
+    client.approve(&seller, &Some(spender.clone()), &ticket_id);
+
+    assert_eq!(
+        client.try_transfer_from(&spender, &buyer, &seller, &ticket_id),
+        Err(Ok(Error::NotOwner))
+    );
+
+    client.list_ticket(&seller, &ticket_id, &15_000_000, &None);
+    assert_eq!(
+        client.try_transfer_from(&spender, &seller, &buyer, &ticket_id),
+        Err(Ok(Error::AlreadyListed))
+    );
+
+    set_timestamp(&env, schedule().event_start);
+    client.mark_ticket_used(&creator, &ticket_id);
+    assert_eq!(
+        client.try_transfer_from(&spender, &seller, &buyer, &ticket_id),
+        Err(Ok(Error::TicketUsed))
+    );
+    assert_eq!(
+        client.try_approve(&buyer, &Some(buyer.clone()), &ticket_id),
+        Err(Ok(Error::NotOwner))
+    );
+}
+
+// === Metadata ===
+
+#[test]
+fn test_token_uri_resolution() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, _, buyer) = setup_test(&env);
+
+    token_admin.mint(&buyer, &200_000_000);
+    init_event(&env, &client, &creator, &token, 100, 250);
+
+    let first = client.mint_ticket(&buyer, &0);
+    let mut last = first;
+    for _ in 0..11 {
+        last = client.mint_ticket(&buyer, &0);
+    }
+
+    // Without a base URI tickets fall back to the event metadata
+    assert_eq!(
+        client.token_uri(&first),
+        String::from_str(&env, "Test Event")
+    );
+
+    let base_uri = String::from_str(&env, "ipfs://bafy/");
+    client.set_base_uri(&creator, &base_uri);
+    assert_eq!(
+        marketplace_events(&env, &client),
+        vec![
+            &env,
+            published(
+                &env,
+                &client,
+                &BaseUriUpdate {
+                    base_uri: base_uri.clone()
+                }
+            )
+        ]
+    );
+    assert_eq!(client.get_base_uri(), Some(base_uri));
+    assert_eq!(
+        client.token_uri(&first),
+        String::from_str(&env, "ipfs://bafy/1")
+    );
+    assert_eq!(
+        client.token_uri(&last),
+        String::from_str(&env, "ipfs://bafy/12")
+    );
+
+    // A seat-specific override can be set before the ticket is even minted
+    let vip = String::from_str(&env, "ipfs://vip/front-row.json");
+    client.set_ticket_uri(&creator, &13, &Some(vip.clone()));
+    assert_eq!(client.try_token_uri(&13), Err(Ok(Error::TicketNotFound)));
+    let seat = client.mint_ticket(&buyer, &0);
+    assert_eq!(client.token_uri(&seat), vip);
+
+    client.set_ticket_uri(&creator, &seat, &None);
+    assert_eq!(
+        client.token_uri(&seat),
+        String::from_str(&env, "ipfs://bafy/13")
+    );
+}
+
+#[test]
+fn test_metadata_freeze() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, _, buyer) = setup_test(&env);
+
+    token_admin.mint(&buyer, &100_000_000);
+    init_event(&env, &client, &creator, &token, 100, 250);
+
+    let ticket_id = client.mint_ticket(&buyer, &0);
+    let uri = String::from_str(&env, "ipfs://seat/1");
+
+    assert_eq!(
+        client.try_set_base_uri(&buyer, &uri),
+        Err(Ok(Error::NotCreator))
+    );
+    assert_eq!(
+        client.try_set_ticket_uri(&buyer, &ticket_id, &Some(uri.clone())),
+        Err(Ok(Error::NotCreator))
+    );
+    assert_eq!(
+        client.try_set_ticket_uri(&creator, &101, &Some(uri.clone())),
+        Err(Ok(Error::TicketNotFound))
+    );
+    assert_eq!(
+        client.try_set_base_uri(&creator, &String::from_str(&env, "")),
+        Err(Ok(Error::InvalidUri))
+    );
+    assert_eq!(
+        client.try_freeze_metadata(&buyer),
+        Err(Ok(Error::NotCreator))
+    );
+
+    client.set_ticket_uri(&creator, &ticket_id, &Some(uri.clone()));
+    assert!(!client.is_metadata_frozen());
+    client.freeze_metadata(&creator);
+    assert!(client.is_metadata_frozen());
+
+    assert_eq!(
+        client.try_set_base_uri(&creator, &uri),
+        Err(Ok(Error::MetadataFrozen))
+    );
+    assert_eq!(
+        client.try_set_ticket_uri(&creator, &ticket_id, &None),
+        Err(Ok(Error::MetadataFrozen))
+    );
+    assert_eq!(
+        client.try_freeze_metadata(&creator),
+        Err(Ok(Error::MetadataFrozen))
+    );
+    assert_eq!(client.token_uri(&ticket_id), uri);
+}
+
+// === Storage Benchmarks ===
+
+// Mint one ticket to a freshly funded wallet
+fn mint_to_new_wallet(
+    env: &Env,
+    client: &TicketMarketplaceClient,
+    token_admin: &token::StellarAssetClient,
+) {
+    let buyer = Address::generate(env);
+    token_admin.mint(&buyer, &10_000_000);
+    client.mint_ticket(&buyer, &0);
+}
+
+#[test]
+fn test_mint_cost_does_not_scale_with_supply() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, _, _) = setup_test(&env);
+
+    init_event(&env, &client, &creator, &token, 1_000, 250);
+
+    // Warm up so the first mint's one-off writes are not part of the baseline
+    mint_to_new_wallet(&env, &client, &token_admin);
+    mint_to_new_wallet(&env, &client, &token_admin);
+    let early = env.cost_estimate().resources();
+
+    for _ in 0..200 {
+        mint_to_new_wallet(&env, &client, &token_admin);
+    }
+    mint_to_new_wallet(&env, &client, &token_admin);
+    let late = env.cost_estimate().resources();
+
+    // Listing queries read one page of tickets, not every ticket minted
+    client.get_all_secondary_listings(&1, &10);
+    let first_page = env.cost_estimate().resources();
+    client.get_all_secondary_listings(&191, &10);
+    let last_page = env.cost_estimate().resources();
+
+    // Entries and bytes touched (what the fee is charged on) stay flat. CPU
+    // instructions are not compared because the native test host also meters
+    // work proportional to the size of its in-memory ledger.
+    assert_eq!(client.get_tickets_minted(), 203);
+    assert_eq!(late.memory_read_entries, early.memory_read_entries);
+    assert_eq!(late.write_entries, early.write_entries);
+    assert_eq!(late.write_bytes, early.write_bytes);
+    assert_eq!(
+        late.persistent_rent_ledger_bytes,
+        early.persistent_rent_ledger_bytes
+    );
+    assert_eq!(
+        first_page.memory_read_entries,
+        last_page.memory_read_entries
+    );
+    assert!(first_page.memory_read_entries < 20);
+}
+
+// === Storage Lifetime ===
+
+fn ticket_ttl(env: &Env, client: &TicketMarketplaceClient, ticket_id: u32) -> u32 {
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .get_ttl(&DataKey::Ticket(ticket_id))
+    })
+}
+
+fn instance_ttl(env: &Env, client: &TicketMarketplaceClient) -> u32 {
+    env.as_contract(&client.address, || env.storage().instance().get_ttl())
+}
+
+fn advance_ledgers(env: &Env, ledgers: u32) {
+    env.ledger().with_mut(|li| {
+        li.sequence_number += ledgers;
+        li.timestamp += ledgers as u64 * LEDGER_CLOSE_SECONDS;
+    });
+}
+
+#[test]
+fn test_ttl_extended_on_write() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, _, buyer) = setup_test(&env);
+
+    token_admin.mint(&buyer, &100_000_000);
+    init_event(&env, &client, &creator, &token, 100, 250);
+
+    let ticket_id = client.mint_ticket(&buyer, &0);
+
+    // By default entries outlive the end of the event by a day
+    let expected = (schedule().event_end / LEDGER_CLOSE_SECONDS) as u32 + DAY_IN_LEDGERS;
+    assert!(expected > DEFAULT_MIN_TTL_EXTENSION);
+    assert_eq!(instance_ttl(&env, &client), expected);
+    assert_eq!(ticket_ttl(&env, &client, ticket_id), expected);
+}
+
+#[test]
+fn test_extend_ticket_ttl() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, _, buyer) = setup_test(&env);
+
+    token_admin.mint(&buyer, &100_000_000);
+    init_event(&env, &client, &creator, &token, 100, 250);
+
+    let ticket_id = client.mint_ticket(&buyer, &0);
+    let initial_ttl = ticket_ttl(&env, &client, ticket_id);
+
+    // Three weeks later the entries are still live but have aged
+    advance_ledgers(&env, 21 * DAY_IN_LEDGERS);
+    assert_eq!(
+        ticket_ttl(&env, &client, ticket_id),
+        initial_ttl - 21 * DAY_IN_LEDGERS
+    );
+
+    // Anyone can pay to bump the ticket and the event back to a full
+    // extension, which now reaches past the end of the event
+    client.extend_ticket_ttl(&ticket_id);
+    assert_eq!(
+        ticket_ttl(&env, &client, ticket_id),
+        DEFAULT_MIN_TTL_EXTENSION
+    );
+
+    advance_ledgers(&env, 21 * DAY_IN_LEDGERS);
+    client.extend_event_ttl();
+    assert_eq!(instance_ttl(&env, &client), DEFAULT_MIN_TTL_EXTENSION);
+
+    // Within the threshold a bump is a no-op
+    advance_ledgers(&env, 10);
+    client.extend_event_ttl();
+    assert_eq!(instance_ttl(&env, &client), DEFAULT_MIN_TTL_EXTENSION - 10);
+
+    assert_eq!(
+        client.try_extend_ticket_ttl(&99),
+        Err(Ok(Error::TicketNotFound))
+    );
+}
+
+#[test]
+fn test_ttl_policy_retains_until_event_date() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, _, buyer) = setup_test(&env);
+
+    token_admin.mint(&buyer, &100_000_000);
+    init_event(&env, &client, &creator, &token, 100, 250);
+
+    // Show is 90 days out, past the default extension
+    let days_to_show = 90;
+    let policy = TtlPolicy {
+        min_extension: DEFAULT_MIN_TTL_EXTENSION,
+        retain_until: env.ledger().timestamp()
+            + days_to_show as u64 * DAY_IN_LEDGERS as u64 * LEDGER_CLOSE_SECONDS,
+    };
+    client.set_ttl_policy(&creator, &policy);
+    assert_eq!(client.get_ttl_policy(), policy);
+
+    let ticket_id = client.mint_ticket(&buyer, &0);
+
+    // Entries outlive the show by a day of grace
+    let expected = (days_to_show + 1) * DAY_IN_LEDGERS;
+    assert_eq!(ticket_ttl(&env, &client, ticket_id), expected);
+    assert_eq!(instance_ttl(&env, &client), expected);
+
+    // Still readable on show day without anyone bumping in between
+    advance_ledgers(&env, days_to_show * DAY_IN_LEDGERS);
+    assert_eq!(client.get_ticket(&ticket_id).owner, buyer);
+}
+
+#[test]
+fn test_set_ttl_policy_errors() {
+    let env = Env::default();
+    let (client, token, _, creator, seller, _) = setup_test(&env);
+
+    init_event(&env, &client, &creator, &token, 100, 250);
+
+    let policy = TtlPolicy {
+        min_extension: DEFAULT_MIN_TTL_EXTENSION,
+        retain_until: 0,
+    };
+    assert_eq!(
+        client.try_set_ttl_policy(&seller, &policy),
+        Err(Ok(Error::NotCreator))
+    );
+
+    let too_short = TtlPolicy {
+        min_extension: 1,
+        retain_until: 0,
+    };
+    assert_eq!(
+        client.try_set_ttl_policy(&creator, &too_short),
+        Err(Ok(Error::InvalidTtlPolicy))
+    );
+}
+
+// === Events ===
+
+// Events published by the marketplace itself during the last invocation
+fn marketplace_events(
+    env: &Env,
+    client: &TicketMarketplaceClient,
+) -> Vec<(Address, Vec<Val>, Val)> {
+    let mut events = Vec::new(env);
+    for event in env.events().all().iter() {
+        if event.0 == client.address {
+            events.push_back(event);
+        }
     }
+    events
+}
+
+fn published(
+    env: &Env,
+    client: &TicketMarketplaceClient,
+    event: &impl Event,
+) -> (Address, Vec<Val>, Val) {
+    (client.address.clone(), event.topics(env), event.data(env))
+}
+
+#[test]
+fn test_primary_and_secondary_events() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, seller, buyer) = setup_test(&env);
+
+    token_admin.mint(&seller, &100_000_000);
+    token_admin.mint(&buyer, &100_000_000);
+    init_event(&env, &client, &creator, &token, 100, 500);
+
+    let ticket_id = client.mint_ticket(&seller, &0);
+    assert_eq!(
+        marketplace_events(&env, &client),
+        vec![
+            &env,
+            published(
+                &env,
+                &client,
+                &Mint {
+                    ticket_id,
+                    buyer: seller.clone(),
+                    tier_id: 0,
+                    price: 10_000_000,
+                }
+            )
+        ]
+    );
+
+    client.list_ticket(&seller, &ticket_id, &15_000_000, &None);
+    assert_eq!(
+        marketplace_events(&env, &client),
+        vec![
+            &env,
+            published(
+                &env,
+                &client,
+                &List {
+                    ticket_id,
+                    seller: seller.clone(),
+                    price: 15_000_000,
+                    expires_at: None,
+                }
+            )
+        ]
+    );
+
+    client.update_listing_price(&seller, &ticket_id, &20_000_000);
+    assert_eq!(
+        marketplace_events(&env, &client),
+        vec![
+            &env,
+            published(
+                &env,
+                &client,
+                &PriceUpdate {
+                    ticket_id,
+                    seller: seller.clone(),
+                    old_price: 15_000_000,
+                    new_price: 20_000_000,
+                }
+            )
+        ]
+    );
+
+    client.buy_secondary_ticket(&buyer, &ticket_id);
+    assert_eq!(
+        marketplace_events(&env, &client),
+        vec![
+            &env,
+            published(
+                &env,
+                &client,
+                &Sale {
+                    ticket_id,
+                    seller: seller.clone(),
+                    buyer: buyer.clone(),
+                    price: 20_000_000,
+                    creator_fee: 1_000_000,
+                    protocol_fee: 0,
+                }
+            )
+        ]
+    );
+}
+
+#[test]
+fn test_delist_transfer_and_check_in_events() {
+    let env = Env::default();
+    let (client, token, token_admin, creator, seller, buyer) = setup_test(&env);
+
+    token_admin.mint(&seller, &100_000_000);
+    init_event(&env, &client, &creator, &token, 100, 250);
+
+    let ticket_id = client.mint_ticket(&seller, &0);
+    client.list_ticket(&seller, &ticket_id, &15_000_000, &None);
+
+    client.delist_ticket(&seller, &ticket_id);
+    assert_eq!(
+        marketplace_events(&env, &client),
+        vec![
+            &env,
+            published(
+                &env,
+                &client,
+                &Delist {
+                    ticket_id,
+                    seller: seller.clone(),
+                }
+            )
+        ]
+    );
+
+    client.transfer_ticket(&seller, &buyer, &ticket_id);
+    assert_eq!(
+        marketplace_events(&env, &client),
+        vec![
+            &env,
+            published(
+                &env,
+                &client,
+                &Transfer {
+                    ticket_id,
+                    from: seller.clone(),
+                    to: buyer.clone(),
+                }
+            )
+        ]
+    );
+
+    // Checking in a listed ticket also withdraws the listing
+    client.list_ticket(&buyer, &ticket_id, &15_000_000, &None);
+    set_timestamp(&env, schedule().event_start);
+    client.mark_ticket_used(&creator, &ticket_id);
+    assert_eq!(
+        marketplace_events(&env, &client),
+        vec![
+            &env,
+            published(
+                &env,
+                &client,
+                &Delist {
+                    ticket_id,
+                    seller: buyer.clone(),
+                }
+            ),
+            published(
+                &env,
+                &client,
+                &CheckIn {
+                    ticket_id,
+                    owner: buyer.clone(),
+                }
+            )
+        ]
+    );
 }
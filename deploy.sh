@@ -6,7 +6,7 @@
 # This script automates the entire deployment process:
 # 1. Builds all contracts
 # 2. Deploys to testnet
-# 3. Initializes factory with NFT WASM hash
+# 3. Sets up the factory with the NFT WASM hash at deployment
 # 4. Generates TypeScript SDKs
 # 5. Creates a Next.js frontend (optional)
 # =============================================================================
@@ -127,7 +127,7 @@ log_success "Factory WASM: $FACTORY_WASM"
 log_success "NFT Collections WASM: $NFT_WASM"
 
 # -----------------------------------------------------------------------------
-# Install NFT WASM (to get hash for factory initialization)
+# Install NFT WASM (to get hash for the factory constructor)
 # -----------------------------------------------------------------------------
 
 log_step "Installing NFT Collections WASM"
@@ -154,10 +154,15 @@ log_success "NFT WASM Hash: $NFT_WASM_HASH"
 
 log_step "Deploying Factory Contract"
 
+# The constructor sets the admin and NFT WASM hash in the deploy transaction,
+# so nobody can claim the factory before it is set up
 FACTORY_DEPLOY_OUTPUT=$(stellar contract deploy \
     --wasm $FACTORY_WASM \
     --source-account $SOURCE_ACCOUNT \
-    --network $NETWORK 2>&1)
+    --network $NETWORK \
+    -- \
+    --admin $SOURCE_ADDRESS \
+    --wasm_hash $NFT_WASM_HASH 2>&1)
 
 # Extract contract ID (last line that looks like a contract ID)
 FACTORY_CONTRACT_ID=$(echo "$FACTORY_DEPLOY_OUTPUT" | grep -oE 'C[A-Z0-9]{55}' | tail -1)
@@ -170,24 +175,8 @@ fi
 
 log_success "Factory Contract ID: $FACTORY_CONTRACT_ID"
 
-# -----------------------------------------------------------------------------
-# Initialize Factory with NFT WASM Hash
-# -----------------------------------------------------------------------------
-
-log_step "Initializing Factory"
-
-stellar contract invoke \
-    --network $NETWORK \
-    --source-account $SOURCE_ACCOUNT \
-    --id $FACTORY_CONTRACT_ID \
-    -- \
-    initialize \
-    --wasm_hash $NFT_WASM_HASH
-
-log_success "Factory initialized with NFT WASM hash"
-
-# Verify initialization
-log_info "Verifying factory initialization..."
+# Verify the constructor arguments
+log_info "Verifying factory setup..."
 STORED_HASH=$(stellar contract invoke \
     --network $NETWORK \
     --source-account $SOURCE_ACCOUNT \
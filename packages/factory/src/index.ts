@@ -39,41 +39,162 @@ export const networks = {
   }
 } as const
 
-export type DataKey = {tag: "WasmHash", values: void} | {tag: "EventCounter", values: void} | {tag: "EventRecord", values: readonly [u32]} | {tag: "CreatorEvents", values: readonly [string]} | {tag: "AllEvents", values: void};
+export const Errors = {
+  2: {message:"NotInitialized"},
+  3: {message:"InvalidSupply"},
+  4: {message:"InvalidPrice"},
+  5: {message:"InvalidFeeBps"},
+  6: {message:"EventInitFailed"},
+  7: {message:"EventNotFound"},
+  8: {message:"InvalidSchedule"},
+  9: {message:"NoPendingAdmin"},
+  10: {message:"EventUpgradeFailed"},
+  11: {message:"TemplateNotFound"},
+  12: {message:"TemplateExists"}
+}
+
+/**
+ * A ticket contract WASM registered with the factory, such as version 1 of
+ * "standard" or "soulbound". Each name and version pair is registered once.
+ */
+export interface Template {
+  name: string;
+  template_id: u32;
+  version: u32;
+  wasm_hash: Buffer;
+}
+
+
+/**
+ * Tier configuration forwarded to the ticket contract's `init`. Field names
+ * and types must match the ticket contract's `TierConfig`.
+ */
+export interface TierConfig {
+  creator_fee_bps: u32;
+  max_per_wallet: u32;
+  name: string;
+  price: i128;
+  supply: u32;
+}
+
+
+/**
+ * Everything needed to configure a new event. Pass an empty `tiers` list
+ * for a single tier built from `total_supply`, `primary_price` and
+ * `creator_fee_bps`.
+ */
+export interface EventParams {
+  creator_fee_bps: u32;
+  event_metadata: string;
+  name: string;
+  payment_token: string;
+  primary_price: i128;
+  schedule: EventSchedule;
+  symbol: string;
+  tiers: Array<TierConfig>;
+  total_supply: u32;
+}
 
 
 export interface EventRecord {
   created_at: u64;
   event_contract: string;
   event_creator: string;
+  event_end: u64;
+  event_start: u64;
   name: string;
   symbol: string;
+  template_id: u32;
+  template_version: u32;
+}
+
+
+/**
+ * The platform's cut of sales on events deployed from now on. Field names
+ * and types must match the ticket contract's `ProtocolFee`.
+ */
+export interface ProtocolFee {
+  primary_bps: u32;
+  secondary_bps: u32;
+  treasury: string;
 }
 
+
+/**
+ * Sale and event timestamps forwarded to the ticket contract's `init`.
+ * Field names and types must match the ticket contract's `EventSchedule`.
+ */
+export interface EventSchedule {
+  event_end: u64;
+  event_start: u64;
+  sale_end: u64;
+  sale_start: u64;
+}
+
+/**
+ * Who may upgrade an event's code. Variants must match the ticket contract's
+ * `UpgradeAuthority`.
+ */
+export type UpgradeAuthority = {tag: "Factory", values: void} | {tag: "Creator", values: void};
+
 export interface Client {
+  /**
+   * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the factory admin
+   */
+  get_admin: (options?: AssembledTransactionOptions<Result<string>>) => Promise<AssembledTransaction<Result<string>>>
+
   /**
    * Construct and simulate a get_event transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Get event record by ID
    */
-  get_event: ({event_id}: {event_id: u32}, options?: AssembledTransactionOptions<EventRecord>) => Promise<AssembledTransaction<EventRecord>>
+  get_event: ({event_id}: {event_id: u32}, options?: AssembledTransactionOptions<Result<EventRecord>>) => Promise<AssembledTransaction<Result<EventRecord>>>
 
   /**
-   * Construct and simulate a initialize transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Initialize the factory with the ticket contract WASM hash
+   * Construct and simulate a accept_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Accept a pending admin transfer (proposed admin only)
    */
-  initialize: ({wasm_hash}: {wasm_hash: Buffer}, options?: AssembledTransactionOptions<null>) => Promise<AssembledTransaction<null>>
+  accept_admin: (options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>
+
+  /**
+   * Construct and simulate a add_template transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Register a ticket contract WASM that new events can be deployed from
+   * (admin only). Returns the new template's id.
+   */
+  add_template: ({name, version, wasm_hash}: {name: string, version: u32, wasm_hash: Buffer}, options?: AssembledTransactionOptions<Result<u32>>) => Promise<AssembledTransaction<Result<u32>>>
 
   /**
    * Construct and simulate a create_event transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Deploy a new ticket marketplace event
+   * Deploy a new ticket marketplace event from a registered template, or
+   * from the default template when `template_id` is 0
+   */
+  create_event: ({salt, event_creator, template_id, params}: {salt: Buffer, event_creator: string, template_id: u32, params: EventParams}, options?: AssembledTransactionOptions<Result<string>>) => Promise<AssembledTransaction<Result<string>>>
+
+  /**
+   * Construct and simulate a get_template transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a template by ID
+   */
+  get_template: ({template_id}: {template_id: u32}, options?: AssembledTransactionOptions<Result<Template>>) => Promise<AssembledTransaction<Result<Template>>>
+
+  /**
+   * Construct and simulate a get_templates transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get all registered templates
    */
-  create_event: ({salt, event_creator, total_supply, primary_price, creator_fee_bps, event_metadata, name, symbol, payment_token}: {salt: Buffer, event_creator: string, total_supply: u32, primary_price: i128, creator_fee_bps: u32, event_metadata: string, name: string, symbol: string, payment_token: string}, options?: AssembledTransactionOptions<string>) => Promise<AssembledTransaction<string>>
+  get_templates: (options?: AssembledTransactionOptions<Array<Template>>) => Promise<AssembledTransaction<Array<Template>>>
 
   /**
    * Construct and simulate a get_wasm_hash transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the WASM hash stored in the factory
+   * Get the WASM hash of the default template, used for new events
+   */
+  get_wasm_hash: (options?: AssembledTransactionOptions<Result<Buffer>>) => Promise<AssembledTransaction<Result<Buffer>>>
+
+  /**
+   * Construct and simulate a set_wasm_hash transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Replace the ticket contract WASM used for new events (admin only). The
+   * hash is registered as the next version of the default template's name,
+   * which becomes the new default template.
    */
-  get_wasm_hash: (options?: AssembledTransactionOptions<Buffer>) => Promise<AssembledTransaction<Buffer>>
+  set_wasm_hash: ({wasm_hash}: {wasm_hash: Buffer}, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>
 
   /**
    * Construct and simulate a get_all_events transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -81,21 +202,85 @@ export interface Client {
    */
   get_all_events: (options?: AssembledTransactionOptions<Array<EventRecord>>) => Promise<AssembledTransaction<Array<EventRecord>>>
 
+  /**
+   * Construct and simulate a transfer_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Propose a new admin (admin only). The proposal takes effect once the new
+   * admin calls `accept_admin`, and a later proposal replaces it.
+   */
+  transfer_admin: ({new_admin}: {new_admin: string}, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>
+
+  /**
+   * Construct and simulate a upgrade_events transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Upgrade the code of deployed events to a registered template (admin
+   * only). Every event must still leave upgrades to the factory, otherwise
+   * none of them are upgraded.
+   */
+  upgrade_events: ({event_ids, template_id}: {event_ids: Array<u32>, template_id: u32}, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>
+
   /**
    * Construct and simulate a get_event_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Get total number of events created
    */
   get_event_count: (options?: AssembledTransactionOptions<u32>) => Promise<AssembledTransaction<u32>>
 
+  /**
+   * Construct and simulate a get_template_id transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the ID of a template by name and version
+   */
+  get_template_id: ({name, version}: {name: string, version: u32}, options?: AssembledTransactionOptions<Result<u32>>) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a extend_event_ttl transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Extend the TTL of an event record and its creator's index until a
+   * month after the event ends. Anyone may call this and pay for the
+   * extension.
+   */
+  extend_event_ttl: ({event_id}: {event_id: u32}, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>
+
+  /**
+   * Construct and simulate a get_protocol_fee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the protocol fee charged on newly deployed events
+   */
+  get_protocol_fee: (options?: AssembledTransactionOptions<Result<ProtocolFee>>) => Promise<AssembledTransaction<Result<ProtocolFee>>>
+
+  /**
+   * Construct and simulate a set_protocol_fee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the protocol fee charged on events deployed from now on (admin only).
+   * Events that already exist keep the fee they were deployed with.
+   */
+  set_protocol_fee: ({protocol_fee}: {protocol_fee: ProtocolFee}, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>
+
+  /**
+   * Construct and simulate a get_pending_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the admin proposed by `transfer_admin`, if any
+   */
+  get_pending_admin: (options?: AssembledTransactionOptions<Option<string>>) => Promise<AssembledTransaction<Option<string>>>
+
   /**
    * Construct and simulate a get_creator_events transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Get all events created by a specific address
    */
   get_creator_events: ({creator}: {creator: string}, options?: AssembledTransactionOptions<Array<EventRecord>>) => Promise<AssembledTransaction<Array<EventRecord>>>
 
+  /**
+   * Construct and simulate a get_default_template transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the ID of the default template
+   */
+  get_default_template: (options?: AssembledTransactionOptions<Result<u32>>) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a set_event_upgrade_authority transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Hand the right to upgrade a deployed event to its creator (admin
+   * only). The event must still leave upgrades to the factory; once the
+   * creator holds that right, only the creator can hand it back.
+   */
+  set_event_upgrade_authority: ({event_id, authority}: {event_id: u32, authority: UpgradeAuthority}, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>
+
 }
 export class Client extends ContractClient {
   static async deploy<T = Client>(
+        /** Constructor/Initialization Args for the contract's `__constructor` method */
+        {admin, wasm_hash}: {admin: string, wasm_hash: Buffer},
     /** Options for initializing a Client as well as for calling a method, with extras specific to deploying. */
     options: MethodOptions &
       Omit<ContractClientOptions, "contractId"> & {
@@ -107,29 +292,65 @@ export class Client extends ContractClient {
         format?: "hex" | "base64";
       }
   ): Promise<AssembledTransaction<T>> {
-    return ContractClient.deploy(null, options)
+    return ContractClient.deploy({admin, wasm_hash}, options)
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAABQAAAAAAAAAAAAAACFdhc21IYXNoAAAAAAAAAAAAAAAMRXZlbnRDb3VudGVyAAAAAQAAAAAAAAALRXZlbnRSZWNvcmQAAAAAAQAAAAQAAAABAAAAAAAAAA1DcmVhdG9yRXZlbnRzAAAAAAAAAQAAABMAAAAAAAAAAAAAAAlBbGxFdmVudHMAAAA=",
-        "AAAAAQAAAAAAAAAAAAAAC0V2ZW50UmVjb3JkAAAAAAUAAAAAAAAACmNyZWF0ZWRfYXQAAAAAAAYAAAAAAAAADmV2ZW50X2NvbnRyYWN0AAAAAAATAAAAAAAAAA1ldmVudF9jcmVhdG9yAAAAAAAAEwAAAAAAAAAEbmFtZQAAABAAAAAAAAAABnN5bWJvbAAAAAAAEA==",
-        "AAAAAAAAABZHZXQgZXZlbnQgcmVjb3JkIGJ5IElEAAAAAAAJZ2V0X2V2ZW50AAAAAAAAAQAAAAAAAAAIZXZlbnRfaWQAAAAEAAAAAQAAB9AAAAALRXZlbnRSZWNvcmQA",
-        "AAAAAAAAADlJbml0aWFsaXplIHRoZSBmYWN0b3J5IHdpdGggdGhlIHRpY2tldCBjb250cmFjdCBXQVNNIGhhc2gAAAAAAAAKaW5pdGlhbGl6ZQAAAAAAAQAAAAAAAAAJd2FzbV9oYXNoAAAAAAAD7gAAACAAAAAA",
-        "AAAAAAAAACVEZXBsb3kgYSBuZXcgdGlja2V0IG1hcmtldHBsYWNlIGV2ZW50AAAAAAAADGNyZWF0ZV9ldmVudAAAAAkAAAAAAAAABHNhbHQAAAPuAAAAIAAAAAAAAAANZXZlbnRfY3JlYXRvcgAAAAAAABMAAAAAAAAADHRvdGFsX3N1cHBseQAAAAQAAAAAAAAADXByaW1hcnlfcHJpY2UAAAAAAAALAAAAAAAAAA9jcmVhdG9yX2ZlZV9icHMAAAAABAAAAAAAAAAOZXZlbnRfbWV0YWRhdGEAAAAAABAAAAAAAAAABG5hbWUAAAAQAAAAAAAAAAZzeW1ib2wAAAAAABAAAAAAAAAADXBheW1lbnRfdG9rZW4AAAAAAAATAAAAAQAAABM=",
-        "AAAAAAAAACdHZXQgdGhlIFdBU00gaGFzaCBzdG9yZWQgaW4gdGhlIGZhY3RvcnkAAAAADWdldF93YXNtX2hhc2gAAAAAAAAAAAAAAQAAA+4AAAAg",
+      new ContractSpec([ "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAACwAAAAAAAAAOTm90SW5pdGlhbGl6ZWQAAAAAAAIAAAAAAAAADUludmFsaWRTdXBwbHkAAAAAAAADAAAAAAAAAAxJbnZhbGlkUHJpY2UAAAAEAAAAAAAAAA1JbnZhbGlkRmVlQnBzAAAAAAAABQAAAAAAAAAPRXZlbnRJbml0RmFpbGVkAAAAAAYAAAAAAAAADUV2ZW50Tm90Rm91bmQAAAAAAAAHAAAAAAAAAA9JbnZhbGlkU2NoZWR1bGUAAAAACAAAAAAAAAAOTm9QZW5kaW5nQWRtaW4AAAAAAAkAAAAAAAAAEkV2ZW50VXBncmFkZUZhaWxlZAAAAAAACgAAAAAAAAAQVGVtcGxhdGVOb3RGb3VuZAAAAAsAAAAAAAAADlRlbXBsYXRlRXhpc3RzAAAAAAAM",
+        "AAAAAQAAAJJBIHRpY2tldCBjb250cmFjdCBXQVNNIHJlZ2lzdGVyZWQgd2l0aCB0aGUgZmFjdG9yeSwgc3VjaCBhcyB2ZXJzaW9uIDEgb2YKInN0YW5kYXJkIiBvciAic291bGJvdW5kIi4gRWFjaCBuYW1lIGFuZCB2ZXJzaW9uIHBhaXIgaXMgcmVnaXN0ZXJlZCBvbmNlLgAAAAAAAAAAAAhUZW1wbGF0ZQAAAAQAAAAAAAAABG5hbWUAAAAQAAAAAAAAAAt0ZW1wbGF0ZV9pZAAAAAAEAAAAAAAAAAd2ZXJzaW9uAAAAAAQAAAAAAAAACXdhc21faGFzaAAAAAAAA+4AAAAg",
+        "AAAAAQAAAIJUaWVyIGNvbmZpZ3VyYXRpb24gZm9yd2FyZGVkIHRvIHRoZSB0aWNrZXQgY29udHJhY3QncyBgaW5pdGAuIEZpZWxkIG5hbWVzCmFuZCB0eXBlcyBtdXN0IG1hdGNoIHRoZSB0aWNrZXQgY29udHJhY3QncyBgVGllckNvbmZpZ2AuAAAAAAAAAAAAClRpZXJDb25maWcAAAAAAAUAAAAAAAAAD2NyZWF0b3JfZmVlX2JwcwAAAAAEAAAAAAAAAA5tYXhfcGVyX3dhbGxldAAAAAAABAAAAAAAAAAEbmFtZQAAABAAAAAAAAAABXByaWNlAAAAAAAACwAAAAAAAAAGc3VwcGx5AAAAAAAE",
+        "AAAAAQAAAJpFdmVyeXRoaW5nIG5lZWRlZCB0byBjb25maWd1cmUgYSBuZXcgZXZlbnQuIFBhc3MgYW4gZW1wdHkgYHRpZXJzYCBsaXN0CmZvciBhIHNpbmdsZSB0aWVyIGJ1aWx0IGZyb20gYHRvdGFsX3N1cHBseWAsIGBwcmltYXJ5X3ByaWNlYCBhbmQKYGNyZWF0b3JfZmVlX2Jwc2AuAAAAAAAAAAAAC0V2ZW50UGFyYW1zAAAAAAkAAAAAAAAAD2NyZWF0b3JfZmVlX2JwcwAAAAAEAAAAAAAAAA5ldmVudF9tZXRhZGF0YQAAAAAAEAAAAAAAAAAEbmFtZQAAABAAAAAAAAAADXBheW1lbnRfdG9rZW4AAAAAAAATAAAAAAAAAA1wcmltYXJ5X3ByaWNlAAAAAAAACwAAAAAAAAAIc2NoZWR1bGUAAAfQAAAADUV2ZW50U2NoZWR1bGUAAAAAAAAAAAAABnN5bWJvbAAAAAAAEAAAAAAAAAAFdGllcnMAAAAAAAPqAAAH0AAAAApUaWVyQ29uZmlnAAAAAAAAAAAADHRvdGFsX3N1cHBseQAAAAQ=",
+        "AAAAAQAAAAAAAAAAAAAAC0V2ZW50UmVjb3JkAAAAAAkAAAAAAAAACmNyZWF0ZWRfYXQAAAAAAAYAAAAAAAAADmV2ZW50X2NvbnRyYWN0AAAAAAATAAAAAAAAAA1ldmVudF9jcmVhdG9yAAAAAAAAEwAAAAAAAAAJZXZlbnRfZW5kAAAAAAAABgAAAAAAAAALZXZlbnRfc3RhcnQAAAAABgAAAAAAAAAEbmFtZQAAABAAAAAAAAAABnN5bWJvbAAAAAAAEAAAAAAAAAALdGVtcGxhdGVfaWQAAAAABAAAAAAAAAAQdGVtcGxhdGVfdmVyc2lvbgAAAAQ=",
+        "AAAAAQAAAIFUaGUgcGxhdGZvcm0ncyBjdXQgb2Ygc2FsZXMgb24gZXZlbnRzIGRlcGxveWVkIGZyb20gbm93IG9uLiBGaWVsZCBuYW1lcwphbmQgdHlwZXMgbXVzdCBtYXRjaCB0aGUgdGlja2V0IGNvbnRyYWN0J3MgYFByb3RvY29sRmVlYC4AAAAAAAAAAAAAC1Byb3RvY29sRmVlAAAAAAMAAAAAAAAAC3ByaW1hcnlfYnBzAAAAAAQAAAAAAAAADXNlY29uZGFyeV9icHMAAAAAAAAEAAAAAAAAAAh0cmVhc3VyeQAAABM=",
+        "AAAABQAAAEVFbWl0dGVkIHdoZW4gdGhlIGZhY3RvcnkgZGVwbG95cyBhbmQgaW5pdGlhbGl6ZXMgYSBuZXcgZXZlbnQgY29udHJhY3QAAAAAAAAAAAAADEV2ZW50Q3JlYXRlZAAAAAEAAAANZXZlbnRfY3JlYXRlZAAAAAAAAAUAAAAAAAAACGV2ZW50X2lkAAAABAAAAAEAAAAAAAAADWV2ZW50X2NyZWF0b3IAAAAAAAATAAAAAQAAAAAAAAAOZXZlbnRfY29udHJhY3QAAAAAABMAAAAAAAAAAAAAAARuYW1lAAAAEAAAAAAAAAAAAAAABnN5bWJvbAAAAAAAEAAAAAAAAAAC",
+        "AAAAAQAAAIxTYWxlIGFuZCBldmVudCB0aW1lc3RhbXBzIGZvcndhcmRlZCB0byB0aGUgdGlja2V0IGNvbnRyYWN0J3MgYGluaXRgLgpGaWVsZCBuYW1lcyBhbmQgdHlwZXMgbXVzdCBtYXRjaCB0aGUgdGlja2V0IGNvbnRyYWN0J3MgYEV2ZW50U2NoZWR1bGVgLgAAAAAAAAANRXZlbnRTY2hlZHVsZQAAAAAAAAQAAAAAAAAACWV2ZW50X2VuZAAAAAAAAAYAAAAAAAAAC2V2ZW50X3N0YXJ0AAAAAAYAAAAAAAAACHNhbGVfZW5kAAAABgAAAAAAAAAKc2FsZV9zdGFydAAAAAAABg==",
+        "AAAAAgAAAF5XaG8gbWF5IHVwZ3JhZGUgYW4gZXZlbnQncyBjb2RlLiBWYXJpYW50cyBtdXN0IG1hdGNoIHRoZSB0aWNrZXQgY29udHJhY3QncwpgVXBncmFkZUF1dGhvcml0eWAuAAAAAAAAAAAAEFVwZ3JhZGVBdXRob3JpdHkAAAACAAAAAAAAAAAAAAAHRmFjdG9yeQAAAAAAAAAAAAAAAAdDcmVhdG9yAA==",
+        "AAAAAAAAABVHZXQgdGhlIGZhY3RvcnkgYWRtaW4AAAAAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAPpAAAAEwAAAAM=",
+        "AAAAAAAAABZHZXQgZXZlbnQgcmVjb3JkIGJ5IElEAAAAAAAJZ2V0X2V2ZW50AAAAAAAAAQAAAAAAAAAIZXZlbnRfaWQAAAAEAAAAAQAAA+kAAAfQAAAAC0V2ZW50UmVjb3JkAAAAAAM=",
+        "AAAAAAAAADVBY2NlcHQgYSBwZW5kaW5nIGFkbWluIHRyYW5zZmVyIChwcm9wb3NlZCBhZG1pbiBvbmx5KQAAAAAAAAxhY2NlcHRfYWRtaW4AAAAAAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAHFSZWdpc3RlciBhIHRpY2tldCBjb250cmFjdCBXQVNNIHRoYXQgbmV3IGV2ZW50cyBjYW4gYmUgZGVwbG95ZWQgZnJvbQooYWRtaW4gb25seSkuIFJldHVybnMgdGhlIG5ldyB0ZW1wbGF0ZSdzIGlkLgAAAAAAAAxhZGRfdGVtcGxhdGUAAAADAAAAAAAAAARuYW1lAAAAEAAAAAAAAAAHdmVyc2lvbgAAAAAEAAAAAAAAAAl3YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAEAAAPpAAAABAAAAAM=",
+        "AAAAAAAAAHZEZXBsb3kgYSBuZXcgdGlja2V0IG1hcmtldHBsYWNlIGV2ZW50IGZyb20gYSByZWdpc3RlcmVkIHRlbXBsYXRlLCBvcgpmcm9tIHRoZSBkZWZhdWx0IHRlbXBsYXRlIHdoZW4gYHRlbXBsYXRlX2lkYCBpcyAwAAAAAAAMY3JlYXRlX2V2ZW50AAAABAAAAAAAAAAEc2FsdAAAA+4AAAAgAAAAAAAAAA1ldmVudF9jcmVhdG9yAAAAAAAAEwAAAAAAAAALdGVtcGxhdGVfaWQAAAAABAAAAAAAAAAGcGFyYW1zAAAAAAfQAAAAC0V2ZW50UGFyYW1zAAAAAAEAAAPpAAAAEwAAAAM=",
+        "AAAAAAAAABRHZXQgYSB0ZW1wbGF0ZSBieSBJRAAAAAxnZXRfdGVtcGxhdGUAAAABAAAAAAAAAAt0ZW1wbGF0ZV9pZAAAAAAEAAAAAQAAA+kAAAfQAAAACFRlbXBsYXRlAAAAAw==",
+        "AAAAAAAAAUZTZXQgdXAgdGhlIGZhY3Rvcnkgd2l0aCBpdHMgYWRtaW4gYW5kIHRoZSB0aWNrZXQgY29udHJhY3QgV0FTTSBoYXNoLAp3aGljaCBpcyByZWdpc3RlcmVkIGFzIHRlbXBsYXRlIDEsICJzdGFuZGFyZCIgdmVyc2lvbiAxLCBhbmQgYmVjb21lcwp0aGUgZGVmYXVsdCB0ZW1wbGF0ZS4gVGhpcyBydW5zIGFzIHBhcnQgb2YgdGhlIGRlcGxveW1lbnQsIHNvIG5vYm9keQpjYW4gY2xhaW0gdGhlIGZhY3RvcnkgaW4gYmV0d2VlbiwgYW5kIHRoZSBhZG1pbiBtdXN0IGF1dGhvcml6ZSBpdC4KTm8gcHJvdG9jb2wgZmVlIGlzIGNoYXJnZWQgdW50aWwgdGhlIGFkbWluIHNldHMgb25lLgAAAAAADV9fY29uc3RydWN0b3IAAAAAAAACAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAACXdhc21faGFzaAAAAAAAA+4AAAAgAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAABxHZXQgYWxsIHJlZ2lzdGVyZWQgdGVtcGxhdGVzAAAADWdldF90ZW1wbGF0ZXMAAAAAAAAAAAAAAQAAA+oAAAfQAAAACFRlbXBsYXRl",
+        "AAAAAAAAAD5HZXQgdGhlIFdBU00gaGFzaCBvZiB0aGUgZGVmYXVsdCB0ZW1wbGF0ZSwgdXNlZCBmb3IgbmV3IGV2ZW50cwAAAAAADWdldF93YXNtX2hhc2gAAAAAAAAAAAAAAQAAA+kAAAPuAAAAIAAAAAM=",
+        "AAAAAAAAALVSZXBsYWNlIHRoZSB0aWNrZXQgY29udHJhY3QgV0FTTSB1c2VkIGZvciBuZXcgZXZlbnRzIChhZG1pbiBvbmx5KS4gVGhlCmhhc2ggaXMgcmVnaXN0ZXJlZCBhcyB0aGUgbmV4dCB2ZXJzaW9uIG9mIHRoZSBkZWZhdWx0IHRlbXBsYXRlJ3MgbmFtZSwKd2hpY2ggYmVjb21lcyB0aGUgbmV3IGRlZmF1bHQgdGVtcGxhdGUuAAAAAAAADXNldF93YXNtX2hhc2gAAAAAAAABAAAAAAAAAAl3YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAEAAAPpAAAD7QAAAAAAAAAD",
         "AAAAAAAAAA5HZXQgYWxsIGV2ZW50cwAAAAAADmdldF9hbGxfZXZlbnRzAAAAAAAAAAAAAQAAA+oAAAfQAAAAC0V2ZW50UmVjb3JkAA==",
+        "AAAAAAAAAIZQcm9wb3NlIGEgbmV3IGFkbWluIChhZG1pbiBvbmx5KS4gVGhlIHByb3Bvc2FsIHRha2VzIGVmZmVjdCBvbmNlIHRoZSBuZXcKYWRtaW4gY2FsbHMgYGFjY2VwdF9hZG1pbmAsIGFuZCBhIGxhdGVyIHByb3Bvc2FsIHJlcGxhY2VzIGl0LgAAAAAADnRyYW5zZmVyX2FkbWluAAAAAAABAAAAAAAAAAluZXdfYWRtaW4AAAAAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAKVVcGdyYWRlIHRoZSBjb2RlIG9mIGRlcGxveWVkIGV2ZW50cyB0byBhIHJlZ2lzdGVyZWQgdGVtcGxhdGUgKGFkbWluCm9ubHkpLiBFdmVyeSBldmVudCBtdXN0IHN0aWxsIGxlYXZlIHVwZ3JhZGVzIHRvIHRoZSBmYWN0b3J5LCBvdGhlcndpc2UKbm9uZSBvZiB0aGVtIGFyZSB1cGdyYWRlZC4AAAAAAAAOdXBncmFkZV9ldmVudHMAAAAAAAIAAAAAAAAACWV2ZW50X2lkcwAAAAAAA+oAAAAEAAAAAAAAAAt0ZW1wbGF0ZV9pZAAAAAAEAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
         "AAAAAAAAACJHZXQgdG90YWwgbnVtYmVyIG9mIGV2ZW50cyBjcmVhdGVkAAAAAAAPZ2V0X2V2ZW50X2NvdW50AAAAAAAAAAABAAAABA==",
-        "AAAAAAAAACxHZXQgYWxsIGV2ZW50cyBjcmVhdGVkIGJ5IGEgc3BlY2lmaWMgYWRkcmVzcwAAABJnZXRfY3JlYXRvcl9ldmVudHMAAAAAAAEAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAEAAAPqAAAH0AAAAAtFdmVudFJlY29yZAA=" ]),
+        "AAAAAAAAACxHZXQgdGhlIElEIG9mIGEgdGVtcGxhdGUgYnkgbmFtZSBhbmQgdmVyc2lvbgAAAA9nZXRfdGVtcGxhdGVfaWQAAAAAAgAAAAAAAAAEbmFtZQAAABAAAAAAAAAAB3ZlcnNpb24AAAAABAAAAAEAAAPpAAAABAAAAAM=",
+        "AAAAAAAAAI1FeHRlbmQgdGhlIFRUTCBvZiBhbiBldmVudCByZWNvcmQgYW5kIGl0cyBjcmVhdG9yJ3MgaW5kZXggdW50aWwgYQptb250aCBhZnRlciB0aGUgZXZlbnQgZW5kcy4gQW55b25lIG1heSBjYWxsIHRoaXMgYW5kIHBheSBmb3IgdGhlCmV4dGVuc2lvbi4AAAAAAAAQZXh0ZW5kX2V2ZW50X3R0bAAAAAEAAAAAAAAACGV2ZW50X2lkAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAADVHZXQgdGhlIHByb3RvY29sIGZlZSBjaGFyZ2VkIG9uIG5ld2x5IGRlcGxveWVkIGV2ZW50cwAAAAAAABBnZXRfcHJvdG9jb2xfZmVlAAAAAAAAAAEAAAPpAAAH0AAAAAtQcm90b2NvbEZlZQAAAAAD",
+        "AAAAAAAAAIlTZXQgdGhlIHByb3RvY29sIGZlZSBjaGFyZ2VkIG9uIGV2ZW50cyBkZXBsb3llZCBmcm9tIG5vdyBvbiAoYWRtaW4gb25seSkuCkV2ZW50cyB0aGF0IGFscmVhZHkgZXhpc3Qga2VlcCB0aGUgZmVlIHRoZXkgd2VyZSBkZXBsb3llZCB3aXRoLgAAAAAAABBzZXRfcHJvdG9jb2xfZmVlAAAAAQAAAAAAAAAMcHJvdG9jb2xfZmVlAAAH0AAAAAtQcm90b2NvbEZlZQAAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAADJHZXQgdGhlIGFkbWluIHByb3Bvc2VkIGJ5IGB0cmFuc2Zlcl9hZG1pbmAsIGlmIGFueQAAAAAAEWdldF9wZW5kaW5nX2FkbWluAAAAAAAAAAAAAAEAAAPoAAAAEw==",
+        "AAAAAAAAACxHZXQgYWxsIGV2ZW50cyBjcmVhdGVkIGJ5IGEgc3BlY2lmaWMgYWRkcmVzcwAAABJnZXRfY3JlYXRvcl9ldmVudHMAAAAAAAEAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAEAAAPqAAAH0AAAAAtFdmVudFJlY29yZAA=",
+        "AAAAAAAAACJHZXQgdGhlIElEIG9mIHRoZSBkZWZhdWx0IHRlbXBsYXRlAAAAAAAUZ2V0X2RlZmF1bHRfdGVtcGxhdGUAAAAAAAAAAQAAA+kAAAAEAAAAAw==",
+        "AAAAAAAAAMFIYW5kIHRoZSByaWdodCB0byB1cGdyYWRlIGEgZGVwbG95ZWQgZXZlbnQgdG8gaXRzIGNyZWF0b3IgKGFkbWluCm9ubHkpLiBUaGUgZXZlbnQgbXVzdCBzdGlsbCBsZWF2ZSB1cGdyYWRlcyB0byB0aGUgZmFjdG9yeTsgb25jZSB0aGUKY3JlYXRvciBob2xkcyB0aGF0IHJpZ2h0LCBvbmx5IHRoZSBjcmVhdG9yIGNhbiBoYW5kIGl0IGJhY2suAAAAAAAAG3NldF9ldmVudF91cGdyYWRlX2F1dGhvcml0eQAAAAACAAAAAAAAAAhldmVudF9pZAAAAAQAAAAAAAAACWF1dGhvcml0eQAAAAAAB9AAAAAQVXBncmFkZUF1dGhvcml0eQAAAAEAAAPpAAAD7QAAAAAAAAAD" ]),
       options
     )
   }
   public readonly fromJSON = {
-    get_event: this.txFromJSON<EventRecord>,
-        initialize: this.txFromJSON<null>,
-        create_event: this.txFromJSON<string>,
-        get_wasm_hash: this.txFromJSON<Buffer>,
+    get_admin: this.txFromJSON<Result<string>>,
+        get_event: this.txFromJSON<Result<EventRecord>>,
+        accept_admin: this.txFromJSON<Result<readonly []>>,
+        add_template: this.txFromJSON<Result<u32>>,
+        create_event: this.txFromJSON<Result<string>>,
+        get_template: this.txFromJSON<Result<Template>>,
+        get_templates: this.txFromJSON<Array<Template>>,
+        get_wasm_hash: this.txFromJSON<Result<Buffer>>,
+        set_wasm_hash: this.txFromJSON<Result<readonly []>>,
         get_all_events: this.txFromJSON<Array<EventRecord>>,
+        transfer_admin: this.txFromJSON<Result<readonly []>>,
+        upgrade_events: this.txFromJSON<Result<readonly []>>,
         get_event_count: this.txFromJSON<u32>,
-        get_creator_events: this.txFromJSON<Array<EventRecord>>
+        get_template_id: this.txFromJSON<Result<u32>>,
+        extend_event_ttl: this.txFromJSON<Result<readonly []>>,
+        get_protocol_fee: this.txFromJSON<Result<ProtocolFee>>,
+        set_protocol_fee: this.txFromJSON<Result<readonly []>>,
+        get_pending_admin: this.txFromJSON<Option<string>>,
+        get_creator_events: this.txFromJSON<Array<EventRecord>>,
+        get_default_template: this.txFromJSON<Result<u32>>,
+        set_event_upgrade_authority: this.txFromJSON<Result<readonly []>>
   }
 }
\ No newline at end of file
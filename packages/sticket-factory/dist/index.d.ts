@@ -1,6 +1,6 @@
 import { Buffer } from "buffer";
-import { AssembledTransaction, Client as ContractClient, ClientOptions as ContractClientOptions, MethodOptions } from '@stellar/stellar-sdk/contract';
-import type { u32, u64, i128, AssembledTransactionOptions } from '@stellar/stellar-sdk/contract';
+import { AssembledTransaction, Client as ContractClient, ClientOptions as ContractClientOptions, MethodOptions, Result } from '@stellar/stellar-sdk/contract';
+import type { u32, u64, i128, AssembledTransactionOptions, Option } from '@stellar/stellar-sdk/contract';
 export * from '@stellar/stellar-sdk';
 export * as contract from '@stellar/stellar-sdk/contract';
 export * as rpc from '@stellar/stellar-sdk/rpc';
@@ -10,74 +10,247 @@ export declare const networks: {
         readonly contractId: "CA2HZDTERE5QPWW3G3YZWB2XTNPGCW2HTEJEWDKCCAHZ7Q4NEO5FWL4V";
     };
 };
-export type DataKey = {
-    tag: "WasmHash";
-    values: void;
-} | {
-    tag: "EventCounter";
-    values: void;
-} | {
-    tag: "EventRecord";
-    values: readonly [u32];
-} | {
-    tag: "CreatorEvents";
-    values: readonly [string];
-} | {
-    tag: "AllEvents";
-    values: void;
+export declare const Errors: {
+    2: {
+        message: string;
+    };
+    3: {
+        message: string;
+    };
+    4: {
+        message: string;
+    };
+    5: {
+        message: string;
+    };
+    6: {
+        message: string;
+    };
+    7: {
+        message: string;
+    };
+    8: {
+        message: string;
+    };
+    9: {
+        message: string;
+    };
+    10: {
+        message: string;
+    };
+    11: {
+        message: string;
+    };
+    12: {
+        message: string;
+    };
 };
+/**
+ * A ticket contract WASM registered with the factory, such as version 1 of
+ * "standard" or "soulbound". Each name and version pair is registered once.
+ */
+export interface Template {
+    name: string;
+    template_id: u32;
+    version: u32;
+    wasm_hash: Buffer;
+}
+/**
+ * Tier configuration forwarded to the ticket contract's `init`. Field names
+ * and types must match the ticket contract's `TierConfig`.
+ */
+export interface TierConfig {
+    creator_fee_bps: u32;
+    max_per_wallet: u32;
+    name: string;
+    price: i128;
+    supply: u32;
+}
+/**
+ * Everything needed to configure a new event. Pass an empty `tiers` list
+ * for a single tier built from `total_supply`, `primary_price` and
+ * `creator_fee_bps`.
+ */
+export interface EventParams {
+    creator_fee_bps: u32;
+    event_metadata: string;
+    name: string;
+    payment_token: string;
+    primary_price: i128;
+    schedule: EventSchedule;
+    symbol: string;
+    tiers: Array<TierConfig>;
+    total_supply: u32;
+}
 export interface EventRecord {
     created_at: u64;
     event_contract: string;
     event_creator: string;
+    event_end: u64;
+    event_start: u64;
     name: string;
     symbol: string;
+    template_id: u32;
+    template_version: u32;
 }
+/**
+ * The platform's cut of sales on events deployed from now on. Field names
+ * and types must match the ticket contract's `ProtocolFee`.
+ */
+export interface ProtocolFee {
+    primary_bps: u32;
+    secondary_bps: u32;
+    treasury: string;
+}
+/**
+ * Sale and event timestamps forwarded to the ticket contract's `init`.
+ * Field names and types must match the ticket contract's `EventSchedule`.
+ */
+export interface EventSchedule {
+    event_end: u64;
+    event_start: u64;
+    sale_end: u64;
+    sale_start: u64;
+}
+/**
+ * Who may upgrade an event's code. Variants must match the ticket contract's
+ * `UpgradeAuthority`.
+ */
+export type UpgradeAuthority = {
+    tag: "Factory";
+    values: void;
+} | {
+    tag: "Creator";
+    values: void;
+};
 export interface Client {
+    /**
+     * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the factory admin
+     */
+    get_admin: (options?: AssembledTransactionOptions<Result<string>>) => Promise<AssembledTransaction<Result<string>>>;
     /**
      * Construct and simulate a get_event transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      * Get event record by ID
      */
     get_event: ({ event_id }: {
         event_id: u32;
-    }, options?: AssembledTransactionOptions<EventRecord>) => Promise<AssembledTransaction<EventRecord>>;
+    }, options?: AssembledTransactionOptions<Result<EventRecord>>) => Promise<AssembledTransaction<Result<EventRecord>>>;
     /**
-     * Construct and simulate a initialize transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Initialize the factory with the ticket contract WASM hash
+     * Construct and simulate a accept_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Accept a pending admin transfer (proposed admin only)
      */
-    initialize: ({ wasm_hash }: {
+    accept_admin: (options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>;
+    /**
+     * Construct and simulate a add_template transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Register a ticket contract WASM that new events can be deployed from
+     * (admin only). Returns the new template's id.
+     */
+    add_template: ({ name, version, wasm_hash }: {
+        name: string;
+        version: u32;
         wasm_hash: Buffer;
-    }, options?: AssembledTransactionOptions<null>) => Promise<AssembledTransaction<null>>;
+    }, options?: AssembledTransactionOptions<Result<u32>>) => Promise<AssembledTransaction<Result<u32>>>;
     /**
      * Construct and simulate a create_event transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Deploy a new ticket marketplace event
+     * Deploy a new ticket marketplace event from a registered template, or
+     * from the default template when `template_id` is 0
      */
-    create_event: ({ salt, event_creator, total_supply, primary_price, creator_fee_bps, event_metadata, name, symbol, payment_token }: {
+    create_event: ({ salt, event_creator, template_id, params }: {
         salt: Buffer;
         event_creator: string;
-        total_supply: u32;
-        primary_price: i128;
-        creator_fee_bps: u32;
-        event_metadata: string;
-        name: string;
-        symbol: string;
-        payment_token: string;
-    }, options?: AssembledTransactionOptions<string>) => Promise<AssembledTransaction<string>>;
+        template_id: u32;
+        params: EventParams;
+    }, options?: AssembledTransactionOptions<Result<string>>) => Promise<AssembledTransaction<Result<string>>>;
+    /**
+     * Construct and simulate a get_template transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get a template by ID
+     */
+    get_template: ({ template_id }: {
+        template_id: u32;
+    }, options?: AssembledTransactionOptions<Result<Template>>) => Promise<AssembledTransaction<Result<Template>>>;
+    /**
+     * Construct and simulate a get_templates transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get all registered templates
+     */
+    get_templates: (options?: AssembledTransactionOptions<Array<Template>>) => Promise<AssembledTransaction<Array<Template>>>;
     /**
      * Construct and simulate a get_wasm_hash transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Get the WASM hash stored in the factory
+     * Get the WASM hash of the default template, used for new events
      */
-    get_wasm_hash: (options?: AssembledTransactionOptions<Buffer>) => Promise<AssembledTransaction<Buffer>>;
+    get_wasm_hash: (options?: AssembledTransactionOptions<Result<Buffer>>) => Promise<AssembledTransaction<Result<Buffer>>>;
+    /**
+     * Construct and simulate a set_wasm_hash transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Replace the ticket contract WASM used for new events (admin only). The
+     * hash is registered as the next version of the default template's name,
+     * which becomes the new default template.
+     */
+    set_wasm_hash: ({ wasm_hash }: {
+        wasm_hash: Buffer;
+    }, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>;
     /**
      * Construct and simulate a get_all_events transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      * Get all events
      */
     get_all_events: (options?: AssembledTransactionOptions<Array<EventRecord>>) => Promise<AssembledTransaction<Array<EventRecord>>>;
+    /**
+     * Construct and simulate a transfer_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Propose a new admin (admin only). The proposal takes effect once the new
+     * admin calls `accept_admin`, and a later proposal replaces it.
+     */
+    transfer_admin: ({ new_admin }: {
+        new_admin: string;
+    }, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>;
+    /**
+     * Construct and simulate a upgrade_events transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Upgrade the code of deployed events to a registered template (admin
+     * only). Every event must still leave upgrades to the factory, otherwise
+     * none of them are upgraded.
+     */
+    upgrade_events: ({ event_ids, template_id }: {
+        event_ids: Array<u32>;
+        template_id: u32;
+    }, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>;
     /**
      * Construct and simulate a get_event_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      * Get total number of events created
      */
     get_event_count: (options?: AssembledTransactionOptions<u32>) => Promise<AssembledTransaction<u32>>;
+    /**
+     * Construct and simulate a get_template_id transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the ID of a template by name and version
+     */
+    get_template_id: ({ name, version }: {
+        name: string;
+        version: u32;
+    }, options?: AssembledTransactionOptions<Result<u32>>) => Promise<AssembledTransaction<Result<u32>>>;
+    /**
+     * Construct and simulate a extend_event_ttl transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Extend the TTL of an event record and its creator's index until a
+     * month after the event ends. Anyone may call this and pay for the
+     * extension.
+     */
+    extend_event_ttl: ({ event_id }: {
+        event_id: u32;
+    }, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>;
+    /**
+     * Construct and simulate a get_protocol_fee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the protocol fee charged on newly deployed events
+     */
+    get_protocol_fee: (options?: AssembledTransactionOptions<Result<ProtocolFee>>) => Promise<AssembledTransaction<Result<ProtocolFee>>>;
+    /**
+     * Construct and simulate a set_protocol_fee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Set the protocol fee charged on events deployed from now on (admin only).
+     * Events that already exist keep the fee they were deployed with.
+     */
+    set_protocol_fee: ({ protocol_fee }: {
+        protocol_fee: ProtocolFee;
+    }, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>;
+    /**
+     * Construct and simulate a get_pending_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the admin proposed by `transfer_admin`, if any
+     */
+    get_pending_admin: (options?: AssembledTransactionOptions<Option<string>>) => Promise<AssembledTransaction<Option<string>>>;
     /**
      * Construct and simulate a get_creator_events transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      * Get all events created by a specific address
@@ -85,10 +258,30 @@ export interface Client {
     get_creator_events: ({ creator }: {
         creator: string;
     }, options?: AssembledTransactionOptions<Array<EventRecord>>) => Promise<AssembledTransaction<Array<EventRecord>>>;
+    /**
+     * Construct and simulate a get_default_template transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the ID of the default template
+     */
+    get_default_template: (options?: AssembledTransactionOptions<Result<u32>>) => Promise<AssembledTransaction<Result<u32>>>;
+    /**
+     * Construct and simulate a set_event_upgrade_authority transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Hand the right to upgrade a deployed event to its creator (admin
+     * only). The event must still leave upgrades to the factory; once the
+     * creator holds that right, only the creator can hand it back.
+     */
+    set_event_upgrade_authority: ({ event_id, authority }: {
+        event_id: u32;
+        authority: UpgradeAuthority;
+    }, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>;
 }
 export declare class Client extends ContractClient {
     readonly options: ContractClientOptions;
     static deploy<T = Client>(
+    /** Constructor/Initialization Args for the contract's `__constructor` method */
+    { admin, wasm_hash }: {
+        admin: string;
+        wasm_hash: Buffer;
+    }, 
     /** Options for initializing a Client as well as for calling a method, with extras specific to deploying. */
     options: MethodOptions & Omit<ContractClientOptions, "contractId"> & {
         /** The hash of the Wasm blob, which must already be installed on-chain. */
@@ -100,12 +293,26 @@ export declare class Client extends ContractClient {
     }): Promise<AssembledTransaction<T>>;
     constructor(options: ContractClientOptions);
     readonly fromJSON: {
-        get_event: (json: string) => AssembledTransaction<EventRecord>;
-        initialize: (json: string) => AssembledTransaction<null>;
-        create_event: (json: string) => AssembledTransaction<string>;
-        get_wasm_hash: (json: string) => AssembledTransaction<Buffer>;
+        get_admin: (json: string) => AssembledTransaction<Result<string, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_event: (json: string) => AssembledTransaction<Result<EventRecord, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        accept_admin: (json: string) => AssembledTransaction<Result<readonly [], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        add_template: (json: string) => AssembledTransaction<Result<number, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        create_event: (json: string) => AssembledTransaction<Result<string, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_template: (json: string) => AssembledTransaction<Result<Template, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_templates: (json: string) => AssembledTransaction<Template[]>;
+        get_wasm_hash: (json: string) => AssembledTransaction<Result<Buffer, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        set_wasm_hash: (json: string) => AssembledTransaction<Result<readonly [], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
         get_all_events: (json: string) => AssembledTransaction<EventRecord[]>;
+        transfer_admin: (json: string) => AssembledTransaction<Result<readonly [], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        upgrade_events: (json: string) => AssembledTransaction<Result<readonly [], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
         get_event_count: (json: string) => AssembledTransaction<number>;
+        get_template_id: (json: string) => AssembledTransaction<Result<number, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        extend_event_ttl: (json: string) => AssembledTransaction<Result<readonly [], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_protocol_fee: (json: string) => AssembledTransaction<Result<ProtocolFee, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        set_protocol_fee: (json: string) => AssembledTransaction<Result<readonly [], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_pending_admin: (json: string) => AssembledTransaction<Option<string>>;
         get_creator_events: (json: string) => AssembledTransaction<EventRecord[]>;
+        get_default_template: (json: string) => AssembledTransaction<Result<number, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        set_event_upgrade_authority: (json: string) => AssembledTransaction<Result<readonly [], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
     };
 }
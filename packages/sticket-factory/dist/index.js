@@ -13,32 +13,83 @@ export const networks = {
         contractId: "CA2HZDTERE5QPWW3G3YZWB2XTNPGCW2HTEJEWDKCCAHZ7Q4NEO5FWL4V",
     }
 };
+export const Errors = {
+    2: { message: "NotInitialized" },
+    3: { message: "InvalidSupply" },
+    4: { message: "InvalidPrice" },
+    5: { message: "InvalidFeeBps" },
+    6: { message: "EventInitFailed" },
+    7: { message: "EventNotFound" },
+    8: { message: "InvalidSchedule" },
+    9: { message: "NoPendingAdmin" },
+    10: { message: "EventUpgradeFailed" },
+    11: { message: "TemplateNotFound" },
+    12: { message: "TemplateExists" }
+};
 export class Client extends ContractClient {
     options;
     static async deploy(
+    /** Constructor/Initialization Args for the contract's `__constructor` method */
+    { admin, wasm_hash }, 
     /** Options for initializing a Client as well as for calling a method, with extras specific to deploying. */
     options) {
-        return ContractClient.deploy(null, options);
+        return ContractClient.deploy({ admin, wasm_hash }, options);
     }
     constructor(options) {
-        super(new ContractSpec(["AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAABQAAAAAAAAAAAAAACFdhc21IYXNoAAAAAAAAAAAAAAAMRXZlbnRDb3VudGVyAAAAAQAAAAAAAAALRXZlbnRSZWNvcmQAAAAAAQAAAAQAAAABAAAAAAAAAA1DcmVhdG9yRXZlbnRzAAAAAAAAAQAAABMAAAAAAAAAAAAAAAlBbGxFdmVudHMAAAA=",
-            "AAAAAQAAAAAAAAAAAAAAC0V2ZW50UmVjb3JkAAAAAAUAAAAAAAAACmNyZWF0ZWRfYXQAAAAAAAYAAAAAAAAADmV2ZW50X2NvbnRyYWN0AAAAAAATAAAAAAAAAA1ldmVudF9jcmVhdG9yAAAAAAAAEwAAAAAAAAAEbmFtZQAAABAAAAAAAAAABnN5bWJvbAAAAAAAEA==",
-            "AAAAAAAAABZHZXQgZXZlbnQgcmVjb3JkIGJ5IElEAAAAAAAJZ2V0X2V2ZW50AAAAAAAAAQAAAAAAAAAIZXZlbnRfaWQAAAAEAAAAAQAAB9AAAAALRXZlbnRSZWNvcmQA",
-            "AAAAAAAAADlJbml0aWFsaXplIHRoZSBmYWN0b3J5IHdpdGggdGhlIHRpY2tldCBjb250cmFjdCBXQVNNIGhhc2gAAAAAAAAKaW5pdGlhbGl6ZQAAAAAAAQAAAAAAAAAJd2FzbV9oYXNoAAAAAAAD7gAAACAAAAAA",
-            "AAAAAAAAACVEZXBsb3kgYSBuZXcgdGlja2V0IG1hcmtldHBsYWNlIGV2ZW50AAAAAAAADGNyZWF0ZV9ldmVudAAAAAkAAAAAAAAABHNhbHQAAAPuAAAAIAAAAAAAAAANZXZlbnRfY3JlYXRvcgAAAAAAABMAAAAAAAAADHRvdGFsX3N1cHBseQAAAAQAAAAAAAAADXByaW1hcnlfcHJpY2UAAAAAAAALAAAAAAAAAA9jcmVhdG9yX2ZlZV9icHMAAAAABAAAAAAAAAAOZXZlbnRfbWV0YWRhdGEAAAAAABAAAAAAAAAABG5hbWUAAAAQAAAAAAAAAAZzeW1ib2wAAAAAABAAAAAAAAAADXBheW1lbnRfdG9rZW4AAAAAAAATAAAAAQAAABM=",
-            "AAAAAAAAACdHZXQgdGhlIFdBU00gaGFzaCBzdG9yZWQgaW4gdGhlIGZhY3RvcnkAAAAADWdldF93YXNtX2hhc2gAAAAAAAAAAAAAAQAAA+4AAAAg",
+        super(new ContractSpec(["AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAACwAAAAAAAAAOTm90SW5pdGlhbGl6ZWQAAAAAAAIAAAAAAAAADUludmFsaWRTdXBwbHkAAAAAAAADAAAAAAAAAAxJbnZhbGlkUHJpY2UAAAAEAAAAAAAAAA1JbnZhbGlkRmVlQnBzAAAAAAAABQAAAAAAAAAPRXZlbnRJbml0RmFpbGVkAAAAAAYAAAAAAAAADUV2ZW50Tm90Rm91bmQAAAAAAAAHAAAAAAAAAA9JbnZhbGlkU2NoZWR1bGUAAAAACAAAAAAAAAAOTm9QZW5kaW5nQWRtaW4AAAAAAAkAAAAAAAAAEkV2ZW50VXBncmFkZUZhaWxlZAAAAAAACgAAAAAAAAAQVGVtcGxhdGVOb3RGb3VuZAAAAAsAAAAAAAAADlRlbXBsYXRlRXhpc3RzAAAAAAAM",
+            "AAAAAQAAAJJBIHRpY2tldCBjb250cmFjdCBXQVNNIHJlZ2lzdGVyZWQgd2l0aCB0aGUgZmFjdG9yeSwgc3VjaCBhcyB2ZXJzaW9uIDEgb2YKInN0YW5kYXJkIiBvciAic291bGJvdW5kIi4gRWFjaCBuYW1lIGFuZCB2ZXJzaW9uIHBhaXIgaXMgcmVnaXN0ZXJlZCBvbmNlLgAAAAAAAAAAAAhUZW1wbGF0ZQAAAAQAAAAAAAAABG5hbWUAAAAQAAAAAAAAAAt0ZW1wbGF0ZV9pZAAAAAAEAAAAAAAAAAd2ZXJzaW9uAAAAAAQAAAAAAAAACXdhc21faGFzaAAAAAAAA+4AAAAg",
+            "AAAAAQAAAIJUaWVyIGNvbmZpZ3VyYXRpb24gZm9yd2FyZGVkIHRvIHRoZSB0aWNrZXQgY29udHJhY3QncyBgaW5pdGAuIEZpZWxkIG5hbWVzCmFuZCB0eXBlcyBtdXN0IG1hdGNoIHRoZSB0aWNrZXQgY29udHJhY3QncyBgVGllckNvbmZpZ2AuAAAAAAAAAAAAClRpZXJDb25maWcAAAAAAAUAAAAAAAAAD2NyZWF0b3JfZmVlX2JwcwAAAAAEAAAAAAAAAA5tYXhfcGVyX3dhbGxldAAAAAAABAAAAAAAAAAEbmFtZQAAABAAAAAAAAAABXByaWNlAAAAAAAACwAAAAAAAAAGc3VwcGx5AAAAAAAE",
+            "AAAAAQAAAJpFdmVyeXRoaW5nIG5lZWRlZCB0byBjb25maWd1cmUgYSBuZXcgZXZlbnQuIFBhc3MgYW4gZW1wdHkgYHRpZXJzYCBsaXN0CmZvciBhIHNpbmdsZSB0aWVyIGJ1aWx0IGZyb20gYHRvdGFsX3N1cHBseWAsIGBwcmltYXJ5X3ByaWNlYCBhbmQKYGNyZWF0b3JfZmVlX2Jwc2AuAAAAAAAAAAAAC0V2ZW50UGFyYW1zAAAAAAkAAAAAAAAAD2NyZWF0b3JfZmVlX2JwcwAAAAAEAAAAAAAAAA5ldmVudF9tZXRhZGF0YQAAAAAAEAAAAAAAAAAEbmFtZQAAABAAAAAAAAAADXBheW1lbnRfdG9rZW4AAAAAAAATAAAAAAAAAA1wcmltYXJ5X3ByaWNlAAAAAAAACwAAAAAAAAAIc2NoZWR1bGUAAAfQAAAADUV2ZW50U2NoZWR1bGUAAAAAAAAAAAAABnN5bWJvbAAAAAAAEAAAAAAAAAAFdGllcnMAAAAAAAPqAAAH0AAAAApUaWVyQ29uZmlnAAAAAAAAAAAADHRvdGFsX3N1cHBseQAAAAQ=",
+            "AAAAAQAAAAAAAAAAAAAAC0V2ZW50UmVjb3JkAAAAAAkAAAAAAAAACmNyZWF0ZWRfYXQAAAAAAAYAAAAAAAAADmV2ZW50X2NvbnRyYWN0AAAAAAATAAAAAAAAAA1ldmVudF9jcmVhdG9yAAAAAAAAEwAAAAAAAAAJZXZlbnRfZW5kAAAAAAAABgAAAAAAAAALZXZlbnRfc3RhcnQAAAAABgAAAAAAAAAEbmFtZQAAABAAAAAAAAAABnN5bWJvbAAAAAAAEAAAAAAAAAALdGVtcGxhdGVfaWQAAAAABAAAAAAAAAAQdGVtcGxhdGVfdmVyc2lvbgAAAAQ=",
+            "AAAAAQAAAIFUaGUgcGxhdGZvcm0ncyBjdXQgb2Ygc2FsZXMgb24gZXZlbnRzIGRlcGxveWVkIGZyb20gbm93IG9uLiBGaWVsZCBuYW1lcwphbmQgdHlwZXMgbXVzdCBtYXRjaCB0aGUgdGlja2V0IGNvbnRyYWN0J3MgYFByb3RvY29sRmVlYC4AAAAAAAAAAAAAC1Byb3RvY29sRmVlAAAAAAMAAAAAAAAAC3ByaW1hcnlfYnBzAAAAAAQAAAAAAAAADXNlY29uZGFyeV9icHMAAAAAAAAEAAAAAAAAAAh0cmVhc3VyeQAAABM=",
+            "AAAABQAAAEVFbWl0dGVkIHdoZW4gdGhlIGZhY3RvcnkgZGVwbG95cyBhbmQgaW5pdGlhbGl6ZXMgYSBuZXcgZXZlbnQgY29udHJhY3QAAAAAAAAAAAAADEV2ZW50Q3JlYXRlZAAAAAEAAAANZXZlbnRfY3JlYXRlZAAAAAAAAAUAAAAAAAAACGV2ZW50X2lkAAAABAAAAAEAAAAAAAAADWV2ZW50X2NyZWF0b3IAAAAAAAATAAAAAQAAAAAAAAAOZXZlbnRfY29udHJhY3QAAAAAABMAAAAAAAAAAAAAAARuYW1lAAAAEAAAAAAAAAAAAAAABnN5bWJvbAAAAAAAEAAAAAAAAAAC",
+            "AAAAAQAAAIxTYWxlIGFuZCBldmVudCB0aW1lc3RhbXBzIGZvcndhcmRlZCB0byB0aGUgdGlja2V0IGNvbnRyYWN0J3MgYGluaXRgLgpGaWVsZCBuYW1lcyBhbmQgdHlwZXMgbXVzdCBtYXRjaCB0aGUgdGlja2V0IGNvbnRyYWN0J3MgYEV2ZW50U2NoZWR1bGVgLgAAAAAAAAANRXZlbnRTY2hlZHVsZQAAAAAAAAQAAAAAAAAACWV2ZW50X2VuZAAAAAAAAAYAAAAAAAAAC2V2ZW50X3N0YXJ0AAAAAAYAAAAAAAAACHNhbGVfZW5kAAAABgAAAAAAAAAKc2FsZV9zdGFydAAAAAAABg==",
+            "AAAAAgAAAF5XaG8gbWF5IHVwZ3JhZGUgYW4gZXZlbnQncyBjb2RlLiBWYXJpYW50cyBtdXN0IG1hdGNoIHRoZSB0aWNrZXQgY29udHJhY3QncwpgVXBncmFkZUF1dGhvcml0eWAuAAAAAAAAAAAAEFVwZ3JhZGVBdXRob3JpdHkAAAACAAAAAAAAAAAAAAAHRmFjdG9yeQAAAAAAAAAAAAAAAAdDcmVhdG9yAA==",
+            "AAAAAAAAABVHZXQgdGhlIGZhY3RvcnkgYWRtaW4AAAAAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAPpAAAAEwAAAAM=",
+            "AAAAAAAAABZHZXQgZXZlbnQgcmVjb3JkIGJ5IElEAAAAAAAJZ2V0X2V2ZW50AAAAAAAAAQAAAAAAAAAIZXZlbnRfaWQAAAAEAAAAAQAAA+kAAAfQAAAAC0V2ZW50UmVjb3JkAAAAAAM=",
+            "AAAAAAAAADVBY2NlcHQgYSBwZW5kaW5nIGFkbWluIHRyYW5zZmVyIChwcm9wb3NlZCBhZG1pbiBvbmx5KQAAAAAAAAxhY2NlcHRfYWRtaW4AAAAAAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+            "AAAAAAAAAHFSZWdpc3RlciBhIHRpY2tldCBjb250cmFjdCBXQVNNIHRoYXQgbmV3IGV2ZW50cyBjYW4gYmUgZGVwbG95ZWQgZnJvbQooYWRtaW4gb25seSkuIFJldHVybnMgdGhlIG5ldyB0ZW1wbGF0ZSdzIGlkLgAAAAAAAAxhZGRfdGVtcGxhdGUAAAADAAAAAAAAAARuYW1lAAAAEAAAAAAAAAAHdmVyc2lvbgAAAAAEAAAAAAAAAAl3YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAEAAAPpAAAABAAAAAM=",
+            "AAAAAAAAAHZEZXBsb3kgYSBuZXcgdGlja2V0IG1hcmtldHBsYWNlIGV2ZW50IGZyb20gYSByZWdpc3RlcmVkIHRlbXBsYXRlLCBvcgpmcm9tIHRoZSBkZWZhdWx0IHRlbXBsYXRlIHdoZW4gYHRlbXBsYXRlX2lkYCBpcyAwAAAAAAAMY3JlYXRlX2V2ZW50AAAABAAAAAAAAAAEc2FsdAAAA+4AAAAgAAAAAAAAAA1ldmVudF9jcmVhdG9yAAAAAAAAEwAAAAAAAAALdGVtcGxhdGVfaWQAAAAABAAAAAAAAAAGcGFyYW1zAAAAAAfQAAAAC0V2ZW50UGFyYW1zAAAAAAEAAAPpAAAAEwAAAAM=",
+            "AAAAAAAAABRHZXQgYSB0ZW1wbGF0ZSBieSBJRAAAAAxnZXRfdGVtcGxhdGUAAAABAAAAAAAAAAt0ZW1wbGF0ZV9pZAAAAAAEAAAAAQAAA+kAAAfQAAAACFRlbXBsYXRlAAAAAw==",
+            "AAAAAAAAAUZTZXQgdXAgdGhlIGZhY3Rvcnkgd2l0aCBpdHMgYWRtaW4gYW5kIHRoZSB0aWNrZXQgY29udHJhY3QgV0FTTSBoYXNoLAp3aGljaCBpcyByZWdpc3RlcmVkIGFzIHRlbXBsYXRlIDEsICJzdGFuZGFyZCIgdmVyc2lvbiAxLCBhbmQgYmVjb21lcwp0aGUgZGVmYXVsdCB0ZW1wbGF0ZS4gVGhpcyBydW5zIGFzIHBhcnQgb2YgdGhlIGRlcGxveW1lbnQsIHNvIG5vYm9keQpjYW4gY2xhaW0gdGhlIGZhY3RvcnkgaW4gYmV0d2VlbiwgYW5kIHRoZSBhZG1pbiBtdXN0IGF1dGhvcml6ZSBpdC4KTm8gcHJvdG9jb2wgZmVlIGlzIGNoYXJnZWQgdW50aWwgdGhlIGFkbWluIHNldHMgb25lLgAAAAAADV9fY29uc3RydWN0b3IAAAAAAAACAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAACXdhc21faGFzaAAAAAAAA+4AAAAgAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+            "AAAAAAAAABxHZXQgYWxsIHJlZ2lzdGVyZWQgdGVtcGxhdGVzAAAADWdldF90ZW1wbGF0ZXMAAAAAAAAAAAAAAQAAA+oAAAfQAAAACFRlbXBsYXRl",
+            "AAAAAAAAAD5HZXQgdGhlIFdBU00gaGFzaCBvZiB0aGUgZGVmYXVsdCB0ZW1wbGF0ZSwgdXNlZCBmb3IgbmV3IGV2ZW50cwAAAAAADWdldF93YXNtX2hhc2gAAAAAAAAAAAAAAQAAA+kAAAPuAAAAIAAAAAM=",
+            "AAAAAAAAALVSZXBsYWNlIHRoZSB0aWNrZXQgY29udHJhY3QgV0FTTSB1c2VkIGZvciBuZXcgZXZlbnRzIChhZG1pbiBvbmx5KS4gVGhlCmhhc2ggaXMgcmVnaXN0ZXJlZCBhcyB0aGUgbmV4dCB2ZXJzaW9uIG9mIHRoZSBkZWZhdWx0IHRlbXBsYXRlJ3MgbmFtZSwKd2hpY2ggYmVjb21lcyB0aGUgbmV3IGRlZmF1bHQgdGVtcGxhdGUuAAAAAAAADXNldF93YXNtX2hhc2gAAAAAAAABAAAAAAAAAAl3YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAEAAAPpAAAD7QAAAAAAAAAD",
             "AAAAAAAAAA5HZXQgYWxsIGV2ZW50cwAAAAAADmdldF9hbGxfZXZlbnRzAAAAAAAAAAAAAQAAA+oAAAfQAAAAC0V2ZW50UmVjb3JkAA==",
+            "AAAAAAAAAIZQcm9wb3NlIGEgbmV3IGFkbWluIChhZG1pbiBvbmx5KS4gVGhlIHByb3Bvc2FsIHRha2VzIGVmZmVjdCBvbmNlIHRoZSBuZXcKYWRtaW4gY2FsbHMgYGFjY2VwdF9hZG1pbmAsIGFuZCBhIGxhdGVyIHByb3Bvc2FsIHJlcGxhY2VzIGl0LgAAAAAADnRyYW5zZmVyX2FkbWluAAAAAAABAAAAAAAAAAluZXdfYWRtaW4AAAAAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+            "AAAAAAAAAKVVcGdyYWRlIHRoZSBjb2RlIG9mIGRlcGxveWVkIGV2ZW50cyB0byBhIHJlZ2lzdGVyZWQgdGVtcGxhdGUgKGFkbWluCm9ubHkpLiBFdmVyeSBldmVudCBtdXN0IHN0aWxsIGxlYXZlIHVwZ3JhZGVzIHRvIHRoZSBmYWN0b3J5LCBvdGhlcndpc2UKbm9uZSBvZiB0aGVtIGFyZSB1cGdyYWRlZC4AAAAAAAAOdXBncmFkZV9ldmVudHMAAAAAAAIAAAAAAAAACWV2ZW50X2lkcwAAAAAAA+oAAAAEAAAAAAAAAAt0ZW1wbGF0ZV9pZAAAAAAEAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
             "AAAAAAAAACJHZXQgdG90YWwgbnVtYmVyIG9mIGV2ZW50cyBjcmVhdGVkAAAAAAAPZ2V0X2V2ZW50X2NvdW50AAAAAAAAAAABAAAABA==",
-            "AAAAAAAAACxHZXQgYWxsIGV2ZW50cyBjcmVhdGVkIGJ5IGEgc3BlY2lmaWMgYWRkcmVzcwAAABJnZXRfY3JlYXRvcl9ldmVudHMAAAAAAAEAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAEAAAPqAAAH0AAAAAtFdmVudFJlY29yZAA="]), options);
+            "AAAAAAAAACxHZXQgdGhlIElEIG9mIGEgdGVtcGxhdGUgYnkgbmFtZSBhbmQgdmVyc2lvbgAAAA9nZXRfdGVtcGxhdGVfaWQAAAAAAgAAAAAAAAAEbmFtZQAAABAAAAAAAAAAB3ZlcnNpb24AAAAABAAAAAEAAAPpAAAABAAAAAM=",
+            "AAAAAAAAAI1FeHRlbmQgdGhlIFRUTCBvZiBhbiBldmVudCByZWNvcmQgYW5kIGl0cyBjcmVhdG9yJ3MgaW5kZXggdW50aWwgYQptb250aCBhZnRlciB0aGUgZXZlbnQgZW5kcy4gQW55b25lIG1heSBjYWxsIHRoaXMgYW5kIHBheSBmb3IgdGhlCmV4dGVuc2lvbi4AAAAAAAAQZXh0ZW5kX2V2ZW50X3R0bAAAAAEAAAAAAAAACGV2ZW50X2lkAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+            "AAAAAAAAADVHZXQgdGhlIHByb3RvY29sIGZlZSBjaGFyZ2VkIG9uIG5ld2x5IGRlcGxveWVkIGV2ZW50cwAAAAAAABBnZXRfcHJvdG9jb2xfZmVlAAAAAAAAAAEAAAPpAAAH0AAAAAtQcm90b2NvbEZlZQAAAAAD",
+            "AAAAAAAAAIlTZXQgdGhlIHByb3RvY29sIGZlZSBjaGFyZ2VkIG9uIGV2ZW50cyBkZXBsb3llZCBmcm9tIG5vdyBvbiAoYWRtaW4gb25seSkuCkV2ZW50cyB0aGF0IGFscmVhZHkgZXhpc3Qga2VlcCB0aGUgZmVlIHRoZXkgd2VyZSBkZXBsb3llZCB3aXRoLgAAAAAAABBzZXRfcHJvdG9jb2xfZmVlAAAAAQAAAAAAAAAMcHJvdG9jb2xfZmVlAAAH0AAAAAtQcm90b2NvbEZlZQAAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+            "AAAAAAAAADJHZXQgdGhlIGFkbWluIHByb3Bvc2VkIGJ5IGB0cmFuc2Zlcl9hZG1pbmAsIGlmIGFueQAAAAAAEWdldF9wZW5kaW5nX2FkbWluAAAAAAAAAAAAAAEAAAPoAAAAEw==",
+            "AAAAAAAAACxHZXQgYWxsIGV2ZW50cyBjcmVhdGVkIGJ5IGEgc3BlY2lmaWMgYWRkcmVzcwAAABJnZXRfY3JlYXRvcl9ldmVudHMAAAAAAAEAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAEAAAPqAAAH0AAAAAtFdmVudFJlY29yZAA=",
+            "AAAAAAAAACJHZXQgdGhlIElEIG9mIHRoZSBkZWZhdWx0IHRlbXBsYXRlAAAAAAAUZ2V0X2RlZmF1bHRfdGVtcGxhdGUAAAAAAAAAAQAAA+kAAAAEAAAAAw==",
+            "AAAAAAAAAMFIYW5kIHRoZSByaWdodCB0byB1cGdyYWRlIGEgZGVwbG95ZWQgZXZlbnQgdG8gaXRzIGNyZWF0b3IgKGFkbWluCm9ubHkpLiBUaGUgZXZlbnQgbXVzdCBzdGlsbCBsZWF2ZSB1cGdyYWRlcyB0byB0aGUgZmFjdG9yeTsgb25jZSB0aGUKY3JlYXRvciBob2xkcyB0aGF0IHJpZ2h0LCBvbmx5IHRoZSBjcmVhdG9yIGNhbiBoYW5kIGl0IGJhY2suAAAAAAAAG3NldF9ldmVudF91cGdyYWRlX2F1dGhvcml0eQAAAAACAAAAAAAAAAhldmVudF9pZAAAAAQAAAAAAAAACWF1dGhvcml0eQAAAAAAB9AAAAAQVXBncmFkZUF1dGhvcml0eQAAAAEAAAPpAAAD7QAAAAAAAAAD"]), options);
         this.options = options;
     }
     fromJSON = {
+        get_admin: (this.txFromJSON),
         get_event: (this.txFromJSON),
-        initialize: (this.txFromJSON),
+        accept_admin: (this.txFromJSON),
+        add_template: (this.txFromJSON),
         create_event: (this.txFromJSON),
+        get_template: (this.txFromJSON),
+        get_templates: (this.txFromJSON),
         get_wasm_hash: (this.txFromJSON),
+        set_wasm_hash: (this.txFromJSON),
         get_all_events: (this.txFromJSON),
+        transfer_admin: (this.txFromJSON),
+        upgrade_events: (this.txFromJSON),
         get_event_count: (this.txFromJSON),
-        get_creator_events: (this.txFromJSON)
+        get_template_id: (this.txFromJSON),
+        extend_event_ttl: (this.txFromJSON),
+        get_protocol_fee: (this.txFromJSON),
+        set_protocol_fee: (this.txFromJSON),
+        get_pending_admin: (this.txFromJSON),
+        get_creator_events: (this.txFromJSON),
+        get_default_template: (this.txFromJSON),
+        set_event_upgrade_authority: (this.txFromJSON)
     };
 }
@@ -1,6 +1,6 @@
 import { Buffer } from "buffer";
-import { AssembledTransaction, Client as ContractClient, ClientOptions as ContractClientOptions, MethodOptions } from '@stellar/stellar-sdk/contract';
-import type { u32, i128, AssembledTransactionOptions, Option } from '@stellar/stellar-sdk/contract';
+import { AssembledTransaction, Client as ContractClient, ClientOptions as ContractClientOptions, MethodOptions, Result } from '@stellar/stellar-sdk/contract';
+import type { u32, u64, i128, AssembledTransactionOptions, Option } from '@stellar/stellar-sdk/contract';
 export * from '@stellar/stellar-sdk';
 export * as contract from '@stellar/stellar-sdk/contract';
 export * as rpc from '@stellar/stellar-sdk/rpc';
@@ -10,22 +10,183 @@ export declare const networks: {
         readonly contractId: "CBBF6CHQTD2KPFI5VQ5BT7LHPPWXGHEAGMTJ3OUZ74TVD6DCJMWN4F4V";
     };
 };
-export type DataKey = {
-    tag: "EventInfo";
-    values: void;
-} | {
-    tag: "Ticket";
-    values: readonly [u32];
-} | {
-    tag: "TicketsMinted";
-    values: void;
-} | {
-    tag: "SecondaryListing";
-    values: readonly [u32];
-} | {
-    tag: "UserTickets";
-    values: readonly [string];
+/**
+ * A tier and its primary sales so far. Tier ids are positions in the list
+ * passed to `init`, starting at 0.
+ */
+export interface Tier {
+    creator_fee_bps: u32;
+    max_per_wallet: u32;
+    minted: u32;
+    name: string;
+    price: i128;
+    supply: u32;
+    tier_id: u32;
+}
+export declare const Errors: {
+    1: {
+        message: string;
+    };
+    2: {
+        message: string;
+    };
+    3: {
+        message: string;
+    };
+    4: {
+        message: string;
+    };
+    5: {
+        message: string;
+    };
+    6: {
+        message: string;
+    };
+    7: {
+        message: string;
+    };
+    8: {
+        message: string;
+    };
+    9: {
+        message: string;
+    };
+    10: {
+        message: string;
+    };
+    11: {
+        message: string;
+    };
+    12: {
+        message: string;
+    };
+    13: {
+        message: string;
+    };
+    14: {
+        message: string;
+    };
+    15: {
+        message: string;
+    };
+    16: {
+        message: string;
+    };
+    17: {
+        message: string;
+    };
+    18: {
+        message: string;
+    };
+    19: {
+        message: string;
+    };
+    20: {
+        message: string;
+    };
+    21: {
+        message: string;
+    };
+    22: {
+        message: string;
+    };
+    23: {
+        message: string;
+    };
+    24: {
+        message: string;
+    };
+    25: {
+        message: string;
+    };
+    26: {
+        message: string;
+    };
+    27: {
+        message: string;
+    };
+    28: {
+        message: string;
+    };
+    29: {
+        message: string;
+    };
+    30: {
+        message: string;
+    };
+    31: {
+        message: string;
+    };
+    32: {
+        message: string;
+    };
+    33: {
+        message: string;
+    };
+    34: {
+        message: string;
+    };
+    35: {
+        message: string;
+    };
+    36: {
+        message: string;
+    };
+    37: {
+        message: string;
+    };
+    38: {
+        message: string;
+    };
+    39: {
+        message: string;
+    };
+    40: {
+        message: string;
+    };
+    41: {
+        message: string;
+    };
+    42: {
+        message: string;
+    };
+    43: {
+        message: string;
+    };
+    44: {
+        message: string;
+    };
+    45: {
+        message: string;
+    };
+    46: {
+        message: string;
+    };
+    47: {
+        message: string;
+    };
+    48: {
+        message: string;
+    };
+    49: {
+        message: string;
+    };
+    50: {
+        message: string;
+    };
 };
+/**
+ * An English auction of a ticket. The highest bid is held in escrow by the
+ * contract, and bids must reach `reserve` before `end_time`.
+ */
+export interface Auction {
+    end_time: u64;
+    highest_bid: i128;
+    highest_bidder: Option<string>;
+    reserve: i128;
+    seller: string;
+    ticket_id: u32;
+}
 export interface EventInfo {
     creator_fee_bps: u32;
     event_creator: string;
@@ -33,25 +194,173 @@ export interface EventInfo {
     name: string;
     payment_token: string;
     primary_price: i128;
+    royalty_remainder_recipient: string;
+    royalty_splits: Array<RoyaltySplit>;
+    schedule: EventSchedule;
     symbol: string;
     total_supply: u32;
 }
+/**
+ * How long storage is kept alive whenever it is touched. Entries are extended
+ * by at least `min_extension` ledgers, and far enough to outlive
+ * `retain_until` (a ledger timestamp). Until the creator sets a policy,
+ * `retain_until` is the event's `event_end`.
+ */
+export interface TtlPolicy {
+    min_extension: u32;
+    retain_until: u64;
+}
 export interface TicketData {
     is_used: boolean;
     owner: string;
     ticket_id: u32;
+    tier_id: u32;
+}
+/**
+ * A tier as configured at deployment. `max_per_wallet` caps how many
+ * tickets of the tier one address may buy on the primary market (0 for no cap).
+ */
+export interface TierConfig {
+    creator_fee_bps: u32;
+    max_per_wallet: u32;
+    name: string;
+    price: i128;
+    supply: u32;
+}
+/**
+ * The platform's cut of sales, set by the deploying factory. `primary_bps`
+ * is taken from primary sales and `secondary_bps` from resales, on top of
+ * the creator fee, and both are paid to `treasury`.
+ */
+export interface ProtocolFee {
+    primary_bps: u32;
+    secondary_bps: u32;
+    treasury: string;
+}
+/**
+ * A buyer's offer for a ticket, with the price held in escrow by the
+ * contract. It can be accepted by whoever owns the ticket until `expiry`
+ * (a ledger timestamp).
+ */
+export interface TicketOffer {
+    buyer: string;
+    expiry: u64;
+    price: i128;
+    ticket_id: u32;
 }
+/**
+ * A descending-price primary sale of one tier. From `sale_start` the price
+ * drops by `price_drop` every `drop_interval` seconds until it reaches
+ * `floor_price`. With `rebate`, buyers get back what they paid above the
+ * clearing price, the price of the last ticket sold.
+ */
+export interface DutchAuction {
+    drop_interval: u64;
+    floor_price: i128;
+    price_drop: i128;
+    rebate: boolean;
+    start_price: i128;
+    tier_id: u32;
+}
+/**
+ * Limits on secondary listing prices. `max_price` is an absolute cap and
+ * `max_markup_bps` a cap relative to the ticket's tier price (10000 is face
+ * value); when both are set the lower one applies. `min_price` is a floor.
+ * Zero disables a limit.
+ */
+export interface ResalePolicy {
+    max_markup_bps: u32;
+    max_price: i128;
+    min_price: i128;
+}
+/**
+ * A share of the creator fee on secondary sales, in basis points of the fee.
+ * Whatever the splits leave over, including rounding dust, goes to
+ * `EventInfo.royalty_remainder_recipient`.
+ */
+export interface RoyaltySplit {
+    bps: u32;
+    recipient: string;
+}
+/**
+ * A buyer's standing bid for up to `quantity` more tickets of the event, or
+ * of one tier, at `price` each. The price of every unfilled ticket is held
+ * in escrow by the contract.
+ */
+export interface CollectionBid {
+    bid_id: u32;
+    buyer: string;
+    price: i128;
+    quantity: u32;
+    tier_id: Option<u32>;
+}
+/**
+ * Ledger timestamps (seconds) bounding the primary sale and the event
+ * itself. Tickets can be minted from `sale_start` until just before
+ * `sale_end`, and the sale must close by the end of the event.
+ */
+export interface EventSchedule {
+    event_end: u64;
+    event_start: u64;
+    sale_end: u64;
+    sale_start: u64;
+}
+/**
+ * An allowlist sale of one tier that runs from `start` until the public
+ * sale opens. Eligible addresses prove membership with a Merkle proof
+ * against `merkle_root`, whose leaves are the SHA-256 of each address's XDR
+ * and whose pairs are hashed in sorted order.
+ */
+export interface PresaleConfig {
+    max_per_wallet: u32;
+    merkle_root: Buffer;
+    price: i128;
+    start: u64;
+    tier_id: u32;
+}
+/**
+ * A ticket for sale on the secondary market. Listings lapse at `expires_at`
+ * (a ledger timestamp), if set, and in any case once the event starts.
+ */
 export interface SecondaryListing {
+    expires_at: Option<u64>;
     price: i128;
     seller: string;
     ticket_id: u32;
 }
+/**
+ * Who may upgrade the contract code: the deploying factory (on behalf of its
+ * admin) or the event creator
+ */
+export type UpgradeAuthority = {
+    tag: "Factory";
+    values: void;
+} | {
+    tag: "Creator";
+    values: void;
+};
 export interface Client {
+    /**
+     * Construct and simulate a bid transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Bid on an auction, holding `amount` in escrow and crediting the
+     * previous highest bid to its bidder for `claim_balance`. Bids must
+     * meet the reserve and beat the highest bid by `MIN_BID_INCREMENT_BPS`,
+     * and a bid in the last `AUCTION_EXTENSION_SECONDS` pushes the end
+     * back, up to the event start.
+     */
+    bid: ({ bidder, ticket_id, amount }: {
+        bidder: string;
+        ticket_id: u32;
+        amount: i128;
+    }, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>;
     /**
      * Construct and simulate a init transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Initialize the ticket marketplace contract
+     * Initialize the ticket marketplace contract. With an empty `tiers` list
+     * the event has a single tier built from `total_supply`, `primary_price`
+     * and `creator_fee_bps`; otherwise the tier supplies must add up to
+     * `total_supply`.
      */
-    init: ({ event_creator, total_supply, primary_price, creator_fee_bps, event_metadata, name, symbol, payment_token }: {
+    init: ({ event_creator, total_supply, primary_price, creator_fee_bps, event_metadata, name, symbol, payment_token, tiers, schedule }: {
         event_creator: string;
         total_supply: u32;
         primary_price: i128;
@@ -60,40 +369,291 @@ export interface Client {
         name: string;
         symbol: string;
         payment_token: string;
-    }, options?: AssembledTransactionOptions<null>) => Promise<AssembledTransaction<null>>;
+        tiers: Array<TierConfig>;
+        schedule: EventSchedule;
+    }, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>;
     /**
      * Construct and simulate a name transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      * Get the name of the event/ticket collection
      */
-    name: (options?: AssembledTransactionOptions<string>) => Promise<AssembledTransaction<string>>;
+    name: (options?: AssembledTransactionOptions<Result<string>>) => Promise<AssembledTransaction<Result<string>>>;
     /**
      * Construct and simulate a symbol transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      * Get the symbol of the ticket
      */
-    symbol: (options?: AssembledTransactionOptions<string>) => Promise<AssembledTransaction<string>>;
+    symbol: (options?: AssembledTransactionOptions<Result<string>>) => Promise<AssembledTransaction<Result<string>>>;
+    /**
+     * Construct and simulate a approve transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Approve a spender for a single ticket, or clear the approval with `None`.
+     * The approver must be the owner or one of the owner's operators, and the
+     * approval is cleared whenever the ticket changes hands.
+     */
+    approve: ({ approver, approved, ticket_id }: {
+        approver: string;
+        approved: Option<string>;
+        ticket_id: u32;
+    }, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>;
+    /**
+     * Construct and simulate a balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the number of tickets held by an address
+     */
+    balance: ({ owner }: {
+        owner: string;
+    }, options?: AssembledTransactionOptions<u32>) => Promise<AssembledTransaction<u32>>;
+    /**
+     * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Replace the contract code. Only the upgrade authority may do this: the
+     * deploying factory by default, or the event creator for events without
+     * a factory or that have opted out.
+     */
+    upgrade: ({ new_wasm_hash }: {
+        new_wasm_hash: Buffer;
+    }, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>;
+    /**
+     * Construct and simulate a version transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the version of the contract interface
+     */
+    version: (options?: AssembledTransactionOptions<u32>) => Promise<AssembledTransaction<u32>>;
+    /**
+     * Construct and simulate a get_tier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get a single tier
+     */
+    get_tier: ({ tier_id }: {
+        tier_id: u32;
+    }, options?: AssembledTransactionOptions<Result<Tier>>) => Promise<AssembledTransaction<Result<Tier>>>;
+    /**
+     * Construct and simulate a owner_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the current owner of a ticket
+     */
+    owner_of: ({ ticket_id }: {
+        ticket_id: u32;
+    }, options?: AssembledTransactionOptions<Result<string>>) => Promise<AssembledTransaction<Result<string>>>;
+    /**
+     * Construct and simulate a get_offer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get a buyer's offer for a ticket, if any
+     */
+    get_offer: ({ ticket_id, buyer }: {
+        ticket_id: u32;
+        buyer: string;
+    }, options?: AssembledTransactionOptions<Option<TicketOffer>>) => Promise<AssembledTransaction<Option<TicketOffer>>>;
+    /**
+     * Construct and simulate a get_tiers transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get all tiers with their primary sales so far
+     */
+    get_tiers: (options?: AssembledTransactionOptions<Result<Array<Tier>>>) => Promise<AssembledTransaction<Result<Array<Tier>>>>;
+    /**
+     * Construct and simulate a token_uri transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the metadata URI of a ticket. A per-ticket override wins, then the
+     * base URI followed by the ticket id, then the event's metadata.
+     */
+    token_uri: ({ ticket_id }: {
+        ticket_id: u32;
+    }, options?: AssembledTransactionOptions<Result<string>>) => Promise<AssembledTransaction<Result<string>>>;
+    /**
+     * Construct and simulate a get_offers transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get all offers for a ticket, including expired ones not yet cancelled
+     */
+    get_offers: ({ ticket_id }: {
+        ticket_id: u32;
+    }, options?: AssembledTransactionOptions<Array<TicketOffer>>) => Promise<AssembledTransaction<Array<TicketOffer>>>;
     /**
      * Construct and simulate a get_ticket transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      * Get ticket information
      */
     get_ticket: ({ ticket_id }: {
         ticket_id: u32;
-    }, options?: AssembledTransactionOptions<TicketData>) => Promise<AssembledTransaction<TicketData>>;
+    }, options?: AssembledTransactionOptions<Result<TicketData>>) => Promise<AssembledTransaction<Result<TicketData>>>;
+    /**
+     * Construct and simulate a is_on_sale transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Check whether the primary sale is open at the current ledger time
+     */
+    is_on_sale: (options?: AssembledTransactionOptions<Result<boolean>>) => Promise<AssembledTransaction<Result<boolean>>>;
+    /**
+     * Construct and simulate a make_offer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Offer to buy a ticket, listed or not, holding `price` in escrow until
+     * the offer is accepted or cancelled. One offer per buyer and ticket,
+     * and `OfferExists` once the ticket has `MAX_OFFERS_PER_TICKET` offers.
+     */
+    make_offer: ({ buyer, ticket_id, price, expiry }: {
+        buyer: string;
+        ticket_id: u32;
+        price: i128;
+        expiry: u64;
+    }, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>;
+    /**
+     * Construct and simulate a set_escrow transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Hold primary sale payments in the contract instead of paying the
+     * creator straight away, so buyers can be refunded if the event is
+     * cancelled (event creator only). Must be chosen before any ticket is sold.
+     * The payment for a ticket is released when it is checked in, and
+     * everything still held is released once the event ends.
+     */
+    set_escrow: ({ creator, enabled }: {
+        creator: string;
+        enabled: boolean;
+    }, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>;
+    /**
+     * Construct and simulate a get_auction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the auction of a ticket
+     */
+    get_auction: ({ ticket_id }: {
+        ticket_id: u32;
+    }, options?: AssembledTransactionOptions<Result<Auction>>) => Promise<AssembledTransaction<Result<Auction>>>;
+    /**
+     * Construct and simulate a get_factory transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the factory that deployed this event, if any
+     */
+    get_factory: (options?: AssembledTransactionOptions<Option<string>>) => Promise<AssembledTransaction<Option<string>>>;
+    /**
+     * Construct and simulate a get_presale transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the presale configuration, if any
+     */
+    get_presale: (options?: AssembledTransactionOptions<Option<PresaleConfig>>) => Promise<AssembledTransaction<Option<PresaleConfig>>>;
+    /**
+     * Construct and simulate a is_escrowed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Check whether primary sale payments are held in escrow
+     */
+    is_escrowed: (options?: AssembledTransactionOptions<boolean>) => Promise<AssembledTransaction<boolean>>;
     /**
      * Construct and simulate a list_ticket transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * List ticket on secondary marketplace
+     * List ticket on secondary marketplace, optionally until `expires_at`.
+     * A lapsed listing of the ticket is replaced.
      */
-    list_ticket: ({ seller, ticket_id, price }: {
+    list_ticket: ({ seller, ticket_id, price, expires_at }: {
         seller: string;
         ticket_id: u32;
         price: i128;
-    }, options?: AssembledTransactionOptions<null>) => Promise<AssembledTransaction<null>>;
+        expires_at: Option<u64>;
+    }, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>;
     /**
      * Construct and simulate a mint_ticket transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Mint a ticket from primary marketplace (buy from event creator)
+     * Mint a ticket of the given tier from primary marketplace (buy from event creator)
      */
-    mint_ticket: ({ buyer }: {
+    mint_ticket: ({ buyer, tier_id }: {
         buyer: string;
-    }, options?: AssembledTransactionOptions<u32>) => Promise<AssembledTransaction<u32>>;
+        tier_id: u32;
+    }, options?: AssembledTransactionOptions<Result<u32>>) => Promise<AssembledTransaction<Result<u32>>>;
+    /**
+     * Construct and simulate a set_factory transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Record the factory that deployed this event and the protocol fee it
+     * charges. Both the factory and the event creator must authorize this,
+     * and it can only be done once, before any ticket is sold.
+     */
+    set_factory: ({ factory, protocol_fee }: {
+        factory: string;
+        protocol_fee: ProtocolFee;
+    }, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>;
+    /**
+     * Construct and simulate a set_presale transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Configure the allowlist presale, or replace its root, price or cap
+     * (event creator only). The presale has to start before the public sale.
+     */
+    set_presale: ({ creator, presale }: {
+        creator: string;
+        presale: PresaleConfig;
+    }, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>;
+    /**
+     * Construct and simulate a accept_offer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Accept an unexpired offer for a ticket (current owner only). The
+     * escrowed price is paid out like a secondary sale and any listing of
+     * the ticket is withdrawn.
+     */
+    accept_offer: ({ seller, ticket_id, buyer }: {
+        seller: string;
+        ticket_id: u32;
+        buyer: string;
+    }, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>;
+    /**
+     * Construct and simulate a cancel_event transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Cancel the event before it ends (event creator only). Minting, trading,
+     * transfers and check-in stop for good, and holders of escrowed tickets
+     * can claim their payment back with `claim_refund`.
+     */
+    cancel_event: ({ creator }: {
+        creator: string;
+    }, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>;
+    /**
+     * Construct and simulate a cancel_offer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Withdraw an offer and get the escrowed price back. This is also how
+     * buyers recover expired offers or offers on tickets that can no longer
+     * be sold.
+     */
+    cancel_offer: ({ buyer, ticket_id }: {
+        buyer: string;
+        ticket_id: u32;
+    }, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>;
+    /**
+     * Construct and simulate a claim_rebate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Pay a Dutch auction buyer back what they paid for a ticket above the
+     * clearing price (original buyer only), once. Rebates stay claimable
+     * after the ticket is resold or checked in and after the creator has
+     * withdrawn the proceeds.
+     */
+    claim_rebate: ({ ticket_id }: {
+        ticket_id: u32;
+    }, options?: AssembledTransactionOptions<Result<i128>>) => Promise<AssembledTransaction<Result<i128>>>;
+    /**
+     * Construct and simulate a claim_refund transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Refund the price paid for a ticket of a cancelled event to its owner.
+     * Only unused tickets bought with escrow enabled are refundable, once each.
+     */
+    claim_refund: ({ ticket_id }: {
+        ticket_id: u32;
+    }, options?: AssembledTransactionOptions<Result<i128>>) => Promise<AssembledTransaction<Result<i128>>>;
+    /**
+     * Construct and simulate a get_approved transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the address approved to transfer a single ticket
+     */
+    get_approved: ({ ticket_id }: {
+        ticket_id: u32;
+    }, options?: AssembledTransactionOptions<Result<Option<string>>>) => Promise<AssembledTransaction<Result<Option<string>>>>;
+    /**
+     * Construct and simulate a get_base_uri transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the base URI, if one has been set
+     */
+    get_base_uri: (options?: AssembledTransactionOptions<Option<string>>) => Promise<AssembledTransaction<Option<string>>>;
+    /**
+     * Construct and simulate a is_cancelled transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Check whether the event has been cancelled
+     */
+    is_cancelled: (options?: AssembledTransactionOptions<boolean>) => Promise<AssembledTransaction<boolean>>;
+    /**
+     * Construct and simulate a mint_presale transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Mint presale tickets at the presale price. The buyer proves they are on
+     * the allowlist with the sibling hashes from their leaf up to the root.
+     */
+    mint_presale: ({ buyer, quantity, proof }: {
+        buyer: string;
+        quantity: u32;
+        proof: Array<Buffer>;
+    }, options?: AssembledTransactionOptions<Result<Array<u32>>>) => Promise<AssembledTransaction<Result<Array<u32>>>>;
+    /**
+     * Construct and simulate a mint_tickets transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Mint several tickets of one tier in a single purchase. The buyer pays
+     * once for the whole batch and receives consecutive ticket ids; if the
+     * tier cannot cover the full quantity nothing is minted.
+     */
+    mint_tickets: ({ buyer, tier_id, quantity }: {
+        buyer: string;
+        tier_id: u32;
+        quantity: u32;
+    }, options?: AssembledTransactionOptions<Result<Array<u32>>>) => Promise<AssembledTransaction<Result<Array<u32>>>>;
+    /**
+     * Construct and simulate a set_base_uri transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Set the base URI that ticket ids are appended to (event creator only)
+     */
+    set_base_uri: ({ creator, base_uri }: {
+        creator: string;
+        base_uri: string;
+    }, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>;
+    /**
+     * Construct and simulate a claim_balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Pay out what an address is owed from auctions: bids that were outbid
+     * or refunded, and the seller's share of settled auctions
+     */
+    claim_balance: ({ owner }: {
+        owner: string;
+    }, options?: AssembledTransactionOptions<Result<i128>>) => Promise<AssembledTransaction<Result<i128>>>;
     /**
      * Construct and simulate a delist_ticket transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      * Delist ticket from secondary marketplace
@@ -101,12 +661,92 @@ export interface Client {
     delist_ticket: ({ seller, ticket_id }: {
         seller: string;
         ticket_id: u32;
-    }, options?: AssembledTransactionOptions<null>) => Promise<AssembledTransaction<null>>;
+    }, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>;
+    /**
+     * Construct and simulate a get_claimable transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get what an address can collect with `claim_balance`
+     */
+    get_claimable: ({ owner }: {
+        owner: string;
+    }, options?: AssembledTransactionOptions<i128>) => Promise<AssembledTransaction<i128>>;
+    /**
+     * Construct and simulate a get_purchased transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get how many tickets an address has bought on the primary market.
+     * Transfers and resales do not reduce this count.
+     */
+    get_purchased: ({ buyer }: {
+        buyer: string;
+    }, options?: AssembledTransactionOptions<u32>) => Promise<AssembledTransaction<u32>>;
+    /**
+     * Construct and simulate a start_auction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Put an unused, unlisted ticket up for auction until `end_time`, which
+     * must be no later than the start of the event (ticket owner only).
+     * Auctioned tickets count as listed until the auction is settled.
+     */
+    start_auction: ({ ticket_id, reserve, end_time }: {
+        ticket_id: u32;
+        reserve: i128;
+        end_time: u64;
+    }, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>;
+    /**
+     * Construct and simulate a transfer_from transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Transfer a ticket on behalf of its owner. The spender must be the owner,
+     * the ticket's approved address or an operator of the owner. Used and
+     * listed tickets cannot be transferred, as with `transfer_ticket`.
+     */
+    transfer_from: ({ spender, from, to, ticket_id }: {
+        spender: string;
+        from: string;
+        to: string;
+        ticket_id: u32;
+    }, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>;
     /**
      * Construct and simulate a get_event_info transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      * Get event information
      */
-    get_event_info: (options?: AssembledTransactionOptions<EventInfo>) => Promise<AssembledTransaction<EventInfo>>;
+    get_event_info: (options?: AssembledTransactionOptions<Result<EventInfo>>) => Promise<AssembledTransaction<Result<EventInfo>>>;
+    /**
+     * Construct and simulate a get_ttl_policy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the storage TTL policy
+     */
+    get_ttl_policy: (options?: AssembledTransactionOptions<TtlPolicy>) => Promise<AssembledTransaction<TtlPolicy>>;
+    /**
+     * Construct and simulate a set_ticket_uri transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Set or clear a ticket's own metadata URI (event creator only). Tickets
+     * that have not been minted yet can be given seat-specific metadata too.
+     */
+    set_ticket_uri: ({ creator, ticket_id, uri }: {
+        creator: string;
+        ticket_id: u32;
+        uri: Option<string>;
+    }, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>;
+    /**
+     * Construct and simulate a set_ttl_policy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Set how long event storage is kept alive (event creator only)
+     */
+    set_ttl_policy: ({ creator, policy }: {
+        creator: string;
+        policy: TtlPolicy;
+    }, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>;
+    /**
+     * Construct and simulate a settle_auction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Close an auction once it has ended (callable by anyone). The ticket
+     * goes to the highest bidder, with the winning bid paid out like a
+     * secondary sale except that the seller's share is credited to
+     * `claim_balance`. If there were no bids the ticket stays with the
+     * seller, and if the event was cancelled or the ticket checked in
+     * meanwhile the highest bid is credited back to its bidder instead.
+     */
+    settle_auction: ({ ticket_id }: {
+        ticket_id: u32;
+    }, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>;
+    /**
+     * Construct and simulate a freeze_metadata transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Permanently prevent further changes to ticket URIs (event creator only)
+     */
+    freeze_metadata: ({ creator }: {
+        creator: string;
+    }, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>;
     /**
      * Construct and simulate a transfer_ticket transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      * Transfer ticket directly (P2P)
@@ -115,7 +755,27 @@ export interface Client {
         from: string;
         to: string;
         ticket_id: u32;
-    }, options?: AssembledTransactionOptions<null>) => Promise<AssembledTransaction<null>>;
+    }, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>;
+    /**
+     * Construct and simulate a extend_event_ttl transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Extend the TTL of the contract instance, code and event information.
+     * Anyone may call this and pay for the extension.
+     */
+    extend_event_ttl: (options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>;
+    /**
+     * Construct and simulate a get_all_auctions transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the auctions that have not been settled among `limit` ticket ids
+     * from `start`
+     */
+    get_all_auctions: ({ start, limit }: {
+        start: u32;
+        limit: u32;
+    }, options?: AssembledTransactionOptions<Array<Auction>>) => Promise<AssembledTransaction<Array<Auction>>>;
+    /**
+     * Construct and simulate a get_protocol_fee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the protocol fee charged by the deploying factory, if any
+     */
+    get_protocol_fee: (options?: AssembledTransactionOptions<Option<ProtocolFee>>) => Promise<AssembledTransaction<Option<ProtocolFee>>>;
     /**
      * Construct and simulate a get_user_tickets transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      * Get tickets owned by user
@@ -125,17 +785,154 @@ export interface Client {
     }, options?: AssembledTransactionOptions<Array<u32>>) => Promise<AssembledTransaction<Array<u32>>>;
     /**
      * Construct and simulate a mark_ticket_used transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Mark ticket as used (check-in at event)
+     * Mark ticket as used (check-in at event). Check-in opens at the start
+     * of the event, so escrowed payments cannot be released before then.
      */
     mark_ticket_used: ({ creator, ticket_id }: {
         creator: string;
         ticket_id: u32;
-    }, options?: AssembledTransactionOptions<null>) => Promise<AssembledTransaction<null>>;
+    }, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>;
+    /**
+     * Construct and simulate a extend_ticket_ttl transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Extend the TTL of a ticket and the entries tied to it: its listing,
+     * auction, offers, approval, metadata URI, escrowed payment, rebate and
+     * the owner's ticket index.
+     * Anyone may call this and pay for the extension.
+     */
+    extend_ticket_ttl: ({ ticket_id }: {
+        ticket_id: u32;
+    }, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>;
+    /**
+     * Construct and simulate a get_current_price transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the price a ticket of a tier costs on the public sale right now
+     */
+    get_current_price: ({ tier_id }: {
+        tier_id: u32;
+    }, options?: AssembledTransactionOptions<Result<i128>>) => Promise<AssembledTransaction<Result<i128>>>;
+    /**
+     * Construct and simulate a get_dutch_auction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the Dutch auction configuration, if any
+     */
+    get_dutch_auction: (options?: AssembledTransactionOptions<Option<DutchAuction>>) => Promise<AssembledTransaction<Option<DutchAuction>>>;
+    /**
+     * Construct and simulate a get_resale_policy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the limits on secondary listing prices
+     */
+    get_resale_policy: (options?: AssembledTransactionOptions<ResalePolicy>) => Promise<AssembledTransaction<ResalePolicy>>;
+    /**
+     * Construct and simulate a set_dutch_auction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Sell a tier at a descending price instead of its fixed price (event
+     * creator only). Must be set before any ticket is sold. Rebates need the
+     * payments at hand, so they also hold all primary payments in escrow.
+     */
+    set_dutch_auction: ({ creator, auction }: {
+        creator: string;
+        auction: DutchAuction;
+    }, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>;
+    /**
+     * Construct and simulate a set_resale_policy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Set the limits on secondary listing prices (event creator only).
+     * Listings made before a change keep their price until it is updated.
+     */
+    set_resale_policy: ({ creator, policy }: {
+        creator: string;
+        policy: ResalePolicy;
+    }, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>;
+    /**
+     * Construct and simulate a withdraw_proceeds transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Withdraw the escrowed proceeds released so far (event creator only),
+     * less the protocol's share of primary sales. Returns the amount paid to
+     * the creator.
+     */
+    withdraw_proceeds: ({ creator }: {
+        creator: string;
+    }, options?: AssembledTransactionOptions<Result<i128>>) => Promise<AssembledTransaction<Result<i128>>>;
+    /**
+     * Construct and simulate a get_clearing_price transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the Dutch auction's clearing price, the price of the last ticket
+     * sold. It is known once the tier sells out or the sale ends.
+     */
+    get_clearing_price: (options?: AssembledTransactionOptions<Result<Option<i128>>>) => Promise<AssembledTransaction<Result<Option<i128>>>>;
+    /**
+     * Construct and simulate a get_collection_bid transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get a collection bid by id
+     */
+    get_collection_bid: ({ bid_id }: {
+        bid_id: u32;
+    }, options?: AssembledTransactionOptions<Result<CollectionBid>>) => Promise<AssembledTransaction<Result<CollectionBid>>>;
+    /**
+     * Construct and simulate a get_escrow_balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the primary sale payments held in escrow, released or not
+     */
+    get_escrow_balance: (options?: AssembledTransactionOptions<i128>) => Promise<AssembledTransaction<i128>>;
+    /**
+     * Construct and simulate a get_max_per_wallet transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the event-wide cap on primary purchases per address (0 for no cap)
+     */
+    get_max_per_wallet: (options?: AssembledTransactionOptions<u32>) => Promise<AssembledTransaction<u32>>;
     /**
      * Construct and simulate a get_tickets_minted transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      * Get number of tickets minted
      */
     get_tickets_minted: (options?: AssembledTransactionOptions<u32>) => Promise<AssembledTransaction<u32>>;
+    /**
+     * Construct and simulate a get_tier_available transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get number of tickets of a tier still available in primary market
+     */
+    get_tier_available: ({ tier_id }: {
+        tier_id: u32;
+    }, options?: AssembledTransactionOptions<Result<u32>>) => Promise<AssembledTransaction<Result<u32>>>;
+    /**
+     * Construct and simulate a is_metadata_frozen transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Check whether ticket metadata has been frozen
+     */
+    is_metadata_frozen: (options?: AssembledTransactionOptions<boolean>) => Promise<AssembledTransaction<boolean>>;
+    /**
+     * Construct and simulate a set_max_per_wallet transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Cap how many tickets one address may buy on the primary market across
+     * all tiers, or lift the cap with 0 (event creator only)
+     */
+    set_max_per_wallet: ({ creator, max_per_wallet }: {
+        creator: string;
+        max_per_wallet: u32;
+    }, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>;
+    /**
+     * Construct and simulate a set_royalty_splits transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Split the creator fee on secondary sales between several recipients
+     * (event creator only). Shares are in basis points of the fee and may
+     * total at most 10000; the remainder goes to `remainder_recipient`.
+     */
+    set_royalty_splits: ({ creator, splits, remainder_recipient }: {
+        creator: string;
+        splits: Array<RoyaltySplit>;
+        remainder_recipient: string;
+    }, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>;
+    /**
+     * Construct and simulate a fill_collection_bid transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Sell an unused, unlisted ticket into a collection bid (ticket owner
+     * only). The escrowed price is paid out like a secondary sale.
+     */
+    fill_collection_bid: ({ seller, bid_id, ticket_id }: {
+        seller: string;
+        bid_id: u32;
+        ticket_id: u32;
+    }, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>;
+    /**
+     * Construct and simulate a get_collection_bids transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the open collection bids among `limit` bid ids from `start`
+     */
+    get_collection_bids: ({ start, limit }: {
+        start: u32;
+        limit: u32;
+    }, options?: AssembledTransactionOptions<Array<CollectionBid>>) => Promise<AssembledTransaction<Array<CollectionBid>>>;
+    /**
+     * Construct and simulate a is_approved_for_all transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Check whether an operator may transfer all of the owner's tickets
+     */
+    is_approved_for_all: ({ owner, operator }: {
+        owner: string;
+        operator: string;
+    }, options?: AssembledTransactionOptions<boolean>) => Promise<AssembledTransaction<boolean>>;
     /**
      * Construct and simulate a buy_secondary_ticket transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      * Buy ticket from secondary marketplace
@@ -143,6 +940,49 @@ export interface Client {
     buy_secondary_ticket: ({ buyer, ticket_id }: {
         buyer: string;
         ticket_id: u32;
+    }, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>;
+    /**
+     * Construct and simulate a get_escrowed_payment transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the payment held in escrow for a ticket, if any
+     */
+    get_escrowed_payment: ({ ticket_id }: {
+        ticket_id: u32;
+    }, options?: AssembledTransactionOptions<Option<i128>>) => Promise<AssembledTransaction<Option<i128>>>;
+    /**
+     * Construct and simulate a get_max_resale_price transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the highest price a ticket may be listed for, if it is capped
+     */
+    get_max_resale_price: ({ ticket_id }: {
+        ticket_id: u32;
+    }, options?: AssembledTransactionOptions<Result<Option<i128>>>) => Promise<AssembledTransaction<Result<Option<i128>>>>;
+    /**
+     * Construct and simulate a get_wallet_allowance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get how many tickets of a tier the buyer may still buy on the primary
+     * market, given the wallet caps and the tier's remaining supply
+     */
+    get_wallet_allowance: ({ buyer, tier_id }: {
+        buyer: string;
+        tier_id: u32;
+    }, options?: AssembledTransactionOptions<Result<u32>>) => Promise<AssembledTransaction<Result<u32>>>;
+    /**
+     * Construct and simulate a place_collection_bid transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Bid for `quantity` tickets of any tier, or of `tier_id`, at `price`
+     * each, holding the full amount in escrow. Returns the bid id.
+     */
+    place_collection_bid: ({ buyer, tier_id, price, quantity }: {
+        buyer: string;
+        tier_id: Option<u32>;
+        price: i128;
+        quantity: u32;
+    }, options?: AssembledTransactionOptions<Result<u32>>) => Promise<AssembledTransaction<Result<u32>>>;
+    /**
+     * Construct and simulate a set_approval_for_all transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Grant or revoke an operator that may transfer all of the owner's tickets
+     */
+    set_approval_for_all: ({ owner, operator, approved }: {
+        owner: string;
+        operator: string;
+        approved: boolean;
     }, options?: AssembledTransactionOptions<null>) => Promise<AssembledTransaction<null>>;
     /**
      * Construct and simulate a update_listing_price transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -152,7 +992,22 @@ export interface Client {
         seller: string;
         ticket_id: u32;
         new_price: i128;
-    }, options?: AssembledTransactionOptions<null>) => Promise<AssembledTransaction<null>>;
+    }, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>;
+    /**
+     * Construct and simulate a cancel_collection_bid transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Withdraw a collection bid and get the escrow for its unfilled tickets
+     * back (bidder only)
+     */
+    cancel_collection_bid: ({ bid_id }: {
+        bid_id: u32;
+    }, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>;
+    /**
+     * Construct and simulate a get_presale_purchased transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get how many presale tickets an address has bought
+     */
+    get_presale_purchased: ({ buyer }: {
+        buyer: string;
+    }, options?: AssembledTransactionOptions<u32>) => Promise<AssembledTransaction<u32>>;
     /**
      * Construct and simulate a get_secondary_listing transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      * Get secondary listing
@@ -164,12 +1019,36 @@ export interface Client {
      * Construct and simulate a get_tickets_available transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      * Get number of tickets available in primary market
      */
-    get_tickets_available: (options?: AssembledTransactionOptions<u32>) => Promise<AssembledTransaction<u32>>;
+    get_tickets_available: (options?: AssembledTransactionOptions<Result<u32>>) => Promise<AssembledTransaction<Result<u32>>>;
+    /**
+     * Construct and simulate a get_upgrade_authority transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get who may upgrade the contract code
+     */
+    get_upgrade_authority: (options?: AssembledTransactionOptions<UpgradeAuthority>) => Promise<AssembledTransaction<UpgradeAuthority>>;
+    /**
+     * Construct and simulate a set_upgrade_authority transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Hand the right to upgrade to the factory or the creator. The current
+     * upgrade authority must authorize the change; the factory does so
+     * through its admin-only `set_event_upgrade_authority`.
+     */
+    set_upgrade_authority: ({ authority }: {
+        authority: UpgradeAuthority;
+    }, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>;
+    /**
+     * Construct and simulate a get_withdrawable_proceeds transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the escrowed proceeds the creator could withdraw now, before the
+     * protocol fee
+     */
+    get_withdrawable_proceeds: (options?: AssembledTransactionOptions<Result<i128>>) => Promise<AssembledTransaction<Result<i128>>>;
     /**
      * Construct and simulate a get_all_secondary_listings transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Get all secondary listings
+     * Get the secondary listings that can still be bought among `limit`
+     * ticket ids from `start`. A cancelled event has none.
      */
-    get_all_secondary_listings: (options?: AssembledTransactionOptions<Array<SecondaryListing>>) => Promise<AssembledTransaction<Array<SecondaryListing>>>;
+    get_all_secondary_listings: ({ start, limit }: {
+        start: u32;
+        limit: u32;
+    }, options?: AssembledTransactionOptions<Array<SecondaryListing>>) => Promise<AssembledTransaction<Array<SecondaryListing>>>;
 }
 export declare class Client extends ContractClient {
     readonly options: ContractClientOptions;
@@ -185,22 +1064,94 @@ export declare class Client extends ContractClient {
     }): Promise<AssembledTransaction<T>>;
     constructor(options: ContractClientOptions);
     readonly fromJSON: {
-        init: (json: string) => AssembledTransaction<null>;
-        name: (json: string) => AssembledTransaction<string>;
-        symbol: (json: string) => AssembledTransaction<string>;
-        get_ticket: (json: string) => AssembledTransaction<TicketData>;
-        list_ticket: (json: string) => AssembledTransaction<null>;
-        mint_ticket: (json: string) => AssembledTransaction<number>;
-        delist_ticket: (json: string) => AssembledTransaction<null>;
-        get_event_info: (json: string) => AssembledTransaction<EventInfo>;
-        transfer_ticket: (json: string) => AssembledTransaction<null>;
+        bid: (json: string) => AssembledTransaction<Result<readonly [], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        init: (json: string) => AssembledTransaction<Result<readonly [], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        name: (json: string) => AssembledTransaction<Result<string, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        symbol: (json: string) => AssembledTransaction<Result<string, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        approve: (json: string) => AssembledTransaction<Result<readonly [], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        balance: (json: string) => AssembledTransaction<number>;
+        upgrade: (json: string) => AssembledTransaction<Result<readonly [], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        version: (json: string) => AssembledTransaction<number>;
+        get_tier: (json: string) => AssembledTransaction<Result<Tier, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        owner_of: (json: string) => AssembledTransaction<Result<string, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_offer: (json: string) => AssembledTransaction<Option<TicketOffer>>;
+        get_tiers: (json: string) => AssembledTransaction<Result<Tier[], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        token_uri: (json: string) => AssembledTransaction<Result<string, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_offers: (json: string) => AssembledTransaction<TicketOffer[]>;
+        get_ticket: (json: string) => AssembledTransaction<Result<TicketData, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        is_on_sale: (json: string) => AssembledTransaction<Result<boolean, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        make_offer: (json: string) => AssembledTransaction<Result<readonly [], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        set_escrow: (json: string) => AssembledTransaction<Result<readonly [], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_auction: (json: string) => AssembledTransaction<Result<Auction, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_factory: (json: string) => AssembledTransaction<Option<string>>;
+        get_presale: (json: string) => AssembledTransaction<Option<PresaleConfig>>;
+        is_escrowed: (json: string) => AssembledTransaction<boolean>;
+        list_ticket: (json: string) => AssembledTransaction<Result<readonly [], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        mint_ticket: (json: string) => AssembledTransaction<Result<number, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        set_factory: (json: string) => AssembledTransaction<Result<readonly [], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        set_presale: (json: string) => AssembledTransaction<Result<readonly [], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        accept_offer: (json: string) => AssembledTransaction<Result<readonly [], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        cancel_event: (json: string) => AssembledTransaction<Result<readonly [], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        cancel_offer: (json: string) => AssembledTransaction<Result<readonly [], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        claim_rebate: (json: string) => AssembledTransaction<Result<bigint, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        claim_refund: (json: string) => AssembledTransaction<Result<bigint, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_approved: (json: string) => AssembledTransaction<Result<Option<string>, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_base_uri: (json: string) => AssembledTransaction<Option<string>>;
+        is_cancelled: (json: string) => AssembledTransaction<boolean>;
+        mint_presale: (json: string) => AssembledTransaction<Result<number[], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        mint_tickets: (json: string) => AssembledTransaction<Result<number[], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        set_base_uri: (json: string) => AssembledTransaction<Result<readonly [], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        claim_balance: (json: string) => AssembledTransaction<Result<bigint, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        delist_ticket: (json: string) => AssembledTransaction<Result<readonly [], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_claimable: (json: string) => AssembledTransaction<bigint>;
+        get_purchased: (json: string) => AssembledTransaction<number>;
+        start_auction: (json: string) => AssembledTransaction<Result<readonly [], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        transfer_from: (json: string) => AssembledTransaction<Result<readonly [], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_event_info: (json: string) => AssembledTransaction<Result<EventInfo, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_ttl_policy: (json: string) => AssembledTransaction<TtlPolicy>;
+        set_ticket_uri: (json: string) => AssembledTransaction<Result<readonly [], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        set_ttl_policy: (json: string) => AssembledTransaction<Result<readonly [], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        settle_auction: (json: string) => AssembledTransaction<Result<readonly [], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        freeze_metadata: (json: string) => AssembledTransaction<Result<readonly [], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        transfer_ticket: (json: string) => AssembledTransaction<Result<readonly [], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        extend_event_ttl: (json: string) => AssembledTransaction<Result<readonly [], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_all_auctions: (json: string) => AssembledTransaction<Auction[]>;
+        get_protocol_fee: (json: string) => AssembledTransaction<Option<ProtocolFee>>;
         get_user_tickets: (json: string) => AssembledTransaction<number[]>;
-        mark_ticket_used: (json: string) => AssembledTransaction<null>;
+        mark_ticket_used: (json: string) => AssembledTransaction<Result<readonly [], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        extend_ticket_ttl: (json: string) => AssembledTransaction<Result<readonly [], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_current_price: (json: string) => AssembledTransaction<Result<bigint, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_dutch_auction: (json: string) => AssembledTransaction<Option<DutchAuction>>;
+        get_resale_policy: (json: string) => AssembledTransaction<ResalePolicy>;
+        set_dutch_auction: (json: string) => AssembledTransaction<Result<readonly [], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        set_resale_policy: (json: string) => AssembledTransaction<Result<readonly [], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        withdraw_proceeds: (json: string) => AssembledTransaction<Result<bigint, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_clearing_price: (json: string) => AssembledTransaction<Result<Option<bigint>, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_collection_bid: (json: string) => AssembledTransaction<Result<CollectionBid, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_escrow_balance: (json: string) => AssembledTransaction<bigint>;
+        get_max_per_wallet: (json: string) => AssembledTransaction<number>;
         get_tickets_minted: (json: string) => AssembledTransaction<number>;
-        buy_secondary_ticket: (json: string) => AssembledTransaction<null>;
-        update_listing_price: (json: string) => AssembledTransaction<null>;
+        get_tier_available: (json: string) => AssembledTransaction<Result<number, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        is_metadata_frozen: (json: string) => AssembledTransaction<boolean>;
+        set_max_per_wallet: (json: string) => AssembledTransaction<Result<readonly [], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        set_royalty_splits: (json: string) => AssembledTransaction<Result<readonly [], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        fill_collection_bid: (json: string) => AssembledTransaction<Result<readonly [], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_collection_bids: (json: string) => AssembledTransaction<CollectionBid[]>;
+        is_approved_for_all: (json: string) => AssembledTransaction<boolean>;
+        buy_secondary_ticket: (json: string) => AssembledTransaction<Result<readonly [], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_escrowed_payment: (json: string) => AssembledTransaction<Option<bigint>>;
+        get_max_resale_price: (json: string) => AssembledTransaction<Result<Option<bigint>, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_wallet_allowance: (json: string) => AssembledTransaction<Result<number, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        place_collection_bid: (json: string) => AssembledTransaction<Result<number, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        set_approval_for_all: (json: string) => AssembledTransaction<null>;
+        update_listing_price: (json: string) => AssembledTransaction<Result<readonly [], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        cancel_collection_bid: (json: string) => AssembledTransaction<Result<readonly [], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_presale_purchased: (json: string) => AssembledTransaction<number>;
         get_secondary_listing: (json: string) => AssembledTransaction<Option<SecondaryListing>>;
-        get_tickets_available: (json: string) => AssembledTransaction<number>;
+        get_tickets_available: (json: string) => AssembledTransaction<Result<number, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_upgrade_authority: (json: string) => AssembledTransaction<UpgradeAuthority>;
+        set_upgrade_authority: (json: string) => AssembledTransaction<Result<readonly [], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_withdrawable_proceeds: (json: string) => AssembledTransaction<Result<bigint, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
         get_all_secondary_listings: (json: string) => AssembledTransaction<SecondaryListing[]>;
     };
 }
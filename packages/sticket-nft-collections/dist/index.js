@@ -13,6 +13,58 @@ export const networks = {
         contractId: "CBBF6CHQTD2KPFI5VQ5BT7LHPPWXGHEAGMTJ3OUZ74TVD6DCJMWN4F4V",
     }
 };
+export const Errors = {
+    1: { message: "AlreadyInitialized" },
+    2: { message: "NotInitialized" },
+    3: { message: "InvalidSupply" },
+    4: { message: "InvalidPrice" },
+    5: { message: "InvalidFeeBps" },
+    6: { message: "SoldOut" },
+    7: { message: "TicketNotFound" },
+    8: { message: "NotOwner" },
+    9: { message: "TicketUsed" },
+    10: { message: "AlreadyListed" },
+    11: { message: "NotListed" },
+    12: { message: "NotSeller" },
+    13: { message: "NotCreator" },
+    14: { message: "InvalidTtlPolicy" },
+    15: { message: "NotApproved" },
+    16: { message: "MetadataFrozen" },
+    17: { message: "InvalidUri" },
+    18: { message: "TierNotFound" },
+    19: { message: "TooManyTiers" },
+    20: { message: "InvalidQuantity" },
+    21: { message: "WalletLimitReached" },
+    22: { message: "InvalidSchedule" },
+    23: { message: "SaleNotStarted" },
+    24: { message: "SaleEnded" },
+    25: { message: "InvalidPresale" },
+    26: { message: "PresaleNotActive" },
+    27: { message: "NotOnAllowlist" },
+    28: { message: "PriceAboveCap" },
+    29: { message: "PriceBelowFloor" },
+    30: { message: "InvalidResalePolicy" },
+    31: { message: "InvalidRoyaltySplits" },
+    32: { message: "FactoryAlreadySet" },
+    33: { message: "NoFactory" },
+    34: { message: "TicketsAlreadySold" },
+    35: { message: "EventCancelled" },
+    36: { message: "EventNotCancelled" },
+    37: { message: "NotRefundable" },
+    38: { message: "EventEnded" },
+    39: { message: "ProceedsLocked" },
+    40: { message: "NoProceeds" },
+    41: { message: "InvalidExpiry" },
+    42: { message: "OfferExists" },
+    43: { message: "OfferNotFound" },
+    44: { message: "OfferExpired" },
+    45: { message: "BidNotFound" },
+    46: { message: "TierMismatch" },
+    47: { message: "ListingExpired" },
+    48: { message: "AuctionNotFound" },
+    49: { message: "AuctionNotEnded" },
+    50: { message: "BidTooLow" }
+};
 export class Client extends ContractClient {
     options;
     static async deploy(
@@ -21,46 +73,227 @@ export class Client extends ContractClient {
         return ContractClient.deploy(null, options);
     }
     constructor(options) {
-        super(new ContractSpec(["AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAABQAAAAAAAAAAAAAACUV2ZW50SW5mbwAAAAAAAAEAAAAAAAAABlRpY2tldAAAAAAAAQAAAAQAAAAAAAAAAAAAAA1UaWNrZXRzTWludGVkAAAAAAAAAQAAAAAAAAAQU2Vjb25kYXJ5TGlzdGluZwAAAAEAAAAEAAAAAQAAAAAAAAALVXNlclRpY2tldHMAAAAAAQAAABM=",
-            "AAAAAQAAAAAAAAAAAAAACUV2ZW50SW5mbwAAAAAAAAgAAAAAAAAAD2NyZWF0b3JfZmVlX2JwcwAAAAAEAAAAAAAAAA1ldmVudF9jcmVhdG9yAAAAAAAAEwAAAAAAAAAOZXZlbnRfbWV0YWRhdGEAAAAAABAAAAAAAAAABG5hbWUAAAAQAAAAAAAAAA1wYXltZW50X3Rva2VuAAAAAAAAEwAAAAAAAAANcHJpbWFyeV9wcmljZQAAAAAAAAsAAAAAAAAABnN5bWJvbAAAAAAAEAAAAAAAAAAMdG90YWxfc3VwcGx5AAAABA==",
-            "AAAAAQAAAAAAAAAAAAAAClRpY2tldERhdGEAAAAAAAMAAAAAAAAAB2lzX3VzZWQAAAAAAQAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAl0aWNrZXRfaWQAAAAAAAAE",
-            "AAAAAAAAACpJbml0aWFsaXplIHRoZSB0aWNrZXQgbWFya2V0cGxhY2UgY29udHJhY3QAAAAAAARpbml0AAAACAAAAAAAAAANZXZlbnRfY3JlYXRvcgAAAAAAABMAAAAAAAAADHRvdGFsX3N1cHBseQAAAAQAAAAAAAAADXByaW1hcnlfcHJpY2UAAAAAAAALAAAAAAAAAA9jcmVhdG9yX2ZlZV9icHMAAAAABAAAAAAAAAAOZXZlbnRfbWV0YWRhdGEAAAAAABAAAAAAAAAABG5hbWUAAAAQAAAAAAAAAAZzeW1ib2wAAAAAABAAAAAAAAAADXBheW1lbnRfdG9rZW4AAAAAAAATAAAAAA==",
-            "AAAAAAAAACtHZXQgdGhlIG5hbWUgb2YgdGhlIGV2ZW50L3RpY2tldCBjb2xsZWN0aW9uAAAAAARuYW1lAAAAAAAAAAEAAAAQ",
-            "AAAAAQAAAAAAAAAAAAAAEFNlY29uZGFyeUxpc3RpbmcAAAADAAAAAAAAAAVwcmljZQAAAAAAAAsAAAAAAAAABnNlbGxlcgAAAAAAEwAAAAAAAAAJdGlja2V0X2lkAAAAAAAABA==",
-            "AAAAAAAAABxHZXQgdGhlIHN5bWJvbCBvZiB0aGUgdGlja2V0AAAABnN5bWJvbAAAAAAAAAAAAAEAAAAQ",
-            "AAAAAAAAABZHZXQgdGlja2V0IGluZm9ybWF0aW9uAAAAAAAKZ2V0X3RpY2tldAAAAAAAAQAAAAAAAAAJdGlja2V0X2lkAAAAAAAABAAAAAEAAAfQAAAAClRpY2tldERhdGEAAA==",
-            "AAAAAAAAACRMaXN0IHRpY2tldCBvbiBzZWNvbmRhcnkgbWFya2V0cGxhY2UAAAALbGlzdF90aWNrZXQAAAAAAwAAAAAAAAAGc2VsbGVyAAAAAAATAAAAAAAAAAl0aWNrZXRfaWQAAAAAAAAEAAAAAAAAAAVwcmljZQAAAAAAAAsAAAAA",
-            "AAAAAAAAAD9NaW50IGEgdGlja2V0IGZyb20gcHJpbWFyeSBtYXJrZXRwbGFjZSAoYnV5IGZyb20gZXZlbnQgY3JlYXRvcikAAAAAC21pbnRfdGlja2V0AAAAAAEAAAAAAAAABWJ1eWVyAAAAAAAAEwAAAAEAAAAE",
-            "AAAAAAAAAChEZWxpc3QgdGlja2V0IGZyb20gc2Vjb25kYXJ5IG1hcmtldHBsYWNlAAAADWRlbGlzdF90aWNrZXQAAAAAAAACAAAAAAAAAAZzZWxsZXIAAAAAABMAAAAAAAAACXRpY2tldF9pZAAAAAAAAAQAAAAA",
-            "AAAAAAAAABVHZXQgZXZlbnQgaW5mb3JtYXRpb24AAAAAAAAOZ2V0X2V2ZW50X2luZm8AAAAAAAAAAAABAAAH0AAAAAlFdmVudEluZm8AAAA=",
-            "AAAAAAAAAB5UcmFuc2ZlciB0aWNrZXQgZGlyZWN0bHkgKFAyUCkAAAAAAA90cmFuc2Zlcl90aWNrZXQAAAAAAwAAAAAAAAAEZnJvbQAAABMAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAl0aWNrZXRfaWQAAAAAAAAEAAAAAA==",
+        super(new ContractSpec(["AAAAAQAAAGhBIHRpZXIgYW5kIGl0cyBwcmltYXJ5IHNhbGVzIHNvIGZhci4gVGllciBpZHMgYXJlIHBvc2l0aW9ucyBpbiB0aGUgbGlzdApwYXNzZWQgdG8gYGluaXRgLCBzdGFydGluZyBhdCAwLgAAAAAAAAAEVGllcgAAAAcAAAAAAAAAD2NyZWF0b3JfZmVlX2JwcwAAAAAEAAAAAAAAAA5tYXhfcGVyX3dhbGxldAAAAAAABAAAAAAAAAAGbWludGVkAAAAAAAEAAAAAAAAAARuYW1lAAAAEAAAAAAAAAAFcHJpY2UAAAAAAAALAAAAAAAAAAZzdXBwbHkAAAAAAAQAAAAAAAAAB3RpZXJfaWQAAAAABA==",
+            "AAAABQAAADdFbWl0dGVkIHdoZW4gYSB0aWNrZXQgaXMgbGlzdGVkIG9uIHRoZSBzZWNvbmRhcnkgbWFya2V0AAAAAAAAAAAETGlzdAAAAAEAAAAEbGlzdAAAAAQAAAAAAAAACXRpY2tldF9pZAAAAAAAAAQAAAABAAAAAAAAAAZzZWxsZXIAAAAAABMAAAABAAAAAAAAAAVwcmljZQAAAAAAAAsAAAAAAAAAAAAAAApleHBpcmVzX2F0AAAAAAPoAAAABgAAAAAAAAAC",
+            "AAAABQAAADVFbWl0dGVkIHdoZW4gYSB0aWNrZXQgaXMgYm91Z2h0IG9uIHRoZSBwcmltYXJ5IG1hcmtldAAAAAAAAAAAAAAETWludAAAAAEAAAAEbWludAAAAAQAAAAAAAAACXRpY2tldF9pZAAAAAAAAAQAAAABAAAAAAAAAAVidXllcgAAAAAAABMAAAABAAAAAAAAAAd0aWVyX2lkAAAAAAQAAAAAAAAAAAAAAAVwcmljZQAAAAAAAAsAAAAAAAAAAg==",
+            "AAAABQAAAD5FbWl0dGVkIHdoZW4gYSBsaXN0ZWQgdGlja2V0IGlzIGJvdWdodCBvbiB0aGUgc2Vjb25kYXJ5IG1hcmtldAAAAAAAAAAAAARTYWxlAAAAAQAAAARzYWxlAAAABgAAAAAAAAAJdGlja2V0X2lkAAAAAAAABAAAAAEAAAAAAAAABnNlbGxlcgAAAAAAEwAAAAEAAAAAAAAABWJ1eWVyAAAAAAAAEwAAAAEAAAAAAAAABXByaWNlAAAAAAAACwAAAAAAAAAAAAAAC2NyZWF0b3JfZmVlAAAAAAsAAAAAAAAAAAAAAAxwcm90b2NvbF9mZWUAAAALAAAAAAAAAAI=",
+            "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAMgAAAAAAAAASQWxyZWFkeUluaXRpYWxpemVkAAAAAAABAAAAAAAAAA5Ob3RJbml0aWFsaXplZAAAAAAAAgAAAAAAAAANSW52YWxpZFN1cHBseQAAAAAAAAMAAAAAAAAADEludmFsaWRQcmljZQAAAAQAAAAAAAAADUludmFsaWRGZWVCcHMAAAAAAAAFAAAAAAAAAAdTb2xkT3V0AAAAAAYAAAAAAAAADlRpY2tldE5vdEZvdW5kAAAAAAAHAAAAAAAAAAhOb3RPd25lcgAAAAgAAAAAAAAAClRpY2tldFVzZWQAAAAAAAkAAAAAAAAADUFscmVhZHlMaXN0ZWQAAAAAAAAKAAAAAAAAAAlOb3RMaXN0ZWQAAAAAAAALAAAAAAAAAAlOb3RTZWxsZXIAAAAAAAAMAAAAAAAAAApOb3RDcmVhdG9yAAAAAAANAAAAAAAAABBJbnZhbGlkVHRsUG9saWN5AAAADgAAAAAAAAALTm90QXBwcm92ZWQAAAAADwAAAAAAAAAOTWV0YWRhdGFGcm96ZW4AAAAAABAAAAAAAAAACkludmFsaWRVcmkAAAAAABEAAAAAAAAADFRpZXJOb3RGb3VuZAAAABIAAAAAAAAADFRvb01hbnlUaWVycwAAABMAAAAAAAAAD0ludmFsaWRRdWFudGl0eQAAAAAUAAAAAAAAABJXYWxsZXRMaW1pdFJlYWNoZWQAAAAAABUAAAAAAAAAD0ludmFsaWRTY2hlZHVsZQAAAAAWAAAAAAAAAA5TYWxlTm90U3RhcnRlZAAAAAAAFwAAAAAAAAAJU2FsZUVuZGVkAAAAAAAAGAAAAAAAAAAOSW52YWxpZFByZXNhbGUAAAAAABkAAAAAAAAAEFByZXNhbGVOb3RBY3RpdmUAAAAaAAAAAAAAAA5Ob3RPbkFsbG93bGlzdAAAAAAAGwAAAAAAAAANUHJpY2VBYm92ZUNhcAAAAAAAABwAAAAAAAAAD1ByaWNlQmVsb3dGbG9vcgAAAAAdAAAAAAAAABNJbnZhbGlkUmVzYWxlUG9saWN5AAAAAB4AAAAAAAAAFEludmFsaWRSb3lhbHR5U3BsaXRzAAAAHwAAAAAAAAARRmFjdG9yeUFscmVhZHlTZXQAAAAAAAAgAAAAAAAAAAlOb0ZhY3RvcnkAAAAAAAAhAAAAAAAAABJUaWNrZXRzQWxyZWFkeVNvbGQAAAAAACIAAAAAAAAADkV2ZW50Q2FuY2VsbGVkAAAAAAAjAAAAAAAAABFFdmVudE5vdENhbmNlbGxlZAAAAAAAACQAAAAAAAAADU5vdFJlZnVuZGFibGUAAAAAAAAlAAAAAAAAAApFdmVudEVuZGVkAAAAAAAmAAAAAAAAAA5Qcm9jZWVkc0xvY2tlZAAAAAAAJwAAAAAAAAAKTm9Qcm9jZWVkcwAAAAAAKAAAAAAAAAANSW52YWxpZEV4cGlyeQAAAAAAACkAAAAAAAAAC09mZmVyRXhpc3RzAAAAACoAAAAAAAAADU9mZmVyTm90Rm91bmQAAAAAAAArAAAAAAAAAAxPZmZlckV4cGlyZWQAAAAsAAAAAAAAAAtCaWROb3RGb3VuZAAAAAAtAAAAAAAAAAxUaWVyTWlzbWF0Y2gAAAAuAAAAAAAAAA5MaXN0aW5nRXhwaXJlZAAAAAAALwAAAAAAAAAPQXVjdGlvbk5vdEZvdW5kAAAAADAAAAAAAAAAD0F1Y3Rpb25Ob3RFbmRlZAAAAAAxAAAAAAAAAAlCaWRUb29Mb3cAAAAAAAAy",
+            "AAAABQAAADBFbWl0dGVkIHdoZW4gYSBidXllciBtYWtlcyBhbiBvZmZlciBmb3IgYSB0aWNrZXQAAAAAAAAABU9mZmVyAAAAAAAAAQAAAAVvZmZlcgAAAAAAAAQAAAAAAAAACXRpY2tldF9pZAAAAAAAAAQAAAABAAAAAAAAAAVidXllcgAAAAAAABMAAAABAAAAAAAAAAVwcmljZQAAAAAAAAsAAAAAAAAAAAAAAAZleHBpcnkAAAAAAAYAAAAAAAAAAg==",
+            "AAAABQAAADBFbWl0dGVkIHdoZW4gdGhlIGV2ZW50IGNyZWF0b3IgY2FuY2VscyB0aGUgZXZlbnQAAAAAAAAABkNhbmNlbAAAAAAAAQAAAAZjYW5jZWwAAAAAAAEAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAEAAAAC",
+            "AAAABQAAAEhFbWl0dGVkIHdoZW4gYSBsaXN0aW5nIGlzIHdpdGhkcmF3biwgZWl0aGVyIGJ5IHRoZSBzZWxsZXIgb3IgYXQgY2hlY2staW4AAAAAAAAABkRlbGlzdAAAAAAAAQAAAAZkZWxpc3QAAAAAAAIAAAAAAAAACXRpY2tldF9pZAAAAAAAAAQAAAABAAAAAAAAAAZzZWxsZXIAAAAAABMAAAABAAAAAg==",
+            "AAAABQAAAFRFbWl0dGVkIHdoZW4gYSBEdXRjaCBhdWN0aW9uIGJ1eWVyIGdldHMgYmFjayB3aGF0IHRoZXkgcGFpZCBhYm92ZSB0aGUKY2xlYXJpbmcgcHJpY2UAAAAAAAAABlJlYmF0ZQAAAAAAAQAAAAZyZWJhdGUAAAAAAAMAAAAAAAAACXRpY2tldF9pZAAAAAAAAAQAAAABAAAAAAAAAAVidXllcgAAAAAAABMAAAABAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAAg==",
+            "AAAABQAAAEJFbWl0dGVkIHdoZW4gYSBob2xkZXIgb2YgYSBjYW5jZWxsZWQgZXZlbnQgZ2V0cyB0aGVpciBwYXltZW50IGJhY2sAAAAAAAAAAAAGUmVmdW5kAAAAAAABAAAABnJlZnVuZAAAAAAAAwAAAAAAAAAJdGlja2V0X2lkAAAAAAAABAAAAAEAAAAAAAAABW93bmVyAAAAAAAAEwAAAAEAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAC",
+            "AAAAAQAAAINBbiBFbmdsaXNoIGF1Y3Rpb24gb2YgYSB0aWNrZXQuIFRoZSBoaWdoZXN0IGJpZCBpcyBoZWxkIGluIGVzY3JvdyBieSB0aGUKY29udHJhY3QsIGFuZCBiaWRzIG11c3QgcmVhY2ggYHJlc2VydmVgIGJlZm9yZSBgZW5kX3RpbWVgLgAAAAAAAAAAB0F1Y3Rpb24AAAAABgAAAAAAAAAIZW5kX3RpbWUAAAAGAAAAAAAAAAtoaWdoZXN0X2JpZAAAAAALAAAAAAAAAA5oaWdoZXN0X2JpZGRlcgAAAAAD6AAAABMAAAAAAAAAB3Jlc2VydmUAAAAACwAAAAAAAAAGc2VsbGVyAAAAAAATAAAAAAAAAAl0aWNrZXRfaWQAAAAAAAAE",
+            "AAAABQAAAEJFbWl0dGVkIHdoZW4gYW4gb3duZXIgYXBwcm92ZXMgKG9yIGNsZWFycykgYSBzaW5nbGUtdGlja2V0IHNwZW5kZXIAAAAAAAAAAAAHQXBwcm92ZQAAAAABAAAAB2FwcHJvdmUAAAAAAwAAAAAAAAAJdGlja2V0X2lkAAAAAAAABAAAAAEAAAAAAAAABW93bmVyAAAAAAAAEwAAAAEAAAAAAAAACGFwcHJvdmVkAAAD6AAAABMAAAAAAAAAAg==",
+            "AAAABQAAADVFbWl0dGVkIHdoZW4gdGhlIGV2ZW50IGNyZWF0b3IgbWFya3MgYSB0aWNrZXQgYXMgdXNlZAAAAAAAAAAAAAAHQ2hlY2tJbgAAAAABAAAACGNoZWNrX2luAAAAAgAAAAAAAAAJdGlja2V0X2lkAAAAAAAABAAAAAEAAAAAAAAABW93bmVyAAAAAAAAEwAAAAEAAAAC",
+            "AAAABQAAACpFbWl0dGVkIHdoZW4gdGhlIGNvbnRyYWN0IGNvZGUgaXMgcmVwbGFjZWQAAAAAAAAAAAAHVXBncmFkZQAAAAABAAAAB3VwZ3JhZGUAAAAAAQAAAAAAAAANbmV3X3dhc21faGFzaAAAAAAAA+4AAAAgAAAAAAAAAAI=",
+            "AAAABQAAADFFbWl0dGVkIHdoZW4gYSB0aWNrZXQgaXMgdHJhbnNmZXJyZWQgcGVlciB0byBwZWVyAAAAAAAAAAAAAAhUcmFuc2ZlcgAAAAEAAAAIdHJhbnNmZXIAAAADAAAAAAAAAAl0aWNrZXRfaWQAAAAAAAAEAAAAAQAAAAAAAAAEZnJvbQAAABMAAAABAAAAAAAAAAJ0bwAAAAAAEwAAAAEAAAAC",
+            "AAAABQAAAEdFbWl0dGVkIHdoZW4gdGhlIGV2ZW50IGNyZWF0b3Igd2l0aGRyYXdzIGVzY3Jvd2VkIHByaW1hcnkgc2FsZSBwcm9jZWVkcwAAAAAAAAAACFdpdGhkcmF3AAAAAQAAAAh3aXRoZHJhdwAAAAMAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAEAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAAAAAADHByb3RvY29sX2ZlZQAAAAsAAAAAAAAAAg==",
+            "AAAAAQAAAAAAAAAAAAAACUV2ZW50SW5mbwAAAAAAAAsAAAAAAAAAD2NyZWF0b3JfZmVlX2JwcwAAAAAEAAAAAAAAAA1ldmVudF9jcmVhdG9yAAAAAAAAEwAAAAAAAAAOZXZlbnRfbWV0YWRhdGEAAAAAABAAAAAAAAAABG5hbWUAAAAQAAAAAAAAAA1wYXltZW50X3Rva2VuAAAAAAAAEwAAAAAAAAANcHJpbWFyeV9wcmljZQAAAAAAAAsAAAAAAAAAG3JveWFsdHlfcmVtYWluZGVyX3JlY2lwaWVudAAAAAATAAAAAAAAAA5yb3lhbHR5X3NwbGl0cwAAAAAD6gAAB9AAAAAMUm95YWx0eVNwbGl0AAAAAAAAAAhzY2hlZHVsZQAAB9AAAAANRXZlbnRTY2hlZHVsZQAAAAAAAAAAAAAGc3ltYm9sAAAAAAAQAAAAAAAAAAx0b3RhbF9zdXBwbHkAAAAE",
+            "AAAAAQAAAPtIb3cgbG9uZyBzdG9yYWdlIGlzIGtlcHQgYWxpdmUgd2hlbmV2ZXIgaXQgaXMgdG91Y2hlZC4gRW50cmllcyBhcmUgZXh0ZW5kZWQKYnkgYXQgbGVhc3QgYG1pbl9leHRlbnNpb25gIGxlZGdlcnMsIGFuZCBmYXIgZW5vdWdoIHRvIG91dGxpdmUKYHJldGFpbl91bnRpbGAgKGEgbGVkZ2VyIHRpbWVzdGFtcCkuIFVudGlsIHRoZSBjcmVhdG9yIHNldHMgYSBwb2xpY3ksCmByZXRhaW5fdW50aWxgIGlzIHRoZSBldmVudCdzIGBldmVudF9lbmRgLgAAAAAAAAAACVR0bFBvbGljeQAAAAAAAAIAAAAAAAAADW1pbl9leHRlbnNpb24AAAAAAAAEAAAAAAAAAAxyZXRhaW5fdW50aWwAAAAG",
+            "AAAAAQAAAAAAAAAAAAAAClRpY2tldERhdGEAAAAAAAQAAAAAAAAAB2lzX3VzZWQAAAAAAQAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAl0aWNrZXRfaWQAAAAAAAAEAAAAAAAAAAd0aWVyX2lkAAAAAAQ=",
+            "AAAAAQAAAJBBIHRpZXIgYXMgY29uZmlndXJlZCBhdCBkZXBsb3ltZW50LiBgbWF4X3Blcl93YWxsZXRgIGNhcHMgaG93IG1hbnkKdGlja2V0cyBvZiB0aGUgdGllciBvbmUgYWRkcmVzcyBtYXkgYnV5IG9uIHRoZSBwcmltYXJ5IG1hcmtldCAoMCBmb3Igbm8gY2FwKS4AAAAAAAAAClRpZXJDb25maWcAAAAAAAUAAAAAAAAAD2NyZWF0b3JfZmVlX2JwcwAAAAAEAAAAAAAAAA5tYXhfcGVyX3dhbGxldAAAAAAABAAAAAAAAAAEbmFtZQAAABAAAAAAAAAABXByaWNlAAAAAAAACwAAAAAAAAAGc3VwcGx5AAAAAAAE",
+            "AAAABQAAADhFbWl0dGVkIHdoZW4gYSBiaWQgYmVjb21lcyB0aGUgaGlnaGVzdCBiaWQgb2YgYW4gYXVjdGlvbgAAAAAAAAAKQXVjdGlvbkJpZAAAAAAAAQAAAAthdWN0aW9uX2JpZAAAAAAEAAAAAAAAAAl0aWNrZXRfaWQAAAAAAAAEAAAAAQAAAAAAAAAGYmlkZGVyAAAAAAATAAAAAQAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAAAAAAIZW5kX3RpbWUAAAAGAAAAAAAAAAI=",
+            "AAAAAQAAAMJUaGUgcGxhdGZvcm0ncyBjdXQgb2Ygc2FsZXMsIHNldCBieSB0aGUgZGVwbG95aW5nIGZhY3RvcnkuIGBwcmltYXJ5X2Jwc2AKaXMgdGFrZW4gZnJvbSBwcmltYXJ5IHNhbGVzIGFuZCBgc2Vjb25kYXJ5X2Jwc2AgZnJvbSByZXNhbGVzLCBvbiB0b3Agb2YKdGhlIGNyZWF0b3IgZmVlLCBhbmQgYm90aCBhcmUgcGFpZCB0byBgdHJlYXN1cnlgLgAAAAAAAAAAAAtQcm90b2NvbEZlZQAAAAADAAAAAAAAAAtwcmltYXJ5X2JwcwAAAAAEAAAAAAAAAA1zZWNvbmRhcnlfYnBzAAAAAAAABAAAAAAAAAAIdHJlYXN1cnkAAAAT",
+            "AAAAAQAAAJ9BIGJ1eWVyJ3Mgb2ZmZXIgZm9yIGEgdGlja2V0LCB3aXRoIHRoZSBwcmljZSBoZWxkIGluIGVzY3JvdyBieSB0aGUKY29udHJhY3QuIEl0IGNhbiBiZSBhY2NlcHRlZCBieSB3aG9ldmVyIG93bnMgdGhlIHRpY2tldCB1bnRpbCBgZXhwaXJ5YAooYSBsZWRnZXIgdGltZXN0YW1wKS4AAAAAAAAAAAtUaWNrZXRPZmZlcgAAAAAEAAAAAAAAAAVidXllcgAAAAAAABMAAAAAAAAABmV4cGlyeQAAAAAABgAAAAAAAAAFcHJpY2UAAAAAAAALAAAAAAAAAAl0aWNrZXRfaWQAAAAAAAAE",
+            "AAAABQAAAEBFbWl0dGVkIHdoZW4gYSBidXllciB3aXRoZHJhd3MgYW4gb2ZmZXIgYW5kIGdldHMgdGhlIGVzY3JvdyBiYWNrAAAAAAAAAAtPZmZlckNhbmNlbAAAAAABAAAADG9mZmVyX2NhbmNlbAAAAAIAAAAAAAAACXRpY2tldF9pZAAAAAAAAAQAAAABAAAAAAAAAAVidXllcgAAAAAAABMAAAABAAAAAg==",
+            "AAAABQAAADRFbWl0dGVkIHdoZW4gYSBzZWxsZXIgY2hhbmdlcyB0aGUgcHJpY2Ugb2YgYSBsaXN0aW5nAAAAAAAAAAtQcmljZVVwZGF0ZQAAAAABAAAADHByaWNlX3VwZGF0ZQAAAAQAAAAAAAAACXRpY2tldF9pZAAAAAAAAAQAAAABAAAAAAAAAAZzZWxsZXIAAAAAABMAAAABAAAAAAAAAAlvbGRfcHJpY2UAAAAAAAALAAAAAAAAAAAAAAAJbmV3X3ByaWNlAAAAAAAACwAAAAAAAAAC",
+            "AAAAAQAAAQdBIGRlc2NlbmRpbmctcHJpY2UgcHJpbWFyeSBzYWxlIG9mIG9uZSB0aWVyLiBGcm9tIGBzYWxlX3N0YXJ0YCB0aGUgcHJpY2UKZHJvcHMgYnkgYHByaWNlX2Ryb3BgIGV2ZXJ5IGBkcm9wX2ludGVydmFsYCBzZWNvbmRzIHVudGlsIGl0IHJlYWNoZXMKYGZsb29yX3ByaWNlYC4gV2l0aCBgcmViYXRlYCwgYnV5ZXJzIGdldCBiYWNrIHdoYXQgdGhleSBwYWlkIGFib3ZlIHRoZQpjbGVhcmluZyBwcmljZSwgdGhlIHByaWNlIG9mIHRoZSBsYXN0IHRpY2tldCBzb2xkLgAAAAAAAAAADER1dGNoQXVjdGlvbgAAAAYAAAAAAAAADWRyb3BfaW50ZXJ2YWwAAAAAAAAGAAAAAAAAAAtmbG9vcl9wcmljZQAAAAALAAAAAAAAAApwcmljZV9kcm9wAAAAAAALAAAAAAAAAAZyZWJhdGUAAAAAAAEAAAAAAAAAC3N0YXJ0X3ByaWNlAAAAAAsAAAAAAAAAB3RpZXJfaWQAAAAABA==",
+            "AAAAAQAAAPBMaW1pdHMgb24gc2Vjb25kYXJ5IGxpc3RpbmcgcHJpY2VzLiBgbWF4X3ByaWNlYCBpcyBhbiBhYnNvbHV0ZSBjYXAgYW5kCmBtYXhfbWFya3VwX2Jwc2AgYSBjYXAgcmVsYXRpdmUgdG8gdGhlIHRpY2tldCdzIHRpZXIgcHJpY2UgKDEwMDAwIGlzIGZhY2UKdmFsdWUpOyB3aGVuIGJvdGggYXJlIHNldCB0aGUgbG93ZXIgb25lIGFwcGxpZXMuIGBtaW5fcHJpY2VgIGlzIGEgZmxvb3IuClplcm8gZGlzYWJsZXMgYSBsaW1pdC4AAAAAAAAADFJlc2FsZVBvbGljeQAAAAMAAAAAAAAADm1heF9tYXJrdXBfYnBzAAAAAAAEAAAAAAAAAAltYXhfcHJpY2UAAAAAAAALAAAAAAAAAAltaW5fcHJpY2UAAAAAAAAL",
+            "AAAAAQAAALRBIHNoYXJlIG9mIHRoZSBjcmVhdG9yIGZlZSBvbiBzZWNvbmRhcnkgc2FsZXMsIGluIGJhc2lzIHBvaW50cyBvZiB0aGUgZmVlLgpXaGF0ZXZlciB0aGUgc3BsaXRzIGxlYXZlIG92ZXIsIGluY2x1ZGluZyByb3VuZGluZyBkdXN0LCBnb2VzIHRvCmBFdmVudEluZm8ucm95YWx0eV9yZW1haW5kZXJfcmVjaXBpZW50YC4AAAAAAAAADFJveWFsdHlTcGxpdAAAAAIAAAAAAAAAA2JwcwAAAAAEAAAAAAAAAAlyZWNpcGllbnQAAAAAAAAT",
+            "AAAABQAAADJFbWl0dGVkIHdoZW4gYW4gb3duZXIgcHV0cyBhIHRpY2tldCB1cCBmb3IgYXVjdGlvbgAAAAAAAAAAAAxBdWN0aW9uU3RhcnQAAAABAAAADWF1Y3Rpb25fc3RhcnQAAAAAAAAEAAAAAAAAAAl0aWNrZXRfaWQAAAAAAAAEAAAAAQAAAAAAAAAGc2VsbGVyAAAAAAATAAAAAQAAAAAAAAAHcmVzZXJ2ZQAAAAALAAAAAAAAAAAAAAAIZW5kX3RpbWUAAAAGAAAAAAAAAAI=",
+            "AAAAAQAAAK1BIGJ1eWVyJ3Mgc3RhbmRpbmcgYmlkIGZvciB1cCB0byBgcXVhbnRpdHlgIG1vcmUgdGlja2V0cyBvZiB0aGUgZXZlbnQsIG9yCm9mIG9uZSB0aWVyLCBhdCBgcHJpY2VgIGVhY2guIFRoZSBwcmljZSBvZiBldmVyeSB1bmZpbGxlZCB0aWNrZXQgaXMgaGVsZAppbiBlc2Nyb3cgYnkgdGhlIGNvbnRyYWN0LgAAAAAAAAAAAAANQ29sbGVjdGlvbkJpZAAAAAAAAAUAAAAAAAAABmJpZF9pZAAAAAAABAAAAAAAAAAFYnV5ZXIAAAAAAAATAAAAAAAAAAVwcmljZQAAAAAAAAsAAAAAAAAACHF1YW50aXR5AAAABAAAAAAAAAAHdGllcl9pZAAAAAPoAAAABA==",
+            "AAAAAQAAAMJMZWRnZXIgdGltZXN0YW1wcyAoc2Vjb25kcykgYm91bmRpbmcgdGhlIHByaW1hcnkgc2FsZSBhbmQgdGhlIGV2ZW50Cml0c2VsZi4gVGlja2V0cyBjYW4gYmUgbWludGVkIGZyb20gYHNhbGVfc3RhcnRgIHVudGlsIGp1c3QgYmVmb3JlCmBzYWxlX2VuZGAsIGFuZCB0aGUgc2FsZSBtdXN0IGNsb3NlIGJ5IHRoZSBlbmQgb2YgdGhlIGV2ZW50LgAAAAAAAAAAAA1FdmVudFNjaGVkdWxlAAAAAAAABAAAAAAAAAAJZXZlbnRfZW5kAAAAAAAABgAAAAAAAAALZXZlbnRfc3RhcnQAAAAABgAAAAAAAAAIc2FsZV9lbmQAAAAGAAAAAAAAAApzYWxlX3N0YXJ0AAAAAAAG",
+            "AAAAAQAAAP9BbiBhbGxvd2xpc3Qgc2FsZSBvZiBvbmUgdGllciB0aGF0IHJ1bnMgZnJvbSBgc3RhcnRgIHVudGlsIHRoZSBwdWJsaWMKc2FsZSBvcGVucy4gRWxpZ2libGUgYWRkcmVzc2VzIHByb3ZlIG1lbWJlcnNoaXAgd2l0aCBhIE1lcmtsZSBwcm9vZgphZ2FpbnN0IGBtZXJrbGVfcm9vdGAsIHdob3NlIGxlYXZlcyBhcmUgdGhlIFNIQS0yNTYgb2YgZWFjaCBhZGRyZXNzJ3MgWERSCmFuZCB3aG9zZSBwYWlycyBhcmUgaGFzaGVkIGluIHNvcnRlZCBvcmRlci4AAAAAAAAAAA1QcmVzYWxlQ29uZmlnAAAAAAAABQAAAAAAAAAObWF4X3Blcl93YWxsZXQAAAAAAAQAAAAAAAAAC21lcmtsZV9yb290AAAAA+4AAAAgAAAAAAAAAAVwcmljZQAAAAAAAAsAAAAAAAAABXN0YXJ0AAAAAAAABgAAAAAAAAAHdGllcl9pZAAAAAAE",
+            "AAAABQAAAElFbWl0dGVkIHdoZW4gYW4gb3duZXIgZ3JhbnRzIG9yIHJldm9rZXMgYW4gb3BlcmF0b3IgZm9yIGFsbCB0aGVpciB0aWNrZXRzAAAAAAAAAAAAAA1BcHByb3ZlRm9yQWxsAAAAAAAAAQAAAA9hcHByb3ZlX2Zvcl9hbGwAAAAAAwAAAAAAAAAFb3duZXIAAAAAAAATAAAAAQAAAAAAAAAIb3BlcmF0b3IAAAATAAAAAQAAAAAAAAAIYXBwcm92ZWQAAAABAAAAAAAAAAI=",
+            "AAAABQAAAC1FbWl0dGVkIHdoZW4gYW4gYXVjdGlvbiBjbG9zZXMgd2l0aG91dCBhIHNhbGUAAAAAAAAAAAAADUF1Y3Rpb25DYW5jZWwAAAAAAAABAAAADmF1Y3Rpb25fY2FuY2VsAAAAAAACAAAAAAAAAAl0aWNrZXRfaWQAAAAAAAAEAAAAAQAAAAAAAAAGc2VsbGVyAAAAAAATAAAAAQAAAAI=",
+            "AAAABQAAAENFbWl0dGVkIHdoZW4gdGhlIGNyZWF0b3IgY2hhbmdlcyB0aGUgYmFzZSBVUkkgc2hhcmVkIGJ5IGFsbCB0aWNrZXRzAAAAAAAAAAANQmFzZVVyaVVwZGF0ZQAAAAAAAAEAAAAPYmFzZV91cmlfdXBkYXRlAAAAAAEAAAAAAAAACGJhc2VfdXJpAAAAEAAAAAAAAAAC",
+            "AAAAAAAAASVCaWQgb24gYW4gYXVjdGlvbiwgaG9sZGluZyBgYW1vdW50YCBpbiBlc2Nyb3cgYW5kIGNyZWRpdGluZyB0aGUKcHJldmlvdXMgaGlnaGVzdCBiaWQgdG8gaXRzIGJpZGRlciBmb3IgYGNsYWltX2JhbGFuY2VgLiBCaWRzIG11c3QKbWVldCB0aGUgcmVzZXJ2ZSBhbmQgYmVhdCB0aGUgaGlnaGVzdCBiaWQgYnkgYE1JTl9CSURfSU5DUkVNRU5UX0JQU2AsCmFuZCBhIGJpZCBpbiB0aGUgbGFzdCBgQVVDVElPTl9FWFRFTlNJT05fU0VDT05EU2AgcHVzaGVzIHRoZSBlbmQKYmFjaywgdXAgdG8gdGhlIGV2ZW50IHN0YXJ0LgAAAAAAAANiaWQAAAAAAwAAAAAAAAAGYmlkZGVyAAAAAAATAAAAAAAAAAl0aWNrZXRfaWQAAAAAAAAEAAAAAAAAAAZhbW91bnQAAAAAAAsAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+            "AAAABQAAADxFbWl0dGVkIHdoZW4gdGhlIGNyZWF0b3IgcGVybWFuZW50bHkgZnJlZXplcyB0aWNrZXQgbWV0YWRhdGEAAAAAAAAADk1ldGFkYXRhRnJlZXplAAAAAAABAAAAD21ldGFkYXRhX2ZyZWV6ZQAAAAABAAAAAAAAAAdjcmVhdG9yAAAAABMAAAABAAAAAg==",
+            "AAAAAAAAAN9Jbml0aWFsaXplIHRoZSB0aWNrZXQgbWFya2V0cGxhY2UgY29udHJhY3QuIFdpdGggYW4gZW1wdHkgYHRpZXJzYCBsaXN0CnRoZSBldmVudCBoYXMgYSBzaW5nbGUgdGllciBidWlsdCBmcm9tIGB0b3RhbF9zdXBwbHlgLCBgcHJpbWFyeV9wcmljZWAKYW5kIGBjcmVhdG9yX2ZlZV9icHNgOyBvdGhlcndpc2UgdGhlIHRpZXIgc3VwcGxpZXMgbXVzdCBhZGQgdXAgdG8KYHRvdGFsX3N1cHBseWAuAAAAAARpbml0AAAACgAAAAAAAAANZXZlbnRfY3JlYXRvcgAAAAAAABMAAAAAAAAADHRvdGFsX3N1cHBseQAAAAQAAAAAAAAADXByaW1hcnlfcHJpY2UAAAAAAAALAAAAAAAAAA9jcmVhdG9yX2ZlZV9icHMAAAAABAAAAAAAAAAOZXZlbnRfbWV0YWRhdGEAAAAAABAAAAAAAAAABG5hbWUAAAAQAAAAAAAAAAZzeW1ib2wAAAAAABAAAAAAAAAADXBheW1lbnRfdG9rZW4AAAAAAAATAAAAAAAAAAV0aWVycwAAAAAAA+oAAAfQAAAAClRpZXJDb25maWcAAAAAAAAAAAAIc2NoZWR1bGUAAAfQAAAADUV2ZW50U2NoZWR1bGUAAAAAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+            "AAAAAAAAACtHZXQgdGhlIG5hbWUgb2YgdGhlIGV2ZW50L3RpY2tldCBjb2xsZWN0aW9uAAAAAARuYW1lAAAAAAAAAAEAAAPpAAAAEAAAAAM=",
+            "AAAABQAAAENFbWl0dGVkIHdoZW4gdGhlIGNyZWF0b3Igc2V0cyBvciBjbGVhcnMgYSB0aWNrZXQncyBvd24gbWV0YWRhdGEgVVJJAAAAAAAAAAAPVGlja2V0VXJpVXBkYXRlAAAAAAEAAAARdGlja2V0X3VyaV91cGRhdGUAAAAAAAACAAAAAAAAAAl0aWNrZXRfaWQAAAAAAAAEAAAAAQAAAAAAAAADdXJpAAAAA+gAAAAQAAAAAAAAAAI=",
+            "AAAAAQAAAI5BIHRpY2tldCBmb3Igc2FsZSBvbiB0aGUgc2Vjb25kYXJ5IG1hcmtldC4gTGlzdGluZ3MgbGFwc2UgYXQgYGV4cGlyZXNfYXRgCihhIGxlZGdlciB0aW1lc3RhbXApLCBpZiBzZXQsIGFuZCBpbiBhbnkgY2FzZSBvbmNlIHRoZSBldmVudCBzdGFydHMuAAAAAAAAAAAAEFNlY29uZGFyeUxpc3RpbmcAAAAEAAAAAAAAAApleHBpcmVzX2F0AAAAAAPoAAAABgAAAAAAAAAFcHJpY2UAAAAAAAALAAAAAAAAAAZzZWxsZXIAAAAAABMAAAAAAAAACXRpY2tldF9pZAAAAAAAAAQ=",
+            "AAAAAgAAAGZXaG8gbWF5IHVwZ3JhZGUgdGhlIGNvbnRyYWN0IGNvZGU6IHRoZSBkZXBsb3lpbmcgZmFjdG9yeSAob24gYmVoYWxmIG9mIGl0cwphZG1pbikgb3IgdGhlIGV2ZW50IGNyZWF0b3IAAAAAAAAAAAAQVXBncmFkZUF1dGhvcml0eQAAAAIAAAAAAAAAAAAAAAdGYWN0b3J5AAAAAAAAAAAAAAAAB0NyZWF0b3IA",
+            "AAAAAAAAABxHZXQgdGhlIHN5bWJvbCBvZiB0aGUgdGlja2V0AAAABnN5bWJvbAAAAAAAAAAAAAEAAAPpAAAAEAAAAAM=",
+            "AAAAAAAAAMhBcHByb3ZlIGEgc3BlbmRlciBmb3IgYSBzaW5nbGUgdGlja2V0LCBvciBjbGVhciB0aGUgYXBwcm92YWwgd2l0aCBgTm9uZWAuClRoZSBhcHByb3ZlciBtdXN0IGJlIHRoZSBvd25lciBvciBvbmUgb2YgdGhlIG93bmVyJ3Mgb3BlcmF0b3JzLCBhbmQgdGhlCmFwcHJvdmFsIGlzIGNsZWFyZWQgd2hlbmV2ZXIgdGhlIHRpY2tldCBjaGFuZ2VzIGhhbmRzLgAAAAdhcHByb3ZlAAAAAAMAAAAAAAAACGFwcHJvdmVyAAAAEwAAAAAAAAAIYXBwcm92ZWQAAAPoAAAAEwAAAAAAAAAJdGlja2V0X2lkAAAAAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+            "AAAAAAAAACxHZXQgdGhlIG51bWJlciBvZiB0aWNrZXRzIGhlbGQgYnkgYW4gYWRkcmVzcwAAAAdiYWxhbmNlAAAAAAEAAAAAAAAABW93bmVyAAAAAAAAEwAAAAEAAAAE",
+            "AAAAAAAAAK5SZXBsYWNlIHRoZSBjb250cmFjdCBjb2RlLiBPbmx5IHRoZSB1cGdyYWRlIGF1dGhvcml0eSBtYXkgZG8gdGhpczogdGhlCmRlcGxveWluZyBmYWN0b3J5IGJ5IGRlZmF1bHQsIG9yIHRoZSBldmVudCBjcmVhdG9yIGZvciBldmVudHMgd2l0aG91dAphIGZhY3Rvcnkgb3IgdGhhdCBoYXZlIG9wdGVkIG91dC4AAAAAAAd1cGdyYWRlAAAAAAEAAAAAAAAADW5ld193YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+            "AAAAAAAAAClHZXQgdGhlIHZlcnNpb24gb2YgdGhlIGNvbnRyYWN0IGludGVyZmFjZQAAAAAAAAd2ZXJzaW9uAAAAAAAAAAABAAAABA==",
+            "AAAABQAAAEdFbWl0dGVkIHdoZW4gYSBidXllciBwbGFjZXMgYSBiaWQgZm9yIGFueSB0aWNrZXQgb2YgdGhlIGV2ZW50IG9yIGEgdGllcgAAAAAAAAAAEkNvbGxlY3Rpb25CaWRQbGFjZQAAAAAAAQAAABRjb2xsZWN0aW9uX2JpZF9wbGFjZQAAAAUAAAAAAAAABmJpZF9pZAAAAAAABAAAAAEAAAAAAAAABWJ1eWVyAAAAAAAAEwAAAAEAAAAAAAAAB3RpZXJfaWQAAAAD6AAAAAQAAAAAAAAAAAAAAAVwcmljZQAAAAAAAAsAAAAAAAAAAAAAAAhxdWFudGl0eQAAAAQAAAAAAAAAAg==",
+            "AAAAAAAAABFHZXQgYSBzaW5nbGUgdGllcgAAAAAAAAhnZXRfdGllcgAAAAEAAAAAAAAAB3RpZXJfaWQAAAAABAAAAAEAAAPpAAAH0AAAAARUaWVyAAAAAw==",
+            "AAAAAAAAACFHZXQgdGhlIGN1cnJlbnQgb3duZXIgb2YgYSB0aWNrZXQAAAAAAAAIb3duZXJfb2YAAAABAAAAAAAAAAl0aWNrZXRfaWQAAAAAAAAEAAAAAQAAA+kAAAATAAAAAw==",
+            "AAAABQAAAFRFbWl0dGVkIHdoZW4gYSBidXllciB3aXRoZHJhd3MgYSBjb2xsZWN0aW9uIGJpZCBhbmQgZ2V0cyB0aGUgcmVzdCBvZiB0aGUKZXNjcm93IGJhY2sAAAAAAAAAE0NvbGxlY3Rpb25CaWRDYW5jZWwAAAAAAQAAABVjb2xsZWN0aW9uX2JpZF9jYW5jZWwAAAAAAAADAAAAAAAAAAZiaWRfaWQAAAAAAAQAAAABAAAAAAAAAAVidXllcgAAAAAAABMAAAABAAAAAAAAAAZyZWZ1bmQAAAAAAAsAAAAAAAAAAg==",
+            "AAAAAAAAAChHZXQgYSBidXllcidzIG9mZmVyIGZvciBhIHRpY2tldCwgaWYgYW55AAAACWdldF9vZmZlcgAAAAAAAAIAAAAAAAAACXRpY2tldF9pZAAAAAAAAAQAAAAAAAAABWJ1eWVyAAAAAAAAEwAAAAEAAAPoAAAH0AAAAAtUaWNrZXRPZmZlcgA=",
+            "AAAAAAAAAC1HZXQgYWxsIHRpZXJzIHdpdGggdGhlaXIgcHJpbWFyeSBzYWxlcyBzbyBmYXIAAAAAAAAJZ2V0X3RpZXJzAAAAAAAAAAAAAAEAAAPpAAAD6gAAB9AAAAAEVGllcgAAAAM=",
+            "AAAAAAAAAIVHZXQgdGhlIG1ldGFkYXRhIFVSSSBvZiBhIHRpY2tldC4gQSBwZXItdGlja2V0IG92ZXJyaWRlIHdpbnMsIHRoZW4gdGhlCmJhc2UgVVJJIGZvbGxvd2VkIGJ5IHRoZSB0aWNrZXQgaWQsIHRoZW4gdGhlIGV2ZW50J3MgbWV0YWRhdGEuAAAAAAAACXRva2VuX3VyaQAAAAAAAAEAAAAAAAAACXRpY2tldF9pZAAAAAAAAAQAAAABAAAD6QAAABAAAAAD",
+            "AAAAAAAAAEVHZXQgYWxsIG9mZmVycyBmb3IgYSB0aWNrZXQsIGluY2x1ZGluZyBleHBpcmVkIG9uZXMgbm90IHlldCBjYW5jZWxsZWQAAAAAAAAKZ2V0X29mZmVycwAAAAAAAQAAAAAAAAAJdGlja2V0X2lkAAAAAAAABAAAAAEAAAPqAAAH0AAAAAtUaWNrZXRPZmZlcgA=",
+            "AAAAAAAAABZHZXQgdGlja2V0IGluZm9ybWF0aW9uAAAAAAAKZ2V0X3RpY2tldAAAAAAAAQAAAAAAAAAJdGlja2V0X2lkAAAAAAAABAAAAAEAAAPpAAAH0AAAAApUaWNrZXREYXRhAAAAAAAD",
+            "AAAAAAAAAEFDaGVjayB3aGV0aGVyIHRoZSBwcmltYXJ5IHNhbGUgaXMgb3BlbiBhdCB0aGUgY3VycmVudCBsZWRnZXIgdGltZQAAAAAAAAppc19vbl9zYWxlAAAAAAAAAAAAAQAAA+kAAAABAAAAAw==",
+            "AAAAAAAAAM9PZmZlciB0byBidXkgYSB0aWNrZXQsIGxpc3RlZCBvciBub3QsIGhvbGRpbmcgYHByaWNlYCBpbiBlc2Nyb3cgdW50aWwKdGhlIG9mZmVyIGlzIGFjY2VwdGVkIG9yIGNhbmNlbGxlZC4gT25lIG9mZmVyIHBlciBidXllciBhbmQgdGlja2V0LAphbmQgYE9mZmVyRXhpc3RzYCBvbmNlIHRoZSB0aWNrZXQgaGFzIGBNQVhfT0ZGRVJTX1BFUl9USUNLRVRgIG9mZmVycy4AAAAACm1ha2Vfb2ZmZXIAAAAAAAQAAAAAAAAABWJ1eWVyAAAAAAAAEwAAAAAAAAAJdGlja2V0X2lkAAAAAAAABAAAAAAAAAAFcHJpY2UAAAAAAAALAAAAAAAAAAZleHBpcnkAAAAAAAYAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+            "AAAAAAAAAUJIb2xkIHByaW1hcnkgc2FsZSBwYXltZW50cyBpbiB0aGUgY29udHJhY3QgaW5zdGVhZCBvZiBwYXlpbmcgdGhlCmNyZWF0b3Igc3RyYWlnaHQgYXdheSwgc28gYnV5ZXJzIGNhbiBiZSByZWZ1bmRlZCBpZiB0aGUgZXZlbnQgaXMKY2FuY2VsbGVkIChldmVudCBjcmVhdG9yIG9ubHkpLiBNdXN0IGJlIGNob3NlbiBiZWZvcmUgYW55IHRpY2tldCBpcyBzb2xkLgpUaGUgcGF5bWVudCBmb3IgYSB0aWNrZXQgaXMgcmVsZWFzZWQgd2hlbiBpdCBpcyBjaGVja2VkIGluLCBhbmQKZXZlcnl0aGluZyBzdGlsbCBoZWxkIGlzIHJlbGVhc2VkIG9uY2UgdGhlIGV2ZW50IGVuZHMuAAAAAAAKc2V0X2VzY3JvdwAAAAAAAgAAAAAAAAAHY3JlYXRvcgAAAAATAAAAAAAAAAdlbmFibGVkAAAAAAEAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+            "AAAAAAAAABtHZXQgdGhlIGF1Y3Rpb24gb2YgYSB0aWNrZXQAAAAAC2dldF9hdWN0aW9uAAAAAAEAAAAAAAAACXRpY2tldF9pZAAAAAAAAAQAAAABAAAD6QAAB9AAAAAHQXVjdGlvbgAAAAAD",
+            "AAAAAAAAADBHZXQgdGhlIGZhY3RvcnkgdGhhdCBkZXBsb3llZCB0aGlzIGV2ZW50LCBpZiBhbnkAAAALZ2V0X2ZhY3RvcnkAAAAAAAAAAAEAAAPoAAAAEw==",
+            "AAAAAAAAACVHZXQgdGhlIHByZXNhbGUgY29uZmlndXJhdGlvbiwgaWYgYW55AAAAAAAAC2dldF9wcmVzYWxlAAAAAAAAAAABAAAD6AAAB9AAAAANUHJlc2FsZUNvbmZpZwAAAA==",
+            "AAAAAAAAADZDaGVjayB3aGV0aGVyIHByaW1hcnkgc2FsZSBwYXltZW50cyBhcmUgaGVsZCBpbiBlc2Nyb3cAAAAAAAtpc19lc2Nyb3dlZAAAAAAAAAAAAQAAAAE=",
+            "AAAAAAAAAHBMaXN0IHRpY2tldCBvbiBzZWNvbmRhcnkgbWFya2V0cGxhY2UsIG9wdGlvbmFsbHkgdW50aWwgYGV4cGlyZXNfYXRgLgpBIGxhcHNlZCBsaXN0aW5nIG9mIHRoZSB0aWNrZXQgaXMgcmVwbGFjZWQuAAAAC2xpc3RfdGlja2V0AAAAAAQAAAAAAAAABnNlbGxlcgAAAAAAEwAAAAAAAAAJdGlja2V0X2lkAAAAAAAABAAAAAAAAAAFcHJpY2UAAAAAAAALAAAAAAAAAApleHBpcmVzX2F0AAAAAAPoAAAABgAAAAEAAAPpAAAD7QAAAAAAAAAD",
+            "AAAAAAAAAFFNaW50IGEgdGlja2V0IG9mIHRoZSBnaXZlbiB0aWVyIGZyb20gcHJpbWFyeSBtYXJrZXRwbGFjZSAoYnV5IGZyb20gZXZlbnQgY3JlYXRvcikAAAAAAAALbWludF90aWNrZXQAAAAAAgAAAAAAAAAFYnV5ZXIAAAAAAAATAAAAAAAAAAd0aWVyX2lkAAAAAAQAAAABAAAD6QAAAAQAAAAD",
+            "AAAAAAAAAMFSZWNvcmQgdGhlIGZhY3RvcnkgdGhhdCBkZXBsb3llZCB0aGlzIGV2ZW50IGFuZCB0aGUgcHJvdG9jb2wgZmVlIGl0CmNoYXJnZXMuIEJvdGggdGhlIGZhY3RvcnkgYW5kIHRoZSBldmVudCBjcmVhdG9yIG11c3QgYXV0aG9yaXplIHRoaXMsCmFuZCBpdCBjYW4gb25seSBiZSBkb25lIG9uY2UsIGJlZm9yZSBhbnkgdGlja2V0IGlzIHNvbGQuAAAAAAAAC3NldF9mYWN0b3J5AAAAAAIAAAAAAAAAB2ZhY3RvcnkAAAAAEwAAAAAAAAAMcHJvdG9jb2xfZmVlAAAH0AAAAAtQcm90b2NvbEZlZQAAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+            "AAAAAAAAAIlDb25maWd1cmUgdGhlIGFsbG93bGlzdCBwcmVzYWxlLCBvciByZXBsYWNlIGl0cyByb290LCBwcmljZSBvciBjYXAKKGV2ZW50IGNyZWF0b3Igb25seSkuIFRoZSBwcmVzYWxlIGhhcyB0byBzdGFydCBiZWZvcmUgdGhlIHB1YmxpYyBzYWxlLgAAAAAAAAtzZXRfcHJlc2FsZQAAAAACAAAAAAAAAAdjcmVhdG9yAAAAABMAAAAAAAAAB3ByZXNhbGUAAAAH0AAAAA1QcmVzYWxlQ29uZmlnAAAAAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+            "AAAAAAAAAJ1BY2NlcHQgYW4gdW5leHBpcmVkIG9mZmVyIGZvciBhIHRpY2tldCAoY3VycmVudCBvd25lciBvbmx5KS4gVGhlCmVzY3Jvd2VkIHByaWNlIGlzIHBhaWQgb3V0IGxpa2UgYSBzZWNvbmRhcnkgc2FsZSBhbmQgYW55IGxpc3Rpbmcgb2YKdGhlIHRpY2tldCBpcyB3aXRoZHJhd24uAAAAAAAADGFjY2VwdF9vZmZlcgAAAAMAAAAAAAAABnNlbGxlcgAAAAAAEwAAAAAAAAAJdGlja2V0X2lkAAAAAAAABAAAAAAAAAAFYnV5ZXIAAAAAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+            "AAAAAAAAAL9DYW5jZWwgdGhlIGV2ZW50IGJlZm9yZSBpdCBlbmRzIChldmVudCBjcmVhdG9yIG9ubHkpLiBNaW50aW5nLCB0cmFkaW5nLAp0cmFuc2ZlcnMgYW5kIGNoZWNrLWluIHN0b3AgZm9yIGdvb2QsIGFuZCBob2xkZXJzIG9mIGVzY3Jvd2VkIHRpY2tldHMKY2FuIGNsYWltIHRoZWlyIHBheW1lbnQgYmFjayB3aXRoIGBjbGFpbV9yZWZ1bmRgLgAAAAAMY2FuY2VsX2V2ZW50AAAAAQAAAAAAAAAHY3JlYXRvcgAAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+            "AAAAAAAAAJJXaXRoZHJhdyBhbiBvZmZlciBhbmQgZ2V0IHRoZSBlc2Nyb3dlZCBwcmljZSBiYWNrLiBUaGlzIGlzIGFsc28gaG93CmJ1eWVycyByZWNvdmVyIGV4cGlyZWQgb2ZmZXJzIG9yIG9mZmVycyBvbiB0aWNrZXRzIHRoYXQgY2FuIG5vIGxvbmdlcgpiZSBzb2xkLgAAAAAADGNhbmNlbF9vZmZlcgAAAAIAAAAAAAAABWJ1eWVyAAAAAAAAEwAAAAAAAAAJdGlja2V0X2lkAAAAAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+            "AAAAAAAAAOJQYXkgYSBEdXRjaCBhdWN0aW9uIGJ1eWVyIGJhY2sgd2hhdCB0aGV5IHBhaWQgZm9yIGEgdGlja2V0IGFib3ZlIHRoZQpjbGVhcmluZyBwcmljZSAob3JpZ2luYWwgYnV5ZXIgb25seSksIG9uY2UuIFJlYmF0ZXMgc3RheSBjbGFpbWFibGUKYWZ0ZXIgdGhlIHRpY2tldCBpcyByZXNvbGQgb3IgY2hlY2tlZCBpbiBhbmQgYWZ0ZXIgdGhlIGNyZWF0b3IgaGFzCndpdGhkcmF3biB0aGUgcHJvY2VlZHMuAAAAAAAMY2xhaW1fcmViYXRlAAAAAQAAAAAAAAAJdGlja2V0X2lkAAAAAAAABAAAAAEAAAPpAAAACwAAAAM=",
+            "AAAAAAAAAI9SZWZ1bmQgdGhlIHByaWNlIHBhaWQgZm9yIGEgdGlja2V0IG9mIGEgY2FuY2VsbGVkIGV2ZW50IHRvIGl0cyBvd25lci4KT25seSB1bnVzZWQgdGlja2V0cyBib3VnaHQgd2l0aCBlc2Nyb3cgZW5hYmxlZCBhcmUgcmVmdW5kYWJsZSwgb25jZSBlYWNoLgAAAAAMY2xhaW1fcmVmdW5kAAAAAQAAAAAAAAAJdGlja2V0X2lkAAAAAAAABAAAAAEAAAPpAAAACwAAAAM=",
+            "AAAAAAAAADRHZXQgdGhlIGFkZHJlc3MgYXBwcm92ZWQgdG8gdHJhbnNmZXIgYSBzaW5nbGUgdGlja2V0AAAADGdldF9hcHByb3ZlZAAAAAEAAAAAAAAACXRpY2tldF9pZAAAAAAAAAQAAAABAAAD6QAAA+gAAAATAAAAAw==",
+            "AAAAAAAAACVHZXQgdGhlIGJhc2UgVVJJLCBpZiBvbmUgaGFzIGJlZW4gc2V0AAAAAAAADGdldF9iYXNlX3VyaQAAAAAAAAABAAAD6AAAABA=",
+            "AAAAAAAAACpDaGVjayB3aGV0aGVyIHRoZSBldmVudCBoYXMgYmVlbiBjYW5jZWxsZWQAAAAAAAxpc19jYW5jZWxsZWQAAAAAAAAAAQAAAAE=",
+            "AAAAAAAAAI1NaW50IHByZXNhbGUgdGlja2V0cyBhdCB0aGUgcHJlc2FsZSBwcmljZS4gVGhlIGJ1eWVyIHByb3ZlcyB0aGV5IGFyZSBvbgp0aGUgYWxsb3dsaXN0IHdpdGggdGhlIHNpYmxpbmcgaGFzaGVzIGZyb20gdGhlaXIgbGVhZiB1cCB0byB0aGUgcm9vdC4AAAAAAAAMbWludF9wcmVzYWxlAAAAAwAAAAAAAAAFYnV5ZXIAAAAAAAATAAAAAAAAAAhxdWFudGl0eQAAAAQAAAAAAAAABXByb29mAAAAAAAD6gAAA+4AAAAgAAAAAQAAA+kAAAPqAAAABAAAAAM=",
+            "AAAAAAAAAMFNaW50IHNldmVyYWwgdGlja2V0cyBvZiBvbmUgdGllciBpbiBhIHNpbmdsZSBwdXJjaGFzZS4gVGhlIGJ1eWVyIHBheXMKb25jZSBmb3IgdGhlIHdob2xlIGJhdGNoIGFuZCByZWNlaXZlcyBjb25zZWN1dGl2ZSB0aWNrZXQgaWRzOyBpZiB0aGUKdGllciBjYW5ub3QgY292ZXIgdGhlIGZ1bGwgcXVhbnRpdHkgbm90aGluZyBpcyBtaW50ZWQuAAAAAAAADG1pbnRfdGlja2V0cwAAAAMAAAAAAAAABWJ1eWVyAAAAAAAAEwAAAAAAAAAHdGllcl9pZAAAAAAEAAAAAAAAAAhxdWFudGl0eQAAAAQAAAABAAAD6QAAA+oAAAAEAAAAAw==",
+            "AAAAAAAAAEVTZXQgdGhlIGJhc2UgVVJJIHRoYXQgdGlja2V0IGlkcyBhcmUgYXBwZW5kZWQgdG8gKGV2ZW50IGNyZWF0b3Igb25seSkAAAAAAAAMc2V0X2Jhc2VfdXJpAAAAAgAAAAAAAAAHY3JlYXRvcgAAAAATAAAAAAAAAAhiYXNlX3VyaQAAABAAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+            "AAAAAAAAAHxQYXkgb3V0IHdoYXQgYW4gYWRkcmVzcyBpcyBvd2VkIGZyb20gYXVjdGlvbnM6IGJpZHMgdGhhdCB3ZXJlIG91dGJpZApvciByZWZ1bmRlZCwgYW5kIHRoZSBzZWxsZXIncyBzaGFyZSBvZiBzZXR0bGVkIGF1Y3Rpb25zAAAADWNsYWltX2JhbGFuY2UAAAAAAAABAAAAAAAAAAVvd25lcgAAAAAAABMAAAABAAAD6QAAAAsAAAAD",
+            "AAAAAAAAAChEZWxpc3QgdGlja2V0IGZyb20gc2Vjb25kYXJ5IG1hcmtldHBsYWNlAAAADWRlbGlzdF90aWNrZXQAAAAAAAACAAAAAAAAAAZzZWxsZXIAAAAAABMAAAAAAAAACXRpY2tldF9pZAAAAAAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+            "AAAAAAAAADRHZXQgd2hhdCBhbiBhZGRyZXNzIGNhbiBjb2xsZWN0IHdpdGggYGNsYWltX2JhbGFuY2VgAAAADWdldF9jbGFpbWFibGUAAAAAAAABAAAAAAAAAAVvd25lcgAAAAAAABMAAAABAAAACw==",
+            "AAAAAAAAAHFHZXQgaG93IG1hbnkgdGlja2V0cyBhbiBhZGRyZXNzIGhhcyBib3VnaHQgb24gdGhlIHByaW1hcnkgbWFya2V0LgpUcmFuc2ZlcnMgYW5kIHJlc2FsZXMgZG8gbm90IHJlZHVjZSB0aGlzIGNvdW50LgAAAAAAAA1nZXRfcHVyY2hhc2VkAAAAAAAAAQAAAAAAAAAFYnV5ZXIAAAAAAAATAAAAAQAAAAQ=",
+            "AAAAAAAAAMdQdXQgYW4gdW51c2VkLCB1bmxpc3RlZCB0aWNrZXQgdXAgZm9yIGF1Y3Rpb24gdW50aWwgYGVuZF90aW1lYCwgd2hpY2gKbXVzdCBiZSBubyBsYXRlciB0aGFuIHRoZSBzdGFydCBvZiB0aGUgZXZlbnQgKHRpY2tldCBvd25lciBvbmx5KS4KQXVjdGlvbmVkIHRpY2tldHMgY291bnQgYXMgbGlzdGVkIHVudGlsIHRoZSBhdWN0aW9uIGlzIHNldHRsZWQuAAAAAA1zdGFydF9hdWN0aW9uAAAAAAAAAwAAAAAAAAAJdGlja2V0X2lkAAAAAAAABAAAAAAAAAAHcmVzZXJ2ZQAAAAALAAAAAAAAAAhlbmRfdGltZQAAAAYAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+            "AAAAAAAAAM1UcmFuc2ZlciBhIHRpY2tldCBvbiBiZWhhbGYgb2YgaXRzIG93bmVyLiBUaGUgc3BlbmRlciBtdXN0IGJlIHRoZSBvd25lciwKdGhlIHRpY2tldCdzIGFwcHJvdmVkIGFkZHJlc3Mgb3IgYW4gb3BlcmF0b3Igb2YgdGhlIG93bmVyLiBVc2VkIGFuZApsaXN0ZWQgdGlja2V0cyBjYW5ub3QgYmUgdHJhbnNmZXJyZWQsIGFzIHdpdGggYHRyYW5zZmVyX3RpY2tldGAuAAAAAAAADXRyYW5zZmVyX2Zyb20AAAAAAAAEAAAAAAAAAAdzcGVuZGVyAAAAABMAAAAAAAAABGZyb20AAAATAAAAAAAAAAJ0bwAAAAAAEwAAAAAAAAAJdGlja2V0X2lkAAAAAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+            "AAAAAAAAABVHZXQgZXZlbnQgaW5mb3JtYXRpb24AAAAAAAAOZ2V0X2V2ZW50X2luZm8AAAAAAAAAAAABAAAD6QAAB9AAAAAJRXZlbnRJbmZvAAAAAAAAAw==",
+            "AAAAAAAAABpHZXQgdGhlIHN0b3JhZ2UgVFRMIHBvbGljeQAAAAAADmdldF90dGxfcG9saWN5AAAAAAAAAAAAAQAAB9AAAAAJVHRsUG9saWN5AAAA",
+            "AAAAAAAAAI1TZXQgb3IgY2xlYXIgYSB0aWNrZXQncyBvd24gbWV0YWRhdGEgVVJJIChldmVudCBjcmVhdG9yIG9ubHkpLiBUaWNrZXRzCnRoYXQgaGF2ZSBub3QgYmVlbiBtaW50ZWQgeWV0IGNhbiBiZSBnaXZlbiBzZWF0LXNwZWNpZmljIG1ldGFkYXRhIHRvby4AAAAAAAAOc2V0X3RpY2tldF91cmkAAAAAAAMAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAAAAAAJdGlja2V0X2lkAAAAAAAABAAAAAAAAAADdXJpAAAAA+gAAAAQAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+            "AAAAAAAAAD1TZXQgaG93IGxvbmcgZXZlbnQgc3RvcmFnZSBpcyBrZXB0IGFsaXZlIChldmVudCBjcmVhdG9yIG9ubHkpAAAAAAAADnNldF90dGxfcG9saWN5AAAAAAACAAAAAAAAAAdjcmVhdG9yAAAAABMAAAAAAAAABnBvbGljeQAAAAAH0AAAAAlUdGxQb2xpY3kAAAAAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+            "AAAAAAAAAYRDbG9zZSBhbiBhdWN0aW9uIG9uY2UgaXQgaGFzIGVuZGVkIChjYWxsYWJsZSBieSBhbnlvbmUpLiBUaGUgdGlja2V0CmdvZXMgdG8gdGhlIGhpZ2hlc3QgYmlkZGVyLCB3aXRoIHRoZSB3aW5uaW5nIGJpZCBwYWlkIG91dCBsaWtlIGEKc2Vjb25kYXJ5IHNhbGUgZXhjZXB0IHRoYXQgdGhlIHNlbGxlcidzIHNoYXJlIGlzIGNyZWRpdGVkIHRvCmBjbGFpbV9iYWxhbmNlYC4gSWYgdGhlcmUgd2VyZSBubyBiaWRzIHRoZSB0aWNrZXQgc3RheXMgd2l0aCB0aGUKc2VsbGVyLCBhbmQgaWYgdGhlIGV2ZW50IHdhcyBjYW5jZWxsZWQgb3IgdGhlIHRpY2tldCBjaGVja2VkIGluCm1lYW53aGlsZSB0aGUgaGlnaGVzdCBiaWQgaXMgY3JlZGl0ZWQgYmFjayB0byBpdHMgYmlkZGVyIGluc3RlYWQuAAAADnNldHRsZV9hdWN0aW9uAAAAAAABAAAAAAAAAAl0aWNrZXRfaWQAAAAAAAAEAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+            "AAAAAAAAAEdQZXJtYW5lbnRseSBwcmV2ZW50IGZ1cnRoZXIgY2hhbmdlcyB0byB0aWNrZXQgVVJJcyAoZXZlbnQgY3JlYXRvciBvbmx5KQAAAAAPZnJlZXplX21ldGFkYXRhAAAAAAEAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAEAAAPpAAAD7QAAAAAAAAAD",
+            "AAAAAAAAAB5UcmFuc2ZlciB0aWNrZXQgZGlyZWN0bHkgKFAyUCkAAAAAAA90cmFuc2Zlcl90aWNrZXQAAAAAAwAAAAAAAAAEZnJvbQAAABMAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAl0aWNrZXRfaWQAAAAAAAAEAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+            "AAAAAAAAAHRFeHRlbmQgdGhlIFRUTCBvZiB0aGUgY29udHJhY3QgaW5zdGFuY2UsIGNvZGUgYW5kIGV2ZW50IGluZm9ybWF0aW9uLgpBbnlvbmUgbWF5IGNhbGwgdGhpcyBhbmQgcGF5IGZvciB0aGUgZXh0ZW5zaW9uLgAAABBleHRlbmRfZXZlbnRfdHRsAAAAAAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+            "AAAAAAAAAFFHZXQgdGhlIGF1Y3Rpb25zIHRoYXQgaGF2ZSBub3QgYmVlbiBzZXR0bGVkIGFtb25nIGBsaW1pdGAgdGlja2V0IGlkcwpmcm9tIGBzdGFydGAAAAAAAAAQZ2V0X2FsbF9hdWN0aW9ucwAAAAIAAAAAAAAABXN0YXJ0AAAAAAAABAAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAA+oAAAfQAAAAB0F1Y3Rpb24A",
+            "AAAAAAAAAD1HZXQgdGhlIHByb3RvY29sIGZlZSBjaGFyZ2VkIGJ5IHRoZSBkZXBsb3lpbmcgZmFjdG9yeSwgaWYgYW55AAAAAAAAEGdldF9wcm90b2NvbF9mZWUAAAAAAAAAAQAAA+gAAAfQAAAAC1Byb3RvY29sRmVlAA==",
             "AAAAAAAAABlHZXQgdGlja2V0cyBvd25lZCBieSB1c2VyAAAAAAAAEGdldF91c2VyX3RpY2tldHMAAAABAAAAAAAAAAR1c2VyAAAAEwAAAAEAAAPqAAAABA==",
-            "AAAAAAAAACdNYXJrIHRpY2tldCBhcyB1c2VkIChjaGVjay1pbiBhdCBldmVudCkAAAAAEG1hcmtfdGlja2V0X3VzZWQAAAACAAAAAAAAAAdjcmVhdG9yAAAAABMAAAAAAAAACXRpY2tldF9pZAAAAAAAAAQAAAAA",
+            "AAAAAAAAAIdNYXJrIHRpY2tldCBhcyB1c2VkIChjaGVjay1pbiBhdCBldmVudCkuIENoZWNrLWluIG9wZW5zIGF0IHRoZSBzdGFydApvZiB0aGUgZXZlbnQsIHNvIGVzY3Jvd2VkIHBheW1lbnRzIGNhbm5vdCBiZSByZWxlYXNlZCBiZWZvcmUgdGhlbi4AAAAAEG1hcmtfdGlja2V0X3VzZWQAAAACAAAAAAAAAAdjcmVhdG9yAAAAABMAAAAAAAAACXRpY2tldF9pZAAAAAAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+            "AAAAAAAAANNFeHRlbmQgdGhlIFRUTCBvZiBhIHRpY2tldCBhbmQgdGhlIGVudHJpZXMgdGllZCB0byBpdDogaXRzIGxpc3RpbmcsCmF1Y3Rpb24sIG9mZmVycywgYXBwcm92YWwsIG1ldGFkYXRhIFVSSSwgZXNjcm93ZWQgcGF5bWVudCwgcmViYXRlIGFuZAp0aGUgb3duZXIncyB0aWNrZXQgaW5kZXguCkFueW9uZSBtYXkgY2FsbCB0aGlzIGFuZCBwYXkgZm9yIHRoZSBleHRlbnNpb24uAAAAABFleHRlbmRfdGlja2V0X3R0bAAAAAAAAAEAAAAAAAAACXRpY2tldF9pZAAAAAAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+            "AAAAAAAAAENHZXQgdGhlIHByaWNlIGEgdGlja2V0IG9mIGEgdGllciBjb3N0cyBvbiB0aGUgcHVibGljIHNhbGUgcmlnaHQgbm93AAAAABFnZXRfY3VycmVudF9wcmljZQAAAAAAAAEAAAAAAAAAB3RpZXJfaWQAAAAABAAAAAEAAAPpAAAACwAAAAM=",
+            "AAAAAAAAACtHZXQgdGhlIER1dGNoIGF1Y3Rpb24gY29uZmlndXJhdGlvbiwgaWYgYW55AAAAABFnZXRfZHV0Y2hfYXVjdGlvbgAAAAAAAAAAAAABAAAD6AAAB9AAAAAMRHV0Y2hBdWN0aW9u",
+            "AAAAAAAAACpHZXQgdGhlIGxpbWl0cyBvbiBzZWNvbmRhcnkgbGlzdGluZyBwcmljZXMAAAAAABFnZXRfcmVzYWxlX3BvbGljeQAAAAAAAAAAAAABAAAH0AAAAAxSZXNhbGVQb2xpY3k=",
+            "AAAAAAAAAM5TZWxsIGEgdGllciBhdCBhIGRlc2NlbmRpbmcgcHJpY2UgaW5zdGVhZCBvZiBpdHMgZml4ZWQgcHJpY2UgKGV2ZW50CmNyZWF0b3Igb25seSkuIE11c3QgYmUgc2V0IGJlZm9yZSBhbnkgdGlja2V0IGlzIHNvbGQuIFJlYmF0ZXMgbmVlZCB0aGUKcGF5bWVudHMgYXQgaGFuZCwgc28gdGhleSBhbHNvIGhvbGQgYWxsIHByaW1hcnkgcGF5bWVudHMgaW4gZXNjcm93LgAAAAAAEXNldF9kdXRjaF9hdWN0aW9uAAAAAAAAAgAAAAAAAAAHY3JlYXRvcgAAAAATAAAAAAAAAAdhdWN0aW9uAAAAB9AAAAAMRHV0Y2hBdWN0aW9uAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+            "AAAAAAAAAIRTZXQgdGhlIGxpbWl0cyBvbiBzZWNvbmRhcnkgbGlzdGluZyBwcmljZXMgKGV2ZW50IGNyZWF0b3Igb25seSkuCkxpc3RpbmdzIG1hZGUgYmVmb3JlIGEgY2hhbmdlIGtlZXAgdGhlaXIgcHJpY2UgdW50aWwgaXQgaXMgdXBkYXRlZC4AAAARc2V0X3Jlc2FsZV9wb2xpY3kAAAAAAAACAAAAAAAAAAdjcmVhdG9yAAAAABMAAAAAAAAABnBvbGljeQAAAAAH0AAAAAxSZXNhbGVQb2xpY3kAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+            "AAAAAAAAAJhXaXRoZHJhdyB0aGUgZXNjcm93ZWQgcHJvY2VlZHMgcmVsZWFzZWQgc28gZmFyIChldmVudCBjcmVhdG9yIG9ubHkpLApsZXNzIHRoZSBwcm90b2NvbCdzIHNoYXJlIG9mIHByaW1hcnkgc2FsZXMuIFJldHVybnMgdGhlIGFtb3VudCBwYWlkIHRvCnRoZSBjcmVhdG9yLgAAABF3aXRoZHJhd19wcm9jZWVkcwAAAAAAAAEAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAEAAAPpAAAACwAAAAM=",
+            "AAAAAAAAAIBHZXQgdGhlIER1dGNoIGF1Y3Rpb24ncyBjbGVhcmluZyBwcmljZSwgdGhlIHByaWNlIG9mIHRoZSBsYXN0IHRpY2tldApzb2xkLiBJdCBpcyBrbm93biBvbmNlIHRoZSB0aWVyIHNlbGxzIG91dCBvciB0aGUgc2FsZSBlbmRzLgAAABJnZXRfY2xlYXJpbmdfcHJpY2UAAAAAAAAAAAABAAAD6QAAA+gAAAALAAAAAw==",
+            "AAAAAAAAABpHZXQgYSBjb2xsZWN0aW9uIGJpZCBieSBpZAAAAAAAEmdldF9jb2xsZWN0aW9uX2JpZAAAAAAAAQAAAAAAAAAGYmlkX2lkAAAAAAAEAAAAAQAAA+kAAAfQAAAADUNvbGxlY3Rpb25CaWQAAAAAAAAD",
+            "AAAAAAAAAD1HZXQgdGhlIHByaW1hcnkgc2FsZSBwYXltZW50cyBoZWxkIGluIGVzY3JvdywgcmVsZWFzZWQgb3Igbm90AAAAAAAAEmdldF9lc2Nyb3dfYmFsYW5jZQAAAAAAAAAAAAEAAAAL",
+            "AAAAAAAAAEZHZXQgdGhlIGV2ZW50LXdpZGUgY2FwIG9uIHByaW1hcnkgcHVyY2hhc2VzIHBlciBhZGRyZXNzICgwIGZvciBubyBjYXApAAAAAAASZ2V0X21heF9wZXJfd2FsbGV0AAAAAAAAAAAAAQAAAAQ=",
             "AAAAAAAAABxHZXQgbnVtYmVyIG9mIHRpY2tldHMgbWludGVkAAAAEmdldF90aWNrZXRzX21pbnRlZAAAAAAAAAAAAAEAAAAE",
-            "AAAAAAAAACVCdXkgdGlja2V0IGZyb20gc2Vjb25kYXJ5IG1hcmtldHBsYWNlAAAAAAAAFGJ1eV9zZWNvbmRhcnlfdGlja2V0AAAAAgAAAAAAAAAFYnV5ZXIAAAAAAAATAAAAAAAAAAl0aWNrZXRfaWQAAAAAAAAEAAAAAA==",
-            "AAAAAAAAABRVcGRhdGUgbGlzdGluZyBwcmljZQAAABR1cGRhdGVfbGlzdGluZ19wcmljZQAAAAMAAAAAAAAABnNlbGxlcgAAAAAAEwAAAAAAAAAJdGlja2V0X2lkAAAAAAAABAAAAAAAAAAJbmV3X3ByaWNlAAAAAAAACwAAAAA=",
+            "AAAAAAAAAEFHZXQgbnVtYmVyIG9mIHRpY2tldHMgb2YgYSB0aWVyIHN0aWxsIGF2YWlsYWJsZSBpbiBwcmltYXJ5IG1hcmtldAAAAAAAABJnZXRfdGllcl9hdmFpbGFibGUAAAAAAAEAAAAAAAAAB3RpZXJfaWQAAAAABAAAAAEAAAPpAAAABAAAAAM=",
+            "AAAAAAAAAC1DaGVjayB3aGV0aGVyIHRpY2tldCBtZXRhZGF0YSBoYXMgYmVlbiBmcm96ZW4AAAAAAAASaXNfbWV0YWRhdGFfZnJvemVuAAAAAAAAAAAAAQAAAAE=",
+            "AAAAAAAAAHxDYXAgaG93IG1hbnkgdGlja2V0cyBvbmUgYWRkcmVzcyBtYXkgYnV5IG9uIHRoZSBwcmltYXJ5IG1hcmtldCBhY3Jvc3MKYWxsIHRpZXJzLCBvciBsaWZ0IHRoZSBjYXAgd2l0aCAwIChldmVudCBjcmVhdG9yIG9ubHkpAAAAEnNldF9tYXhfcGVyX3dhbGxldAAAAAAAAgAAAAAAAAAHY3JlYXRvcgAAAAATAAAAAAAAAA5tYXhfcGVyX3dhbGxldAAAAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+            "AAAAAAAAAMlTcGxpdCB0aGUgY3JlYXRvciBmZWUgb24gc2Vjb25kYXJ5IHNhbGVzIGJldHdlZW4gc2V2ZXJhbCByZWNpcGllbnRzCihldmVudCBjcmVhdG9yIG9ubHkpLiBTaGFyZXMgYXJlIGluIGJhc2lzIHBvaW50cyBvZiB0aGUgZmVlIGFuZCBtYXkKdG90YWwgYXQgbW9zdCAxMDAwMDsgdGhlIHJlbWFpbmRlciBnb2VzIHRvIGByZW1haW5kZXJfcmVjaXBpZW50YC4AAAAAAAASc2V0X3JveWFsdHlfc3BsaXRzAAAAAAADAAAAAAAAAAdjcmVhdG9yAAAAABMAAAAAAAAABnNwbGl0cwAAAAAD6gAAB9AAAAAMUm95YWx0eVNwbGl0AAAAAAAAABNyZW1haW5kZXJfcmVjaXBpZW50AAAAABMAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+            "AAAAAAAAAIBTZWxsIGFuIHVudXNlZCwgdW5saXN0ZWQgdGlja2V0IGludG8gYSBjb2xsZWN0aW9uIGJpZCAodGlja2V0IG93bmVyCm9ubHkpLiBUaGUgZXNjcm93ZWQgcHJpY2UgaXMgcGFpZCBvdXQgbGlrZSBhIHNlY29uZGFyeSBzYWxlLgAAABNmaWxsX2NvbGxlY3Rpb25fYmlkAAAAAAMAAAAAAAAABnNlbGxlcgAAAAAAEwAAAAAAAAAGYmlkX2lkAAAAAAAEAAAAAAAAAAl0aWNrZXRfaWQAAAAAAAAEAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+            "AAAAAAAAAD9HZXQgdGhlIG9wZW4gY29sbGVjdGlvbiBiaWRzIGFtb25nIGBsaW1pdGAgYmlkIGlkcyBmcm9tIGBzdGFydGAAAAAAE2dldF9jb2xsZWN0aW9uX2JpZHMAAAAAAgAAAAAAAAAFc3RhcnQAAAAAAAAEAAAAAAAAAAVsaW1pdAAAAAAAAAQAAAABAAAD6gAAB9AAAAANQ29sbGVjdGlvbkJpZAAAAA==",
+            "AAAAAAAAAEFDaGVjayB3aGV0aGVyIGFuIG9wZXJhdG9yIG1heSB0cmFuc2ZlciBhbGwgb2YgdGhlIG93bmVyJ3MgdGlja2V0cwAAAAAAABNpc19hcHByb3ZlZF9mb3JfYWxsAAAAAAIAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAIb3BlcmF0b3IAAAATAAAAAQAAAAE=",
+            "AAAAAAAAACVCdXkgdGlja2V0IGZyb20gc2Vjb25kYXJ5IG1hcmtldHBsYWNlAAAAAAAAFGJ1eV9zZWNvbmRhcnlfdGlja2V0AAAAAgAAAAAAAAAFYnV5ZXIAAAAAAAATAAAAAAAAAAl0aWNrZXRfaWQAAAAAAAAEAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+            "AAAAAAAAADNHZXQgdGhlIHBheW1lbnQgaGVsZCBpbiBlc2Nyb3cgZm9yIGEgdGlja2V0LCBpZiBhbnkAAAAAFGdldF9lc2Nyb3dlZF9wYXltZW50AAAAAQAAAAAAAAAJdGlja2V0X2lkAAAAAAAABAAAAAEAAAPoAAAACw==",
+            "AAAAAAAAAEFHZXQgdGhlIGhpZ2hlc3QgcHJpY2UgYSB0aWNrZXQgbWF5IGJlIGxpc3RlZCBmb3IsIGlmIGl0IGlzIGNhcHBlZAAAAAAAABRnZXRfbWF4X3Jlc2FsZV9wcmljZQAAAAEAAAAAAAAACXRpY2tldF9pZAAAAAAAAAQAAAABAAAD6QAAA+gAAAALAAAAAw==",
+            "AAAAAAAAAINHZXQgaG93IG1hbnkgdGlja2V0cyBvZiBhIHRpZXIgdGhlIGJ1eWVyIG1heSBzdGlsbCBidXkgb24gdGhlIHByaW1hcnkKbWFya2V0LCBnaXZlbiB0aGUgd2FsbGV0IGNhcHMgYW5kIHRoZSB0aWVyJ3MgcmVtYWluaW5nIHN1cHBseQAAAAAUZ2V0X3dhbGxldF9hbGxvd2FuY2UAAAACAAAAAAAAAAVidXllcgAAAAAAABMAAAAAAAAAB3RpZXJfaWQAAAAABAAAAAEAAAPpAAAABAAAAAM=",
+            "AAAAAAAAAIBCaWQgZm9yIGBxdWFudGl0eWAgdGlja2V0cyBvZiBhbnkgdGllciwgb3Igb2YgYHRpZXJfaWRgLCBhdCBgcHJpY2VgCmVhY2gsIGhvbGRpbmcgdGhlIGZ1bGwgYW1vdW50IGluIGVzY3Jvdy4gUmV0dXJucyB0aGUgYmlkIGlkLgAAABRwbGFjZV9jb2xsZWN0aW9uX2JpZAAAAAQAAAAAAAAABWJ1eWVyAAAAAAAAEwAAAAAAAAAHdGllcl9pZAAAAAPoAAAABAAAAAAAAAAFcHJpY2UAAAAAAAALAAAAAAAAAAhxdWFudGl0eQAAAAQAAAABAAAD6QAAAAQAAAAD",
+            "AAAAAAAAAEhHcmFudCBvciByZXZva2UgYW4gb3BlcmF0b3IgdGhhdCBtYXkgdHJhbnNmZXIgYWxsIG9mIHRoZSBvd25lcidzIHRpY2tldHMAAAAUc2V0X2FwcHJvdmFsX2Zvcl9hbGwAAAADAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAACG9wZXJhdG9yAAAAEwAAAAAAAAAIYXBwcm92ZWQAAAABAAAAAA==",
+            "AAAAAAAAABRVcGRhdGUgbGlzdGluZyBwcmljZQAAABR1cGRhdGVfbGlzdGluZ19wcmljZQAAAAMAAAAAAAAABnNlbGxlcgAAAAAAEwAAAAAAAAAJdGlja2V0X2lkAAAAAAAABAAAAAAAAAAJbmV3X3ByaWNlAAAAAAAACwAAAAEAAAPpAAAD7QAAAAAAAAAD",
+            "AAAAAAAAAFhXaXRoZHJhdyBhIGNvbGxlY3Rpb24gYmlkIGFuZCBnZXQgdGhlIGVzY3JvdyBmb3IgaXRzIHVuZmlsbGVkIHRpY2tldHMKYmFjayAoYmlkZGVyIG9ubHkpAAAAFWNhbmNlbF9jb2xsZWN0aW9uX2JpZAAAAAAAAAEAAAAAAAAABmJpZF9pZAAAAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+            "AAAAAAAAADJHZXQgaG93IG1hbnkgcHJlc2FsZSB0aWNrZXRzIGFuIGFkZHJlc3MgaGFzIGJvdWdodAAAAAAAFWdldF9wcmVzYWxlX3B1cmNoYXNlZAAAAAAAAAEAAAAAAAAABWJ1eWVyAAAAAAAAEwAAAAEAAAAE",
             "AAAAAAAAABVHZXQgc2Vjb25kYXJ5IGxpc3RpbmcAAAAAAAAVZ2V0X3NlY29uZGFyeV9saXN0aW5nAAAAAAAAAQAAAAAAAAAJdGlja2V0X2lkAAAAAAAABAAAAAEAAAPoAAAH0AAAABBTZWNvbmRhcnlMaXN0aW5n",
-            "AAAAAAAAADFHZXQgbnVtYmVyIG9mIHRpY2tldHMgYXZhaWxhYmxlIGluIHByaW1hcnkgbWFya2V0AAAAAAAAFWdldF90aWNrZXRzX2F2YWlsYWJsZQAAAAAAAAAAAAABAAAABA==",
-            "AAAAAAAAABpHZXQgYWxsIHNlY29uZGFyeSBsaXN0aW5ncwAAAAAAGmdldF9hbGxfc2Vjb25kYXJ5X2xpc3RpbmdzAAAAAAAAAAAAAQAAA+oAAAfQAAAAEFNlY29uZGFyeUxpc3Rpbmc="]), options);
+            "AAAAAAAAADFHZXQgbnVtYmVyIG9mIHRpY2tldHMgYXZhaWxhYmxlIGluIHByaW1hcnkgbWFya2V0AAAAAAAAFWdldF90aWNrZXRzX2F2YWlsYWJsZQAAAAAAAAAAAAABAAAD6QAAAAQAAAAD",
+            "AAAAAAAAACVHZXQgd2hvIG1heSB1cGdyYWRlIHRoZSBjb250cmFjdCBjb2RlAAAAAAAAFWdldF91cGdyYWRlX2F1dGhvcml0eQAAAAAAAAAAAAABAAAH0AAAABBVcGdyYWRlQXV0aG9yaXR5",
+            "AAAAAAAAALtIYW5kIHRoZSByaWdodCB0byB1cGdyYWRlIHRvIHRoZSBmYWN0b3J5IG9yIHRoZSBjcmVhdG9yLiBUaGUgY3VycmVudAp1cGdyYWRlIGF1dGhvcml0eSBtdXN0IGF1dGhvcml6ZSB0aGUgY2hhbmdlOyB0aGUgZmFjdG9yeSBkb2VzIHNvCnRocm91Z2ggaXRzIGFkbWluLW9ubHkgYHNldF9ldmVudF91cGdyYWRlX2F1dGhvcml0eWAuAAAAABVzZXRfdXBncmFkZV9hdXRob3JpdHkAAAAAAAABAAAAAAAAAAlhdXRob3JpdHkAAAAAAAfQAAAAEFVwZ3JhZGVBdXRob3JpdHkAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+            "AAAAAAAAAFFHZXQgdGhlIGVzY3Jvd2VkIHByb2NlZWRzIHRoZSBjcmVhdG9yIGNvdWxkIHdpdGhkcmF3IG5vdywgYmVmb3JlIHRoZQpwcm90b2NvbCBmZWUAAAAAAAAZZ2V0X3dpdGhkcmF3YWJsZV9wcm9jZWVkcwAAAAAAAAAAAAABAAAD6QAAAAsAAAAD",
+            "AAAAAAAAAHZHZXQgdGhlIHNlY29uZGFyeSBsaXN0aW5ncyB0aGF0IGNhbiBzdGlsbCBiZSBib3VnaHQgYW1vbmcgYGxpbWl0YAp0aWNrZXQgaWRzIGZyb20gYHN0YXJ0YC4gQSBjYW5jZWxsZWQgZXZlbnQgaGFzIG5vbmUuAAAAAAAaZ2V0X2FsbF9zZWNvbmRhcnlfbGlzdGluZ3MAAAAAAAIAAAAAAAAABXN0YXJ0AAAAAAAABAAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAA+oAAAfQAAAAEFNlY29uZGFyeUxpc3Rpbmc="]), options);
         this.options = options;
     }
     fromJSON = {
+        bid: (this.txFromJSON),
         init: (this.txFromJSON),
         name: (this.txFromJSON),
         symbol: (this.txFromJSON),
+        approve: (this.txFromJSON),
+        balance: (this.txFromJSON),
+        upgrade: (this.txFromJSON),
+        version: (this.txFromJSON),
+        get_tier: (this.txFromJSON),
+        owner_of: (this.txFromJSON),
+        get_offer: (this.txFromJSON),
+        get_tiers: (this.txFromJSON),
+        token_uri: (this.txFromJSON),
+        get_offers: (this.txFromJSON),
         get_ticket: (this.txFromJSON),
+        is_on_sale: (this.txFromJSON),
+        make_offer: (this.txFromJSON),
+        set_escrow: (this.txFromJSON),
+        get_auction: (this.txFromJSON),
+        get_factory: (this.txFromJSON),
+        get_presale: (this.txFromJSON),
+        is_escrowed: (this.txFromJSON),
         list_ticket: (this.txFromJSON),
         mint_ticket: (this.txFromJSON),
+        set_factory: (this.txFromJSON),
+        set_presale: (this.txFromJSON),
+        accept_offer: (this.txFromJSON),
+        cancel_event: (this.txFromJSON),
+        cancel_offer: (this.txFromJSON),
+        claim_rebate: (this.txFromJSON),
+        claim_refund: (this.txFromJSON),
+        get_approved: (this.txFromJSON),
+        get_base_uri: (this.txFromJSON),
+        is_cancelled: (this.txFromJSON),
+        mint_presale: (this.txFromJSON),
+        mint_tickets: (this.txFromJSON),
+        set_base_uri: (this.txFromJSON),
+        claim_balance: (this.txFromJSON),
         delist_ticket: (this.txFromJSON),
+        get_claimable: (this.txFromJSON),
+        get_purchased: (this.txFromJSON),
+        start_auction: (this.txFromJSON),
+        transfer_from: (this.txFromJSON),
         get_event_info: (this.txFromJSON),
+        get_ttl_policy: (this.txFromJSON),
+        set_ticket_uri: (this.txFromJSON),
+        set_ttl_policy: (this.txFromJSON),
+        settle_auction: (this.txFromJSON),
+        freeze_metadata: (this.txFromJSON),
         transfer_ticket: (this.txFromJSON),
+        extend_event_ttl: (this.txFromJSON),
+        get_all_auctions: (this.txFromJSON),
+        get_protocol_fee: (this.txFromJSON),
         get_user_tickets: (this.txFromJSON),
         mark_ticket_used: (this.txFromJSON),
+        extend_ticket_ttl: (this.txFromJSON),
+        get_current_price: (this.txFromJSON),
+        get_dutch_auction: (this.txFromJSON),
+        get_resale_policy: (this.txFromJSON),
+        set_dutch_auction: (this.txFromJSON),
+        set_resale_policy: (this.txFromJSON),
+        withdraw_proceeds: (this.txFromJSON),
+        get_clearing_price: (this.txFromJSON),
+        get_collection_bid: (this.txFromJSON),
+        get_escrow_balance: (this.txFromJSON),
+        get_max_per_wallet: (this.txFromJSON),
         get_tickets_minted: (this.txFromJSON),
+        get_tier_available: (this.txFromJSON),
+        is_metadata_frozen: (this.txFromJSON),
+        set_max_per_wallet: (this.txFromJSON),
+        set_royalty_splits: (this.txFromJSON),
+        fill_collection_bid: (this.txFromJSON),
+        get_collection_bids: (this.txFromJSON),
+        is_approved_for_all: (this.txFromJSON),
         buy_secondary_ticket: (this.txFromJSON),
+        get_escrowed_payment: (this.txFromJSON),
+        get_max_resale_price: (this.txFromJSON),
+        get_wallet_allowance: (this.txFromJSON),
+        place_collection_bid: (this.txFromJSON),
+        set_approval_for_all: (this.txFromJSON),
         update_listing_price: (this.txFromJSON),
+        cancel_collection_bid: (this.txFromJSON),
+        get_presale_purchased: (this.txFromJSON),
         get_secondary_listing: (this.txFromJSON),
         get_tickets_available: (this.txFromJSON),
+        get_upgrade_authority: (this.txFromJSON),
+        set_upgrade_authority: (this.txFromJSON),
+        get_withdrawable_proceeds: (this.txFromJSON),
         get_all_secondary_listings: (this.txFromJSON)
     };
 }
@@ -39,7 +39,86 @@ export const networks = {
   }
 } as const
 
-export type DataKey = {tag: "EventInfo", values: void} | {tag: "Ticket", values: readonly [u32]} | {tag: "TicketsMinted", values: void} | {tag: "SecondaryListing", values: readonly [u32]} | {tag: "UserTickets", values: readonly [string]};
+
+/**
+ * A tier and its primary sales so far. Tier ids are positions in the list
+ * passed to `init`, starting at 0.
+ */
+export interface Tier {
+  creator_fee_bps: u32;
+  max_per_wallet: u32;
+  minted: u32;
+  name: string;
+  price: i128;
+  supply: u32;
+  tier_id: u32;
+}
+
+export const Errors = {
+  1: {message:"AlreadyInitialized"},
+  2: {message:"NotInitialized"},
+  3: {message:"InvalidSupply"},
+  4: {message:"InvalidPrice"},
+  5: {message:"InvalidFeeBps"},
+  6: {message:"SoldOut"},
+  7: {message:"TicketNotFound"},
+  8: {message:"NotOwner"},
+  9: {message:"TicketUsed"},
+  10: {message:"AlreadyListed"},
+  11: {message:"NotListed"},
+  12: {message:"NotSeller"},
+  13: {message:"NotCreator"},
+  14: {message:"InvalidTtlPolicy"},
+  15: {message:"NotApproved"},
+  16: {message:"MetadataFrozen"},
+  17: {message:"InvalidUri"},
+  18: {message:"TierNotFound"},
+  19: {message:"TooManyTiers"},
+  20: {message:"InvalidQuantity"},
+  21: {message:"WalletLimitReached"},
+  22: {message:"InvalidSchedule"},
+  23: {message:"SaleNotStarted"},
+  24: {message:"SaleEnded"},
+  25: {message:"InvalidPresale"},
+  26: {message:"PresaleNotActive"},
+  27: {message:"NotOnAllowlist"},
+  28: {message:"PriceAboveCap"},
+  29: {message:"PriceBelowFloor"},
+  30: {message:"InvalidResalePolicy"},
+  31: {message:"InvalidRoyaltySplits"},
+  32: {message:"FactoryAlreadySet"},
+  33: {message:"NoFactory"},
+  34: {message:"TicketsAlreadySold"},
+  35: {message:"EventCancelled"},
+  36: {message:"EventNotCancelled"},
+  37: {message:"NotRefundable"},
+  38: {message:"EventEnded"},
+  39: {message:"ProceedsLocked"},
+  40: {message:"NoProceeds"},
+  41: {message:"InvalidExpiry"},
+  42: {message:"OfferExists"},
+  43: {message:"OfferNotFound"},
+  44: {message:"OfferExpired"},
+  45: {message:"BidNotFound"},
+  46: {message:"TierMismatch"},
+  47: {message:"ListingExpired"},
+  48: {message:"AuctionNotFound"},
+  49: {message:"AuctionNotEnded"},
+  50: {message:"BidTooLow"}
+}
+
+/**
+ * An English auction of a ticket. The highest bid is held in escrow by the
+ * contract, and bids must reach `reserve` before `end_time`.
+ */
+export interface Auction {
+  end_time: u64;
+  highest_bid: i128;
+  highest_bidder: Option<string>;
+  reserve: i128;
+  seller: string;
+  ticket_id: u32;
+}
 
 
 export interface EventInfo {
@@ -49,78 +128,538 @@ export interface EventInfo {
   name: string;
   payment_token: string;
   primary_price: i128;
+  royalty_remainder_recipient: string;
+  royalty_splits: Array<RoyaltySplit>;
+  schedule: EventSchedule;
   symbol: string;
   total_supply: u32;
 }
 
 
+/**
+ * How long storage is kept alive whenever it is touched. Entries are extended
+ * by at least `min_extension` ledgers, and far enough to outlive
+ * `retain_until` (a ledger timestamp). Until the creator sets a policy,
+ * `retain_until` is the event's `event_end`.
+ */
+export interface TtlPolicy {
+  min_extension: u32;
+  retain_until: u64;
+}
+
+
 export interface TicketData {
   is_used: boolean;
   owner: string;
   ticket_id: u32;
+  tier_id: u32;
+}
+
+
+/**
+ * A tier as configured at deployment. `max_per_wallet` caps how many
+ * tickets of the tier one address may buy on the primary market (0 for no cap).
+ */
+export interface TierConfig {
+  creator_fee_bps: u32;
+  max_per_wallet: u32;
+  name: string;
+  price: i128;
+  supply: u32;
+}
+
+
+/**
+ * The platform's cut of sales, set by the deploying factory. `primary_bps`
+ * is taken from primary sales and `secondary_bps` from resales, on top of
+ * the creator fee, and both are paid to `treasury`.
+ */
+export interface ProtocolFee {
+  primary_bps: u32;
+  secondary_bps: u32;
+  treasury: string;
+}
+
+
+/**
+ * A buyer's offer for a ticket, with the price held in escrow by the
+ * contract. It can be accepted by whoever owns the ticket until `expiry`
+ * (a ledger timestamp).
+ */
+export interface TicketOffer {
+  buyer: string;
+  expiry: u64;
+  price: i128;
+  ticket_id: u32;
+}
+
+
+/**
+ * A descending-price primary sale of one tier. From `sale_start` the price
+ * drops by `price_drop` every `drop_interval` seconds until it reaches
+ * `floor_price`. With `rebate`, buyers get back what they paid above the
+ * clearing price, the price of the last ticket sold.
+ */
+export interface DutchAuction {
+  drop_interval: u64;
+  floor_price: i128;
+  price_drop: i128;
+  rebate: boolean;
+  start_price: i128;
+  tier_id: u32;
+}
+
+
+/**
+ * Limits on secondary listing prices. `max_price` is an absolute cap and
+ * `max_markup_bps` a cap relative to the ticket's tier price (10000 is face
+ * value); when both are set the lower one applies. `min_price` is a floor.
+ * Zero disables a limit.
+ */
+export interface ResalePolicy {
+  max_markup_bps: u32;
+  max_price: i128;
+  min_price: i128;
 }
 
 
+/**
+ * A share of the creator fee on secondary sales, in basis points of the fee.
+ * Whatever the splits leave over, including rounding dust, goes to
+ * `EventInfo.royalty_remainder_recipient`.
+ */
+export interface RoyaltySplit {
+  bps: u32;
+  recipient: string;
+}
+
+
+/**
+ * A buyer's standing bid for up to `quantity` more tickets of the event, or
+ * of one tier, at `price` each. The price of every unfilled ticket is held
+ * in escrow by the contract.
+ */
+export interface CollectionBid {
+  bid_id: u32;
+  buyer: string;
+  price: i128;
+  quantity: u32;
+  tier_id: Option<u32>;
+}
+
+
+/**
+ * Ledger timestamps (seconds) bounding the primary sale and the event
+ * itself. Tickets can be minted from `sale_start` until just before
+ * `sale_end`, and the sale must close by the end of the event.
+ */
+export interface EventSchedule {
+  event_end: u64;
+  event_start: u64;
+  sale_end: u64;
+  sale_start: u64;
+}
+
+
+/**
+ * An allowlist sale of one tier that runs from `start` until the public
+ * sale opens. Eligible addresses prove membership with a Merkle proof
+ * against `merkle_root`, whose leaves are the SHA-256 of each address's XDR
+ * and whose pairs are hashed in sorted order.
+ */
+export interface PresaleConfig {
+  max_per_wallet: u32;
+  merkle_root: Buffer;
+  price: i128;
+  start: u64;
+  tier_id: u32;
+}
+
+
+/**
+ * A ticket for sale on the secondary market. Listings lapse at `expires_at`
+ * (a ledger timestamp), if set, and in any case once the event starts.
+ */
 export interface SecondaryListing {
+  expires_at: Option<u64>;
   price: i128;
   seller: string;
   ticket_id: u32;
 }
 
+/**
+ * Who may upgrade the contract code: the deploying factory (on behalf of its
+ * admin) or the event creator
+ */
+export type UpgradeAuthority = {tag: "Factory", values: void} | {tag: "Creator", values: void};
+
 export interface Client {
+  /**
+   * Construct and simulate a bid transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Bid on an auction, holding `amount` in escrow and crediting the
+   * previous highest bid to its bidder for `claim_balance`. Bids must
+   * meet the reserve and beat the highest bid by `MIN_BID_INCREMENT_BPS`,
+   * and a bid in the last `AUCTION_EXTENSION_SECONDS` pushes the end
+   * back, up to the event start.
+   */
+  bid: ({bidder, ticket_id, amount}: {bidder: string, ticket_id: u32, amount: i128}, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>
+
   /**
    * Construct and simulate a init transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Initialize the ticket marketplace contract
+   * Initialize the ticket marketplace contract. With an empty `tiers` list
+   * the event has a single tier built from `total_supply`, `primary_price`
+   * and `creator_fee_bps`; otherwise the tier supplies must add up to
+   * `total_supply`.
    */
-  init: ({event_creator, total_supply, primary_price, creator_fee_bps, event_metadata, name, symbol, payment_token}: {event_creator: string, total_supply: u32, primary_price: i128, creator_fee_bps: u32, event_metadata: string, name: string, symbol: string, payment_token: string}, options?: AssembledTransactionOptions<null>) => Promise<AssembledTransaction<null>>
+  init: ({event_creator, total_supply, primary_price, creator_fee_bps, event_metadata, name, symbol, payment_token, tiers, schedule}: {event_creator: string, total_supply: u32, primary_price: i128, creator_fee_bps: u32, event_metadata: string, name: string, symbol: string, payment_token: string, tiers: Array<TierConfig>, schedule: EventSchedule}, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>
 
   /**
    * Construct and simulate a name transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Get the name of the event/ticket collection
    */
-  name: (options?: AssembledTransactionOptions<string>) => Promise<AssembledTransaction<string>>
+  name: (options?: AssembledTransactionOptions<Result<string>>) => Promise<AssembledTransaction<Result<string>>>
 
   /**
    * Construct and simulate a symbol transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Get the symbol of the ticket
    */
-  symbol: (options?: AssembledTransactionOptions<string>) => Promise<AssembledTransaction<string>>
+  symbol: (options?: AssembledTransactionOptions<Result<string>>) => Promise<AssembledTransaction<Result<string>>>
+
+  /**
+   * Construct and simulate a approve transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Approve a spender for a single ticket, or clear the approval with `None`.
+   * The approver must be the owner or one of the owner's operators, and the
+   * approval is cleared whenever the ticket changes hands.
+   */
+  approve: ({approver, approved, ticket_id}: {approver: string, approved: Option<string>, ticket_id: u32}, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>
+
+  /**
+   * Construct and simulate a balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the number of tickets held by an address
+   */
+  balance: ({owner}: {owner: string}, options?: AssembledTransactionOptions<u32>) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Replace the contract code. Only the upgrade authority may do this: the
+   * deploying factory by default, or the event creator for events without
+   * a factory or that have opted out.
+   */
+  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>
+
+  /**
+   * Construct and simulate a version transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the version of the contract interface
+   */
+  version: (options?: AssembledTransactionOptions<u32>) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a get_tier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a single tier
+   */
+  get_tier: ({tier_id}: {tier_id: u32}, options?: AssembledTransactionOptions<Result<Tier>>) => Promise<AssembledTransaction<Result<Tier>>>
+
+  /**
+   * Construct and simulate a owner_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the current owner of a ticket
+   */
+  owner_of: ({ticket_id}: {ticket_id: u32}, options?: AssembledTransactionOptions<Result<string>>) => Promise<AssembledTransaction<Result<string>>>
+
+  /**
+   * Construct and simulate a get_offer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a buyer's offer for a ticket, if any
+   */
+  get_offer: ({ticket_id, buyer}: {ticket_id: u32, buyer: string}, options?: AssembledTransactionOptions<Option<TicketOffer>>) => Promise<AssembledTransaction<Option<TicketOffer>>>
+
+  /**
+   * Construct and simulate a get_tiers transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get all tiers with their primary sales so far
+   */
+  get_tiers: (options?: AssembledTransactionOptions<Result<Array<Tier>>>) => Promise<AssembledTransaction<Result<Array<Tier>>>>
+
+  /**
+   * Construct and simulate a token_uri transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the metadata URI of a ticket. A per-ticket override wins, then the
+   * base URI followed by the ticket id, then the event's metadata.
+   */
+  token_uri: ({ticket_id}: {ticket_id: u32}, options?: AssembledTransactionOptions<Result<string>>) => Promise<AssembledTransaction<Result<string>>>
+
+  /**
+   * Construct and simulate a get_offers transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get all offers for a ticket, including expired ones not yet cancelled
+   */
+  get_offers: ({ticket_id}: {ticket_id: u32}, options?: AssembledTransactionOptions<Array<TicketOffer>>) => Promise<AssembledTransaction<Array<TicketOffer>>>
 
   /**
    * Construct and simulate a get_ticket transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Get ticket information
    */
-  get_ticket: ({ticket_id}: {ticket_id: u32}, options?: AssembledTransactionOptions<TicketData>) => Promise<AssembledTransaction<TicketData>>
+  get_ticket: ({ticket_id}: {ticket_id: u32}, options?: AssembledTransactionOptions<Result<TicketData>>) => Promise<AssembledTransaction<Result<TicketData>>>
+
+  /**
+   * Construct and simulate a is_on_sale transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check whether the primary sale is open at the current ledger time
+   */
+  is_on_sale: (options?: AssembledTransactionOptions<Result<boolean>>) => Promise<AssembledTransaction<Result<boolean>>>
+
+  /**
+   * Construct and simulate a make_offer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Offer to buy a ticket, listed or not, holding `price` in escrow until
+   * the offer is accepted or cancelled. One offer per buyer and ticket,
+   * and `OfferExists` once the ticket has `MAX_OFFERS_PER_TICKET` offers.
+   */
+  make_offer: ({buyer, ticket_id, price, expiry}: {buyer: string, ticket_id: u32, price: i128, expiry: u64}, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>
+
+  /**
+   * Construct and simulate a set_escrow transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Hold primary sale payments in the contract instead of paying the
+   * creator straight away, so buyers can be refunded if the event is
+   * cancelled (event creator only). Must be chosen before any ticket is sold.
+   * The payment for a ticket is released when it is checked in, and
+   * everything still held is released once the event ends.
+   */
+  set_escrow: ({creator, enabled}: {creator: string, enabled: boolean}, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>
+
+  /**
+   * Construct and simulate a get_auction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the auction of a ticket
+   */
+  get_auction: ({ticket_id}: {ticket_id: u32}, options?: AssembledTransactionOptions<Result<Auction>>) => Promise<AssembledTransaction<Result<Auction>>>
+
+  /**
+   * Construct and simulate a get_factory transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the factory that deployed this event, if any
+   */
+  get_factory: (options?: AssembledTransactionOptions<Option<string>>) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a get_presale transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the presale configuration, if any
+   */
+  get_presale: (options?: AssembledTransactionOptions<Option<PresaleConfig>>) => Promise<AssembledTransaction<Option<PresaleConfig>>>
+
+  /**
+   * Construct and simulate a is_escrowed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check whether primary sale payments are held in escrow
+   */
+  is_escrowed: (options?: AssembledTransactionOptions<boolean>) => Promise<AssembledTransaction<boolean>>
 
   /**
    * Construct and simulate a list_ticket transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * List ticket on secondary marketplace
+   * List ticket on secondary marketplace, optionally until `expires_at`.
+   * A lapsed listing of the ticket is replaced.
    */
-  list_ticket: ({seller, ticket_id, price}: {seller: string, ticket_id: u32, price: i128}, options?: AssembledTransactionOptions<null>) => Promise<AssembledTransaction<null>>
+  list_ticket: ({seller, ticket_id, price, expires_at}: {seller: string, ticket_id: u32, price: i128, expires_at: Option<u64>}, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>
 
   /**
    * Construct and simulate a mint_ticket transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Mint a ticket from primary marketplace (buy from event creator)
+   * Mint a ticket of the given tier from primary marketplace (buy from event creator)
+   */
+  mint_ticket: ({buyer, tier_id}: {buyer: string, tier_id: u32}, options?: AssembledTransactionOptions<Result<u32>>) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a set_factory transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Record the factory that deployed this event and the protocol fee it
+   * charges. Both the factory and the event creator must authorize this,
+   * and it can only be done once, before any ticket is sold.
+   */
+  set_factory: ({factory, protocol_fee}: {factory: string, protocol_fee: ProtocolFee}, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>
+
+  /**
+   * Construct and simulate a set_presale transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Configure the allowlist presale, or replace its root, price or cap
+   * (event creator only). The presale has to start before the public sale.
+   */
+  set_presale: ({creator, presale}: {creator: string, presale: PresaleConfig}, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>
+
+  /**
+   * Construct and simulate a accept_offer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Accept an unexpired offer for a ticket (current owner only). The
+   * escrowed price is paid out like a secondary sale and any listing of
+   * the ticket is withdrawn.
+   */
+  accept_offer: ({seller, ticket_id, buyer}: {seller: string, ticket_id: u32, buyer: string}, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>
+
+  /**
+   * Construct and simulate a cancel_event transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Cancel the event before it ends (event creator only). Minting, trading,
+   * transfers and check-in stop for good, and holders of escrowed tickets
+   * can claim their payment back with `claim_refund`.
+   */
+  cancel_event: ({creator}: {creator: string}, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>
+
+  /**
+   * Construct and simulate a cancel_offer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Withdraw an offer and get the escrowed price back. This is also how
+   * buyers recover expired offers or offers on tickets that can no longer
+   * be sold.
+   */
+  cancel_offer: ({buyer, ticket_id}: {buyer: string, ticket_id: u32}, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>
+
+  /**
+   * Construct and simulate a claim_rebate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Pay a Dutch auction buyer back what they paid for a ticket above the
+   * clearing price (original buyer only), once. Rebates stay claimable
+   * after the ticket is resold or checked in and after the creator has
+   * withdrawn the proceeds.
+   */
+  claim_rebate: ({ticket_id}: {ticket_id: u32}, options?: AssembledTransactionOptions<Result<i128>>) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a claim_refund transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Refund the price paid for a ticket of a cancelled event to its owner.
+   * Only unused tickets bought with escrow enabled are refundable, once each.
+   */
+  claim_refund: ({ticket_id}: {ticket_id: u32}, options?: AssembledTransactionOptions<Result<i128>>) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a get_approved transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the address approved to transfer a single ticket
+   */
+  get_approved: ({ticket_id}: {ticket_id: u32}, options?: AssembledTransactionOptions<Result<Option<string>>>) => Promise<AssembledTransaction<Result<Option<string>>>>
+
+  /**
+   * Construct and simulate a get_base_uri transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the base URI, if one has been set
+   */
+  get_base_uri: (options?: AssembledTransactionOptions<Option<string>>) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a is_cancelled transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check whether the event has been cancelled
    */
-  mint_ticket: ({buyer}: {buyer: string}, options?: AssembledTransactionOptions<u32>) => Promise<AssembledTransaction<u32>>
+  is_cancelled: (options?: AssembledTransactionOptions<boolean>) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a mint_presale transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Mint presale tickets at the presale price. The buyer proves they are on
+   * the allowlist with the sibling hashes from their leaf up to the root.
+   */
+  mint_presale: ({buyer, quantity, proof}: {buyer: string, quantity: u32, proof: Array<Buffer>}, options?: AssembledTransactionOptions<Result<Array<u32>>>) => Promise<AssembledTransaction<Result<Array<u32>>>>
+
+  /**
+   * Construct and simulate a mint_tickets transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Mint several tickets of one tier in a single purchase. The buyer pays
+   * once for the whole batch and receives consecutive ticket ids; if the
+   * tier cannot cover the full quantity nothing is minted.
+   */
+  mint_tickets: ({buyer, tier_id, quantity}: {buyer: string, tier_id: u32, quantity: u32}, options?: AssembledTransactionOptions<Result<Array<u32>>>) => Promise<AssembledTransaction<Result<Array<u32>>>>
+
+  /**
+   * Construct and simulate a set_base_uri transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the base URI that ticket ids are appended to (event creator only)
+   */
+  set_base_uri: ({creator, base_uri}: {creator: string, base_uri: string}, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>
+
+  /**
+   * Construct and simulate a claim_balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Pay out what an address is owed from auctions: bids that were outbid
+   * or refunded, and the seller's share of settled auctions
+   */
+  claim_balance: ({owner}: {owner: string}, options?: AssembledTransactionOptions<Result<i128>>) => Promise<AssembledTransaction<Result<i128>>>
 
   /**
    * Construct and simulate a delist_ticket transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Delist ticket from secondary marketplace
    */
-  delist_ticket: ({seller, ticket_id}: {seller: string, ticket_id: u32}, options?: AssembledTransactionOptions<null>) => Promise<AssembledTransaction<null>>
+  delist_ticket: ({seller, ticket_id}: {seller: string, ticket_id: u32}, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>
+
+  /**
+   * Construct and simulate a get_claimable transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get what an address can collect with `claim_balance`
+   */
+  get_claimable: ({owner}: {owner: string}, options?: AssembledTransactionOptions<i128>) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_purchased transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get how many tickets an address has bought on the primary market.
+   * Transfers and resales do not reduce this count.
+   */
+  get_purchased: ({buyer}: {buyer: string}, options?: AssembledTransactionOptions<u32>) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a start_auction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Put an unused, unlisted ticket up for auction until `end_time`, which
+   * must be no later than the start of the event (ticket owner only).
+   * Auctioned tickets count as listed until the auction is settled.
+   */
+  start_auction: ({ticket_id, reserve, end_time}: {ticket_id: u32, reserve: i128, end_time: u64}, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>
+
+  /**
+   * Construct and simulate a transfer_from transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Transfer a ticket on behalf of its owner. The spender must be the owner,
+   * the ticket's approved address or an operator of the owner. Used and
+   * listed tickets cannot be transferred, as with `transfer_ticket`.
+   */
+  transfer_from: ({spender, from, to, ticket_id}: {spender: string, from: string, to: string, ticket_id: u32}, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>
 
   /**
    * Construct and simulate a get_event_info transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Get event information
    */
-  get_event_info: (options?: AssembledTransactionOptions<EventInfo>) => Promise<AssembledTransaction<EventInfo>>
+  get_event_info: (options?: AssembledTransactionOptions<Result<EventInfo>>) => Promise<AssembledTransaction<Result<EventInfo>>>
+
+  /**
+   * Construct and simulate a get_ttl_policy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the storage TTL policy
+   */
+  get_ttl_policy: (options?: AssembledTransactionOptions<TtlPolicy>) => Promise<AssembledTransaction<TtlPolicy>>
+
+  /**
+   * Construct and simulate a set_ticket_uri transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set or clear a ticket's own metadata URI (event creator only). Tickets
+   * that have not been minted yet can be given seat-specific metadata too.
+   */
+  set_ticket_uri: ({creator, ticket_id, uri}: {creator: string, ticket_id: u32, uri: Option<string>}, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>
+
+  /**
+   * Construct and simulate a set_ttl_policy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set how long event storage is kept alive (event creator only)
+   */
+  set_ttl_policy: ({creator, policy}: {creator: string, policy: TtlPolicy}, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>
+
+  /**
+   * Construct and simulate a settle_auction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Close an auction once it has ended (callable by anyone). The ticket
+   * goes to the highest bidder, with the winning bid paid out like a
+   * secondary sale except that the seller's share is credited to
+   * `claim_balance`. If there were no bids the ticket stays with the
+   * seller, and if the event was cancelled or the ticket checked in
+   * meanwhile the highest bid is credited back to its bidder instead.
+   */
+  settle_auction: ({ticket_id}: {ticket_id: u32}, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>
+
+  /**
+   * Construct and simulate a freeze_metadata transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Permanently prevent further changes to ticket URIs (event creator only)
+   */
+  freeze_metadata: ({creator}: {creator: string}, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>
 
   /**
    * Construct and simulate a transfer_ticket transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Transfer ticket directly (P2P)
    */
-  transfer_ticket: ({from, to, ticket_id}: {from: string, to: string, ticket_id: u32}, options?: AssembledTransactionOptions<null>) => Promise<AssembledTransaction<null>>
+  transfer_ticket: ({from, to, ticket_id}: {from: string, to: string, ticket_id: u32}, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>
+
+  /**
+   * Construct and simulate a extend_event_ttl transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Extend the TTL of the contract instance, code and event information.
+   * Anyone may call this and pay for the extension.
+   */
+  extend_event_ttl: (options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>
+
+  /**
+   * Construct and simulate a get_all_auctions transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the auctions that have not been settled among `limit` ticket ids
+   * from `start`
+   */
+  get_all_auctions: ({start, limit}: {start: u32, limit: u32}, options?: AssembledTransactionOptions<Array<Auction>>) => Promise<AssembledTransaction<Array<Auction>>>
+
+  /**
+   * Construct and simulate a get_protocol_fee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the protocol fee charged by the deploying factory, if any
+   */
+  get_protocol_fee: (options?: AssembledTransactionOptions<Option<ProtocolFee>>) => Promise<AssembledTransaction<Option<ProtocolFee>>>
 
   /**
    * Construct and simulate a get_user_tickets transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -130,9 +669,85 @@ export interface Client {
 
   /**
    * Construct and simulate a mark_ticket_used transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Mark ticket as used (check-in at event)
+   * Mark ticket as used (check-in at event). Check-in opens at the start
+   * of the event, so escrowed payments cannot be released before then.
+   */
+  mark_ticket_used: ({creator, ticket_id}: {creator: string, ticket_id: u32}, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>
+
+  /**
+   * Construct and simulate a extend_ticket_ttl transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Extend the TTL of a ticket and the entries tied to it: its listing,
+   * auction, offers, approval, metadata URI, escrowed payment, rebate and
+   * the owner's ticket index.
+   * Anyone may call this and pay for the extension.
+   */
+  extend_ticket_ttl: ({ticket_id}: {ticket_id: u32}, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>
+
+  /**
+   * Construct and simulate a get_current_price transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the price a ticket of a tier costs on the public sale right now
+   */
+  get_current_price: ({tier_id}: {tier_id: u32}, options?: AssembledTransactionOptions<Result<i128>>) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a get_dutch_auction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the Dutch auction configuration, if any
+   */
+  get_dutch_auction: (options?: AssembledTransactionOptions<Option<DutchAuction>>) => Promise<AssembledTransaction<Option<DutchAuction>>>
+
+  /**
+   * Construct and simulate a get_resale_policy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the limits on secondary listing prices
+   */
+  get_resale_policy: (options?: AssembledTransactionOptions<ResalePolicy>) => Promise<AssembledTransaction<ResalePolicy>>
+
+  /**
+   * Construct and simulate a set_dutch_auction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Sell a tier at a descending price instead of its fixed price (event
+   * creator only). Must be set before any ticket is sold. Rebates need the
+   * payments at hand, so they also hold all primary payments in escrow.
+   */
+  set_dutch_auction: ({creator, auction}: {creator: string, auction: DutchAuction}, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>
+
+  /**
+   * Construct and simulate a set_resale_policy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the limits on secondary listing prices (event creator only).
+   * Listings made before a change keep their price until it is updated.
    */
-  mark_ticket_used: ({creator, ticket_id}: {creator: string, ticket_id: u32}, options?: AssembledTransactionOptions<null>) => Promise<AssembledTransaction<null>>
+  set_resale_policy: ({creator, policy}: {creator: string, policy: ResalePolicy}, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>
+
+  /**
+   * Construct and simulate a withdraw_proceeds transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Withdraw the escrowed proceeds released so far (event creator only),
+   * less the protocol's share of primary sales. Returns the amount paid to
+   * the creator.
+   */
+  withdraw_proceeds: ({creator}: {creator: string}, options?: AssembledTransactionOptions<Result<i128>>) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a get_clearing_price transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the Dutch auction's clearing price, the price of the last ticket
+   * sold. It is known once the tier sells out or the sale ends.
+   */
+  get_clearing_price: (options?: AssembledTransactionOptions<Result<Option<i128>>>) => Promise<AssembledTransaction<Result<Option<i128>>>>
+
+  /**
+   * Construct and simulate a get_collection_bid transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a collection bid by id
+   */
+  get_collection_bid: ({bid_id}: {bid_id: u32}, options?: AssembledTransactionOptions<Result<CollectionBid>>) => Promise<AssembledTransaction<Result<CollectionBid>>>
+
+  /**
+   * Construct and simulate a get_escrow_balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the primary sale payments held in escrow, released or not
+   */
+  get_escrow_balance: (options?: AssembledTransactionOptions<i128>) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_max_per_wallet transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the event-wide cap on primary purchases per address (0 for no cap)
+   */
+  get_max_per_wallet: (options?: AssembledTransactionOptions<u32>) => Promise<AssembledTransaction<u32>>
 
   /**
    * Construct and simulate a get_tickets_minted transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -140,17 +755,108 @@ export interface Client {
    */
   get_tickets_minted: (options?: AssembledTransactionOptions<u32>) => Promise<AssembledTransaction<u32>>
 
+  /**
+   * Construct and simulate a get_tier_available transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get number of tickets of a tier still available in primary market
+   */
+  get_tier_available: ({tier_id}: {tier_id: u32}, options?: AssembledTransactionOptions<Result<u32>>) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a is_metadata_frozen transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check whether ticket metadata has been frozen
+   */
+  is_metadata_frozen: (options?: AssembledTransactionOptions<boolean>) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a set_max_per_wallet transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Cap how many tickets one address may buy on the primary market across
+   * all tiers, or lift the cap with 0 (event creator only)
+   */
+  set_max_per_wallet: ({creator, max_per_wallet}: {creator: string, max_per_wallet: u32}, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>
+
+  /**
+   * Construct and simulate a set_royalty_splits transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Split the creator fee on secondary sales between several recipients
+   * (event creator only). Shares are in basis points of the fee and may
+   * total at most 10000; the remainder goes to `remainder_recipient`.
+   */
+  set_royalty_splits: ({creator, splits, remainder_recipient}: {creator: string, splits: Array<RoyaltySplit>, remainder_recipient: string}, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>
+
+  /**
+   * Construct and simulate a fill_collection_bid transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Sell an unused, unlisted ticket into a collection bid (ticket owner
+   * only). The escrowed price is paid out like a secondary sale.
+   */
+  fill_collection_bid: ({seller, bid_id, ticket_id}: {seller: string, bid_id: u32, ticket_id: u32}, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>
+
+  /**
+   * Construct and simulate a get_collection_bids transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the open collection bids among `limit` bid ids from `start`
+   */
+  get_collection_bids: ({start, limit}: {start: u32, limit: u32}, options?: AssembledTransactionOptions<Array<CollectionBid>>) => Promise<AssembledTransaction<Array<CollectionBid>>>
+
+  /**
+   * Construct and simulate a is_approved_for_all transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check whether an operator may transfer all of the owner's tickets
+   */
+  is_approved_for_all: ({owner, operator}: {owner: string, operator: string}, options?: AssembledTransactionOptions<boolean>) => Promise<AssembledTransaction<boolean>>
+
   /**
    * Construct and simulate a buy_secondary_ticket transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Buy ticket from secondary marketplace
    */
-  buy_secondary_ticket: ({buyer, ticket_id}: {buyer: string, ticket_id: u32}, options?: AssembledTransactionOptions<null>) => Promise<AssembledTransaction<null>>
+  buy_secondary_ticket: ({buyer, ticket_id}: {buyer: string, ticket_id: u32}, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>
+
+  /**
+   * Construct and simulate a get_escrowed_payment transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the payment held in escrow for a ticket, if any
+   */
+  get_escrowed_payment: ({ticket_id}: {ticket_id: u32}, options?: AssembledTransactionOptions<Option<i128>>) => Promise<AssembledTransaction<Option<i128>>>
+
+  /**
+   * Construct and simulate a get_max_resale_price transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the highest price a ticket may be listed for, if it is capped
+   */
+  get_max_resale_price: ({ticket_id}: {ticket_id: u32}, options?: AssembledTransactionOptions<Result<Option<i128>>>) => Promise<AssembledTransaction<Result<Option<i128>>>>
+
+  /**
+   * Construct and simulate a get_wallet_allowance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get how many tickets of a tier the buyer may still buy on the primary
+   * market, given the wallet caps and the tier's remaining supply
+   */
+  get_wallet_allowance: ({buyer, tier_id}: {buyer: string, tier_id: u32}, options?: AssembledTransactionOptions<Result<u32>>) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a place_collection_bid transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Bid for `quantity` tickets of any tier, or of `tier_id`, at `price`
+   * each, holding the full amount in escrow. Returns the bid id.
+   */
+  place_collection_bid: ({buyer, tier_id, price, quantity}: {buyer: string, tier_id: Option<u32>, price: i128, quantity: u32}, options?: AssembledTransactionOptions<Result<u32>>) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a set_approval_for_all transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Grant or revoke an operator that may transfer all of the owner's tickets
+   */
+  set_approval_for_all: ({owner, operator, approved}: {owner: string, operator: string, approved: boolean}, options?: AssembledTransactionOptions<null>) => Promise<AssembledTransaction<null>>
 
   /**
    * Construct and simulate a update_listing_price transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Update listing price
    */
-  update_listing_price: ({seller, ticket_id, new_price}: {seller: string, ticket_id: u32, new_price: i128}, options?: AssembledTransactionOptions<null>) => Promise<AssembledTransaction<null>>
+  update_listing_price: ({seller, ticket_id, new_price}: {seller: string, ticket_id: u32, new_price: i128}, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>
+
+  /**
+   * Construct and simulate a cancel_collection_bid transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Withdraw a collection bid and get the escrow for its unfilled tickets
+   * back (bidder only)
+   */
+  cancel_collection_bid: ({bid_id}: {bid_id: u32}, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>
+
+  /**
+   * Construct and simulate a get_presale_purchased transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get how many presale tickets an address has bought
+   */
+  get_presale_purchased: ({buyer}: {buyer: string}, options?: AssembledTransactionOptions<u32>) => Promise<AssembledTransaction<u32>>
 
   /**
    * Construct and simulate a get_secondary_listing transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -162,13 +868,35 @@ export interface Client {
    * Construct and simulate a get_tickets_available transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Get number of tickets available in primary market
    */
-  get_tickets_available: (options?: AssembledTransactionOptions<u32>) => Promise<AssembledTransaction<u32>>
+  get_tickets_available: (options?: AssembledTransactionOptions<Result<u32>>) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a get_upgrade_authority transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get who may upgrade the contract code
+   */
+  get_upgrade_authority: (options?: AssembledTransactionOptions<UpgradeAuthority>) => Promise<AssembledTransaction<UpgradeAuthority>>
+
+  /**
+   * Construct and simulate a set_upgrade_authority transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Hand the right to upgrade to the factory or the creator. The current
+   * upgrade authority must authorize the change; the factory does so
+   * through its admin-only `set_event_upgrade_authority`.
+   */
+  set_upgrade_authority: ({authority}: {authority: UpgradeAuthority}, options?: AssembledTransactionOptions<Result<readonly []>>) => Promise<AssembledTransaction<Result<readonly []>>>
+
+  /**
+   * Construct and simulate a get_withdrawable_proceeds transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the escrowed proceeds the creator could withdraw now, before the
+   * protocol fee
+   */
+  get_withdrawable_proceeds: (options?: AssembledTransactionOptions<Result<i128>>) => Promise<AssembledTransaction<Result<i128>>>
 
   /**
    * Construct and simulate a get_all_secondary_listings transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get all secondary listings
+   * Get the secondary listings that can still be bought among `limit`
+   * ticket ids from `start`. A cancelled event has none.
    */
-  get_all_secondary_listings: (options?: AssembledTransactionOptions<Array<SecondaryListing>>) => Promise<AssembledTransaction<Array<SecondaryListing>>>
+  get_all_secondary_listings: ({start, limit}: {start: u32, limit: u32}, options?: AssembledTransactionOptions<Array<SecondaryListing>>) => Promise<AssembledTransaction<Array<SecondaryListing>>>
 
 }
 export class Client extends ContractClient {
@@ -188,47 +916,228 @@ export class Client extends ContractClient {
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAABQAAAAAAAAAAAAAACUV2ZW50SW5mbwAAAAAAAAEAAAAAAAAABlRpY2tldAAAAAAAAQAAAAQAAAAAAAAAAAAAAA1UaWNrZXRzTWludGVkAAAAAAAAAQAAAAAAAAAQU2Vjb25kYXJ5TGlzdGluZwAAAAEAAAAEAAAAAQAAAAAAAAALVXNlclRpY2tldHMAAAAAAQAAABM=",
-        "AAAAAQAAAAAAAAAAAAAACUV2ZW50SW5mbwAAAAAAAAgAAAAAAAAAD2NyZWF0b3JfZmVlX2JwcwAAAAAEAAAAAAAAAA1ldmVudF9jcmVhdG9yAAAAAAAAEwAAAAAAAAAOZXZlbnRfbWV0YWRhdGEAAAAAABAAAAAAAAAABG5hbWUAAAAQAAAAAAAAAA1wYXltZW50X3Rva2VuAAAAAAAAEwAAAAAAAAANcHJpbWFyeV9wcmljZQAAAAAAAAsAAAAAAAAABnN5bWJvbAAAAAAAEAAAAAAAAAAMdG90YWxfc3VwcGx5AAAABA==",
-        "AAAAAQAAAAAAAAAAAAAAClRpY2tldERhdGEAAAAAAAMAAAAAAAAAB2lzX3VzZWQAAAAAAQAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAl0aWNrZXRfaWQAAAAAAAAE",
-        "AAAAAAAAACpJbml0aWFsaXplIHRoZSB0aWNrZXQgbWFya2V0cGxhY2UgY29udHJhY3QAAAAAAARpbml0AAAACAAAAAAAAAANZXZlbnRfY3JlYXRvcgAAAAAAABMAAAAAAAAADHRvdGFsX3N1cHBseQAAAAQAAAAAAAAADXByaW1hcnlfcHJpY2UAAAAAAAALAAAAAAAAAA9jcmVhdG9yX2ZlZV9icHMAAAAABAAAAAAAAAAOZXZlbnRfbWV0YWRhdGEAAAAAABAAAAAAAAAABG5hbWUAAAAQAAAAAAAAAAZzeW1ib2wAAAAAABAAAAAAAAAADXBheW1lbnRfdG9rZW4AAAAAAAATAAAAAA==",
-        "AAAAAAAAACtHZXQgdGhlIG5hbWUgb2YgdGhlIGV2ZW50L3RpY2tldCBjb2xsZWN0aW9uAAAAAARuYW1lAAAAAAAAAAEAAAAQ",
-        "AAAAAQAAAAAAAAAAAAAAEFNlY29uZGFyeUxpc3RpbmcAAAADAAAAAAAAAAVwcmljZQAAAAAAAAsAAAAAAAAABnNlbGxlcgAAAAAAEwAAAAAAAAAJdGlja2V0X2lkAAAAAAAABA==",
-        "AAAAAAAAABxHZXQgdGhlIHN5bWJvbCBvZiB0aGUgdGlja2V0AAAABnN5bWJvbAAAAAAAAAAAAAEAAAAQ",
-        "AAAAAAAAABZHZXQgdGlja2V0IGluZm9ybWF0aW9uAAAAAAAKZ2V0X3RpY2tldAAAAAAAAQAAAAAAAAAJdGlja2V0X2lkAAAAAAAABAAAAAEAAAfQAAAAClRpY2tldERhdGEAAA==",
-        "AAAAAAAAACRMaXN0IHRpY2tldCBvbiBzZWNvbmRhcnkgbWFya2V0cGxhY2UAAAALbGlzdF90aWNrZXQAAAAAAwAAAAAAAAAGc2VsbGVyAAAAAAATAAAAAAAAAAl0aWNrZXRfaWQAAAAAAAAEAAAAAAAAAAVwcmljZQAAAAAAAAsAAAAA",
-        "AAAAAAAAAD9NaW50IGEgdGlja2V0IGZyb20gcHJpbWFyeSBtYXJrZXRwbGFjZSAoYnV5IGZyb20gZXZlbnQgY3JlYXRvcikAAAAAC21pbnRfdGlja2V0AAAAAAEAAAAAAAAABWJ1eWVyAAAAAAAAEwAAAAEAAAAE",
-        "AAAAAAAAAChEZWxpc3QgdGlja2V0IGZyb20gc2Vjb25kYXJ5IG1hcmtldHBsYWNlAAAADWRlbGlzdF90aWNrZXQAAAAAAAACAAAAAAAAAAZzZWxsZXIAAAAAABMAAAAAAAAACXRpY2tldF9pZAAAAAAAAAQAAAAA",
-        "AAAAAAAAABVHZXQgZXZlbnQgaW5mb3JtYXRpb24AAAAAAAAOZ2V0X2V2ZW50X2luZm8AAAAAAAAAAAABAAAH0AAAAAlFdmVudEluZm8AAAA=",
-        "AAAAAAAAAB5UcmFuc2ZlciB0aWNrZXQgZGlyZWN0bHkgKFAyUCkAAAAAAA90cmFuc2Zlcl90aWNrZXQAAAAAAwAAAAAAAAAEZnJvbQAAABMAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAl0aWNrZXRfaWQAAAAAAAAEAAAAAA==",
+      new ContractSpec([ "AAAAAQAAAGhBIHRpZXIgYW5kIGl0cyBwcmltYXJ5IHNhbGVzIHNvIGZhci4gVGllciBpZHMgYXJlIHBvc2l0aW9ucyBpbiB0aGUgbGlzdApwYXNzZWQgdG8gYGluaXRgLCBzdGFydGluZyBhdCAwLgAAAAAAAAAEVGllcgAAAAcAAAAAAAAAD2NyZWF0b3JfZmVlX2JwcwAAAAAEAAAAAAAAAA5tYXhfcGVyX3dhbGxldAAAAAAABAAAAAAAAAAGbWludGVkAAAAAAAEAAAAAAAAAARuYW1lAAAAEAAAAAAAAAAFcHJpY2UAAAAAAAALAAAAAAAAAAZzdXBwbHkAAAAAAAQAAAAAAAAAB3RpZXJfaWQAAAAABA==",
+        "AAAABQAAADdFbWl0dGVkIHdoZW4gYSB0aWNrZXQgaXMgbGlzdGVkIG9uIHRoZSBzZWNvbmRhcnkgbWFya2V0AAAAAAAAAAAETGlzdAAAAAEAAAAEbGlzdAAAAAQAAAAAAAAACXRpY2tldF9pZAAAAAAAAAQAAAABAAAAAAAAAAZzZWxsZXIAAAAAABMAAAABAAAAAAAAAAVwcmljZQAAAAAAAAsAAAAAAAAAAAAAAApleHBpcmVzX2F0AAAAAAPoAAAABgAAAAAAAAAC",
+        "AAAABQAAADVFbWl0dGVkIHdoZW4gYSB0aWNrZXQgaXMgYm91Z2h0IG9uIHRoZSBwcmltYXJ5IG1hcmtldAAAAAAAAAAAAAAETWludAAAAAEAAAAEbWludAAAAAQAAAAAAAAACXRpY2tldF9pZAAAAAAAAAQAAAABAAAAAAAAAAVidXllcgAAAAAAABMAAAABAAAAAAAAAAd0aWVyX2lkAAAAAAQAAAAAAAAAAAAAAAVwcmljZQAAAAAAAAsAAAAAAAAAAg==",
+        "AAAABQAAAD5FbWl0dGVkIHdoZW4gYSBsaXN0ZWQgdGlja2V0IGlzIGJvdWdodCBvbiB0aGUgc2Vjb25kYXJ5IG1hcmtldAAAAAAAAAAAAARTYWxlAAAAAQAAAARzYWxlAAAABgAAAAAAAAAJdGlja2V0X2lkAAAAAAAABAAAAAEAAAAAAAAABnNlbGxlcgAAAAAAEwAAAAEAAAAAAAAABWJ1eWVyAAAAAAAAEwAAAAEAAAAAAAAABXByaWNlAAAAAAAACwAAAAAAAAAAAAAAC2NyZWF0b3JfZmVlAAAAAAsAAAAAAAAAAAAAAAxwcm90b2NvbF9mZWUAAAALAAAAAAAAAAI=",
+        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAMgAAAAAAAAASQWxyZWFkeUluaXRpYWxpemVkAAAAAAABAAAAAAAAAA5Ob3RJbml0aWFsaXplZAAAAAAAAgAAAAAAAAANSW52YWxpZFN1cHBseQAAAAAAAAMAAAAAAAAADEludmFsaWRQcmljZQAAAAQAAAAAAAAADUludmFsaWRGZWVCcHMAAAAAAAAFAAAAAAAAAAdTb2xkT3V0AAAAAAYAAAAAAAAADlRpY2tldE5vdEZvdW5kAAAAAAAHAAAAAAAAAAhOb3RPd25lcgAAAAgAAAAAAAAAClRpY2tldFVzZWQAAAAAAAkAAAAAAAAADUFscmVhZHlMaXN0ZWQAAAAAAAAKAAAAAAAAAAlOb3RMaXN0ZWQAAAAAAAALAAAAAAAAAAlOb3RTZWxsZXIAAAAAAAAMAAAAAAAAAApOb3RDcmVhdG9yAAAAAAANAAAAAAAAABBJbnZhbGlkVHRsUG9saWN5AAAADgAAAAAAAAALTm90QXBwcm92ZWQAAAAADwAAAAAAAAAOTWV0YWRhdGFGcm96ZW4AAAAAABAAAAAAAAAACkludmFsaWRVcmkAAAAAABEAAAAAAAAADFRpZXJOb3RGb3VuZAAAABIAAAAAAAAADFRvb01hbnlUaWVycwAAABMAAAAAAAAAD0ludmFsaWRRdWFudGl0eQAAAAAUAAAAAAAAABJXYWxsZXRMaW1pdFJlYWNoZWQAAAAAABUAAAAAAAAAD0ludmFsaWRTY2hlZHVsZQAAAAAWAAAAAAAAAA5TYWxlTm90U3RhcnRlZAAAAAAAFwAAAAAAAAAJU2FsZUVuZGVkAAAAAAAAGAAAAAAAAAAOSW52YWxpZFByZXNhbGUAAAAAABkAAAAAAAAAEFByZXNhbGVOb3RBY3RpdmUAAAAaAAAAAAAAAA5Ob3RPbkFsbG93bGlzdAAAAAAAGwAAAAAAAAANUHJpY2VBYm92ZUNhcAAAAAAAABwAAAAAAAAAD1ByaWNlQmVsb3dGbG9vcgAAAAAdAAAAAAAAABNJbnZhbGlkUmVzYWxlUG9saWN5AAAAAB4AAAAAAAAAFEludmFsaWRSb3lhbHR5U3BsaXRzAAAAHwAAAAAAAAARRmFjdG9yeUFscmVhZHlTZXQAAAAAAAAgAAAAAAAAAAlOb0ZhY3RvcnkAAAAAAAAhAAAAAAAAABJUaWNrZXRzQWxyZWFkeVNvbGQAAAAAACIAAAAAAAAADkV2ZW50Q2FuY2VsbGVkAAAAAAAjAAAAAAAAABFFdmVudE5vdENhbmNlbGxlZAAAAAAAACQAAAAAAAAADU5vdFJlZnVuZGFibGUAAAAAAAAlAAAAAAAAAApFdmVudEVuZGVkAAAAAAAmAAAAAAAAAA5Qcm9jZWVkc0xvY2tlZAAAAAAAJwAAAAAAAAAKTm9Qcm9jZWVkcwAAAAAAKAAAAAAAAAANSW52YWxpZEV4cGlyeQAAAAAAACkAAAAAAAAAC09mZmVyRXhpc3RzAAAAACoAAAAAAAAADU9mZmVyTm90Rm91bmQAAAAAAAArAAAAAAAAAAxPZmZlckV4cGlyZWQAAAAsAAAAAAAAAAtCaWROb3RGb3VuZAAAAAAtAAAAAAAAAAxUaWVyTWlzbWF0Y2gAAAAuAAAAAAAAAA5MaXN0aW5nRXhwaXJlZAAAAAAALwAAAAAAAAAPQXVjdGlvbk5vdEZvdW5kAAAAADAAAAAAAAAAD0F1Y3Rpb25Ob3RFbmRlZAAAAAAxAAAAAAAAAAlCaWRUb29Mb3cAAAAAAAAy",
+        "AAAABQAAADBFbWl0dGVkIHdoZW4gYSBidXllciBtYWtlcyBhbiBvZmZlciBmb3IgYSB0aWNrZXQAAAAAAAAABU9mZmVyAAAAAAAAAQAAAAVvZmZlcgAAAAAAAAQAAAAAAAAACXRpY2tldF9pZAAAAAAAAAQAAAABAAAAAAAAAAVidXllcgAAAAAAABMAAAABAAAAAAAAAAVwcmljZQAAAAAAAAsAAAAAAAAAAAAAAAZleHBpcnkAAAAAAAYAAAAAAAAAAg==",
+        "AAAABQAAADBFbWl0dGVkIHdoZW4gdGhlIGV2ZW50IGNyZWF0b3IgY2FuY2VscyB0aGUgZXZlbnQAAAAAAAAABkNhbmNlbAAAAAAAAQAAAAZjYW5jZWwAAAAAAAEAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAEAAAAC",
+        "AAAABQAAAEhFbWl0dGVkIHdoZW4gYSBsaXN0aW5nIGlzIHdpdGhkcmF3biwgZWl0aGVyIGJ5IHRoZSBzZWxsZXIgb3IgYXQgY2hlY2staW4AAAAAAAAABkRlbGlzdAAAAAAAAQAAAAZkZWxpc3QAAAAAAAIAAAAAAAAACXRpY2tldF9pZAAAAAAAAAQAAAABAAAAAAAAAAZzZWxsZXIAAAAAABMAAAABAAAAAg==",
+        "AAAABQAAAFRFbWl0dGVkIHdoZW4gYSBEdXRjaCBhdWN0aW9uIGJ1eWVyIGdldHMgYmFjayB3aGF0IHRoZXkgcGFpZCBhYm92ZSB0aGUKY2xlYXJpbmcgcHJpY2UAAAAAAAAABlJlYmF0ZQAAAAAAAQAAAAZyZWJhdGUAAAAAAAMAAAAAAAAACXRpY2tldF9pZAAAAAAAAAQAAAABAAAAAAAAAAVidXllcgAAAAAAABMAAAABAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAAg==",
+        "AAAABQAAAEJFbWl0dGVkIHdoZW4gYSBob2xkZXIgb2YgYSBjYW5jZWxsZWQgZXZlbnQgZ2V0cyB0aGVpciBwYXltZW50IGJhY2sAAAAAAAAAAAAGUmVmdW5kAAAAAAABAAAABnJlZnVuZAAAAAAAAwAAAAAAAAAJdGlja2V0X2lkAAAAAAAABAAAAAEAAAAAAAAABW93bmVyAAAAAAAAEwAAAAEAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAC",
+        "AAAAAQAAAINBbiBFbmdsaXNoIGF1Y3Rpb24gb2YgYSB0aWNrZXQuIFRoZSBoaWdoZXN0IGJpZCBpcyBoZWxkIGluIGVzY3JvdyBieSB0aGUKY29udHJhY3QsIGFuZCBiaWRzIG11c3QgcmVhY2ggYHJlc2VydmVgIGJlZm9yZSBgZW5kX3RpbWVgLgAAAAAAAAAAB0F1Y3Rpb24AAAAABgAAAAAAAAAIZW5kX3RpbWUAAAAGAAAAAAAAAAtoaWdoZXN0X2JpZAAAAAALAAAAAAAAAA5oaWdoZXN0X2JpZGRlcgAAAAAD6AAAABMAAAAAAAAAB3Jlc2VydmUAAAAACwAAAAAAAAAGc2VsbGVyAAAAAAATAAAAAAAAAAl0aWNrZXRfaWQAAAAAAAAE",
+        "AAAABQAAAEJFbWl0dGVkIHdoZW4gYW4gb3duZXIgYXBwcm92ZXMgKG9yIGNsZWFycykgYSBzaW5nbGUtdGlja2V0IHNwZW5kZXIAAAAAAAAAAAAHQXBwcm92ZQAAAAABAAAAB2FwcHJvdmUAAAAAAwAAAAAAAAAJdGlja2V0X2lkAAAAAAAABAAAAAEAAAAAAAAABW93bmVyAAAAAAAAEwAAAAEAAAAAAAAACGFwcHJvdmVkAAAD6AAAABMAAAAAAAAAAg==",
+        "AAAABQAAADVFbWl0dGVkIHdoZW4gdGhlIGV2ZW50IGNyZWF0b3IgbWFya3MgYSB0aWNrZXQgYXMgdXNlZAAAAAAAAAAAAAAHQ2hlY2tJbgAAAAABAAAACGNoZWNrX2luAAAAAgAAAAAAAAAJdGlja2V0X2lkAAAAAAAABAAAAAEAAAAAAAAABW93bmVyAAAAAAAAEwAAAAEAAAAC",
+        "AAAABQAAACpFbWl0dGVkIHdoZW4gdGhlIGNvbnRyYWN0IGNvZGUgaXMgcmVwbGFjZWQAAAAAAAAAAAAHVXBncmFkZQAAAAABAAAAB3VwZ3JhZGUAAAAAAQAAAAAAAAANbmV3X3dhc21faGFzaAAAAAAAA+4AAAAgAAAAAAAAAAI=",
+        "AAAABQAAADFFbWl0dGVkIHdoZW4gYSB0aWNrZXQgaXMgdHJhbnNmZXJyZWQgcGVlciB0byBwZWVyAAAAAAAAAAAAAAhUcmFuc2ZlcgAAAAEAAAAIdHJhbnNmZXIAAAADAAAAAAAAAAl0aWNrZXRfaWQAAAAAAAAEAAAAAQAAAAAAAAAEZnJvbQAAABMAAAABAAAAAAAAAAJ0bwAAAAAAEwAAAAEAAAAC",
+        "AAAABQAAAEdFbWl0dGVkIHdoZW4gdGhlIGV2ZW50IGNyZWF0b3Igd2l0aGRyYXdzIGVzY3Jvd2VkIHByaW1hcnkgc2FsZSBwcm9jZWVkcwAAAAAAAAAACFdpdGhkcmF3AAAAAQAAAAh3aXRoZHJhdwAAAAMAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAEAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAAAAAADHByb3RvY29sX2ZlZQAAAAsAAAAAAAAAAg==",
+        "AAAAAQAAAAAAAAAAAAAACUV2ZW50SW5mbwAAAAAAAAsAAAAAAAAAD2NyZWF0b3JfZmVlX2JwcwAAAAAEAAAAAAAAAA1ldmVudF9jcmVhdG9yAAAAAAAAEwAAAAAAAAAOZXZlbnRfbWV0YWRhdGEAAAAAABAAAAAAAAAABG5hbWUAAAAQAAAAAAAAAA1wYXltZW50X3Rva2VuAAAAAAAAEwAAAAAAAAANcHJpbWFyeV9wcmljZQAAAAAAAAsAAAAAAAAAG3JveWFsdHlfcmVtYWluZGVyX3JlY2lwaWVudAAAAAATAAAAAAAAAA5yb3lhbHR5X3NwbGl0cwAAAAAD6gAAB9AAAAAMUm95YWx0eVNwbGl0AAAAAAAAAAhzY2hlZHVsZQAAB9AAAAANRXZlbnRTY2hlZHVsZQAAAAAAAAAAAAAGc3ltYm9sAAAAAAAQAAAAAAAAAAx0b3RhbF9zdXBwbHkAAAAE",
+        "AAAAAQAAAPtIb3cgbG9uZyBzdG9yYWdlIGlzIGtlcHQgYWxpdmUgd2hlbmV2ZXIgaXQgaXMgdG91Y2hlZC4gRW50cmllcyBhcmUgZXh0ZW5kZWQKYnkgYXQgbGVhc3QgYG1pbl9leHRlbnNpb25gIGxlZGdlcnMsIGFuZCBmYXIgZW5vdWdoIHRvIG91dGxpdmUKYHJldGFpbl91bnRpbGAgKGEgbGVkZ2VyIHRpbWVzdGFtcCkuIFVudGlsIHRoZSBjcmVhdG9yIHNldHMgYSBwb2xpY3ksCmByZXRhaW5fdW50aWxgIGlzIHRoZSBldmVudCdzIGBldmVudF9lbmRgLgAAAAAAAAAACVR0bFBvbGljeQAAAAAAAAIAAAAAAAAADW1pbl9leHRlbnNpb24AAAAAAAAEAAAAAAAAAAxyZXRhaW5fdW50aWwAAAAG",
+        "AAAAAQAAAAAAAAAAAAAAClRpY2tldERhdGEAAAAAAAQAAAAAAAAAB2lzX3VzZWQAAAAAAQAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAl0aWNrZXRfaWQAAAAAAAAEAAAAAAAAAAd0aWVyX2lkAAAAAAQ=",
+        "AAAAAQAAAJBBIHRpZXIgYXMgY29uZmlndXJlZCBhdCBkZXBsb3ltZW50LiBgbWF4X3Blcl93YWxsZXRgIGNhcHMgaG93IG1hbnkKdGlja2V0cyBvZiB0aGUgdGllciBvbmUgYWRkcmVzcyBtYXkgYnV5IG9uIHRoZSBwcmltYXJ5IG1hcmtldCAoMCBmb3Igbm8gY2FwKS4AAAAAAAAAClRpZXJDb25maWcAAAAAAAUAAAAAAAAAD2NyZWF0b3JfZmVlX2JwcwAAAAAEAAAAAAAAAA5tYXhfcGVyX3dhbGxldAAAAAAABAAAAAAAAAAEbmFtZQAAABAAAAAAAAAABXByaWNlAAAAAAAACwAAAAAAAAAGc3VwcGx5AAAAAAAE",
+        "AAAABQAAADhFbWl0dGVkIHdoZW4gYSBiaWQgYmVjb21lcyB0aGUgaGlnaGVzdCBiaWQgb2YgYW4gYXVjdGlvbgAAAAAAAAAKQXVjdGlvbkJpZAAAAAAAAQAAAAthdWN0aW9uX2JpZAAAAAAEAAAAAAAAAAl0aWNrZXRfaWQAAAAAAAAEAAAAAQAAAAAAAAAGYmlkZGVyAAAAAAATAAAAAQAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAAAAAAIZW5kX3RpbWUAAAAGAAAAAAAAAAI=",
+        "AAAAAQAAAMJUaGUgcGxhdGZvcm0ncyBjdXQgb2Ygc2FsZXMsIHNldCBieSB0aGUgZGVwbG95aW5nIGZhY3RvcnkuIGBwcmltYXJ5X2Jwc2AKaXMgdGFrZW4gZnJvbSBwcmltYXJ5IHNhbGVzIGFuZCBgc2Vjb25kYXJ5X2Jwc2AgZnJvbSByZXNhbGVzLCBvbiB0b3Agb2YKdGhlIGNyZWF0b3IgZmVlLCBhbmQgYm90aCBhcmUgcGFpZCB0byBgdHJlYXN1cnlgLgAAAAAAAAAAAAtQcm90b2NvbEZlZQAAAAADAAAAAAAAAAtwcmltYXJ5X2JwcwAAAAAEAAAAAAAAAA1zZWNvbmRhcnlfYnBzAAAAAAAABAAAAAAAAAAIdHJlYXN1cnkAAAAT",
+        "AAAAAQAAAJ9BIGJ1eWVyJ3Mgb2ZmZXIgZm9yIGEgdGlja2V0LCB3aXRoIHRoZSBwcmljZSBoZWxkIGluIGVzY3JvdyBieSB0aGUKY29udHJhY3QuIEl0IGNhbiBiZSBhY2NlcHRlZCBieSB3aG9ldmVyIG93bnMgdGhlIHRpY2tldCB1bnRpbCBgZXhwaXJ5YAooYSBsZWRnZXIgdGltZXN0YW1wKS4AAAAAAAAAAAtUaWNrZXRPZmZlcgAAAAAEAAAAAAAAAAVidXllcgAAAAAAABMAAAAAAAAABmV4cGlyeQAAAAAABgAAAAAAAAAFcHJpY2UAAAAAAAALAAAAAAAAAAl0aWNrZXRfaWQAAAAAAAAE",
+        "AAAABQAAAEBFbWl0dGVkIHdoZW4gYSBidXllciB3aXRoZHJhd3MgYW4gb2ZmZXIgYW5kIGdldHMgdGhlIGVzY3JvdyBiYWNrAAAAAAAAAAtPZmZlckNhbmNlbAAAAAABAAAADG9mZmVyX2NhbmNlbAAAAAIAAAAAAAAACXRpY2tldF9pZAAAAAAAAAQAAAABAAAAAAAAAAVidXllcgAAAAAAABMAAAABAAAAAg==",
+        "AAAABQAAADRFbWl0dGVkIHdoZW4gYSBzZWxsZXIgY2hhbmdlcyB0aGUgcHJpY2Ugb2YgYSBsaXN0aW5nAAAAAAAAAAtQcmljZVVwZGF0ZQAAAAABAAAADHByaWNlX3VwZGF0ZQAAAAQAAAAAAAAACXRpY2tldF9pZAAAAAAAAAQAAAABAAAAAAAAAAZzZWxsZXIAAAAAABMAAAABAAAAAAAAAAlvbGRfcHJpY2UAAAAAAAALAAAAAAAAAAAAAAAJbmV3X3ByaWNlAAAAAAAACwAAAAAAAAAC",
+        "AAAAAQAAAQdBIGRlc2NlbmRpbmctcHJpY2UgcHJpbWFyeSBzYWxlIG9mIG9uZSB0aWVyLiBGcm9tIGBzYWxlX3N0YXJ0YCB0aGUgcHJpY2UKZHJvcHMgYnkgYHByaWNlX2Ryb3BgIGV2ZXJ5IGBkcm9wX2ludGVydmFsYCBzZWNvbmRzIHVudGlsIGl0IHJlYWNoZXMKYGZsb29yX3ByaWNlYC4gV2l0aCBgcmViYXRlYCwgYnV5ZXJzIGdldCBiYWNrIHdoYXQgdGhleSBwYWlkIGFib3ZlIHRoZQpjbGVhcmluZyBwcmljZSwgdGhlIHByaWNlIG9mIHRoZSBsYXN0IHRpY2tldCBzb2xkLgAAAAAAAAAADER1dGNoQXVjdGlvbgAAAAYAAAAAAAAADWRyb3BfaW50ZXJ2YWwAAAAAAAAGAAAAAAAAAAtmbG9vcl9wcmljZQAAAAALAAAAAAAAAApwcmljZV9kcm9wAAAAAAALAAAAAAAAAAZyZWJhdGUAAAAAAAEAAAAAAAAAC3N0YXJ0X3ByaWNlAAAAAAsAAAAAAAAAB3RpZXJfaWQAAAAABA==",
+        "AAAAAQAAAPBMaW1pdHMgb24gc2Vjb25kYXJ5IGxpc3RpbmcgcHJpY2VzLiBgbWF4X3ByaWNlYCBpcyBhbiBhYnNvbHV0ZSBjYXAgYW5kCmBtYXhfbWFya3VwX2Jwc2AgYSBjYXAgcmVsYXRpdmUgdG8gdGhlIHRpY2tldCdzIHRpZXIgcHJpY2UgKDEwMDAwIGlzIGZhY2UKdmFsdWUpOyB3aGVuIGJvdGggYXJlIHNldCB0aGUgbG93ZXIgb25lIGFwcGxpZXMuIGBtaW5fcHJpY2VgIGlzIGEgZmxvb3IuClplcm8gZGlzYWJsZXMgYSBsaW1pdC4AAAAAAAAADFJlc2FsZVBvbGljeQAAAAMAAAAAAAAADm1heF9tYXJrdXBfYnBzAAAAAAAEAAAAAAAAAAltYXhfcHJpY2UAAAAAAAALAAAAAAAAAAltaW5fcHJpY2UAAAAAAAAL",
+        "AAAAAQAAALRBIHNoYXJlIG9mIHRoZSBjcmVhdG9yIGZlZSBvbiBzZWNvbmRhcnkgc2FsZXMsIGluIGJhc2lzIHBvaW50cyBvZiB0aGUgZmVlLgpXaGF0ZXZlciB0aGUgc3BsaXRzIGxlYXZlIG92ZXIsIGluY2x1ZGluZyByb3VuZGluZyBkdXN0LCBnb2VzIHRvCmBFdmVudEluZm8ucm95YWx0eV9yZW1haW5kZXJfcmVjaXBpZW50YC4AAAAAAAAADFJveWFsdHlTcGxpdAAAAAIAAAAAAAAAA2JwcwAAAAAEAAAAAAAAAAlyZWNpcGllbnQAAAAAAAAT",
+        "AAAABQAAADJFbWl0dGVkIHdoZW4gYW4gb3duZXIgcHV0cyBhIHRpY2tldCB1cCBmb3IgYXVjdGlvbgAAAAAAAAAAAAxBdWN0aW9uU3RhcnQAAAABAAAADWF1Y3Rpb25fc3RhcnQAAAAAAAAEAAAAAAAAAAl0aWNrZXRfaWQAAAAAAAAEAAAAAQAAAAAAAAAGc2VsbGVyAAAAAAATAAAAAQAAAAAAAAAHcmVzZXJ2ZQAAAAALAAAAAAAAAAAAAAAIZW5kX3RpbWUAAAAGAAAAAAAAAAI=",
+        "AAAAAQAAAK1BIGJ1eWVyJ3Mgc3RhbmRpbmcgYmlkIGZvciB1cCB0byBgcXVhbnRpdHlgIG1vcmUgdGlja2V0cyBvZiB0aGUgZXZlbnQsIG9yCm9mIG9uZSB0aWVyLCBhdCBgcHJpY2VgIGVhY2guIFRoZSBwcmljZSBvZiBldmVyeSB1bmZpbGxlZCB0aWNrZXQgaXMgaGVsZAppbiBlc2Nyb3cgYnkgdGhlIGNvbnRyYWN0LgAAAAAAAAAAAAANQ29sbGVjdGlvbkJpZAAAAAAAAAUAAAAAAAAABmJpZF9pZAAAAAAABAAAAAAAAAAFYnV5ZXIAAAAAAAATAAAAAAAAAAVwcmljZQAAAAAAAAsAAAAAAAAACHF1YW50aXR5AAAABAAAAAAAAAAHdGllcl9pZAAAAAPoAAAABA==",
+        "AAAAAQAAAMJMZWRnZXIgdGltZXN0YW1wcyAoc2Vjb25kcykgYm91bmRpbmcgdGhlIHByaW1hcnkgc2FsZSBhbmQgdGhlIGV2ZW50Cml0c2VsZi4gVGlja2V0cyBjYW4gYmUgbWludGVkIGZyb20gYHNhbGVfc3RhcnRgIHVudGlsIGp1c3QgYmVmb3JlCmBzYWxlX2VuZGAsIGFuZCB0aGUgc2FsZSBtdXN0IGNsb3NlIGJ5IHRoZSBlbmQgb2YgdGhlIGV2ZW50LgAAAAAAAAAAAA1FdmVudFNjaGVkdWxlAAAAAAAABAAAAAAAAAAJZXZlbnRfZW5kAAAAAAAABgAAAAAAAAALZXZlbnRfc3RhcnQAAAAABgAAAAAAAAAIc2FsZV9lbmQAAAAGAAAAAAAAAApzYWxlX3N0YXJ0AAAAAAAG",
+        "AAAAAQAAAP9BbiBhbGxvd2xpc3Qgc2FsZSBvZiBvbmUgdGllciB0aGF0IHJ1bnMgZnJvbSBgc3RhcnRgIHVudGlsIHRoZSBwdWJsaWMKc2FsZSBvcGVucy4gRWxpZ2libGUgYWRkcmVzc2VzIHByb3ZlIG1lbWJlcnNoaXAgd2l0aCBhIE1lcmtsZSBwcm9vZgphZ2FpbnN0IGBtZXJrbGVfcm9vdGAsIHdob3NlIGxlYXZlcyBhcmUgdGhlIFNIQS0yNTYgb2YgZWFjaCBhZGRyZXNzJ3MgWERSCmFuZCB3aG9zZSBwYWlycyBhcmUgaGFzaGVkIGluIHNvcnRlZCBvcmRlci4AAAAAAAAAAA1QcmVzYWxlQ29uZmlnAAAAAAAABQAAAAAAAAAObWF4X3Blcl93YWxsZXQAAAAAAAQAAAAAAAAAC21lcmtsZV9yb290AAAAA+4AAAAgAAAAAAAAAAVwcmljZQAAAAAAAAsAAAAAAAAABXN0YXJ0AAAAAAAABgAAAAAAAAAHdGllcl9pZAAAAAAE",
+        "AAAABQAAAElFbWl0dGVkIHdoZW4gYW4gb3duZXIgZ3JhbnRzIG9yIHJldm9rZXMgYW4gb3BlcmF0b3IgZm9yIGFsbCB0aGVpciB0aWNrZXRzAAAAAAAAAAAAAA1BcHByb3ZlRm9yQWxsAAAAAAAAAQAAAA9hcHByb3ZlX2Zvcl9hbGwAAAAAAwAAAAAAAAAFb3duZXIAAAAAAAATAAAAAQAAAAAAAAAIb3BlcmF0b3IAAAATAAAAAQAAAAAAAAAIYXBwcm92ZWQAAAABAAAAAAAAAAI=",
+        "AAAABQAAAC1FbWl0dGVkIHdoZW4gYW4gYXVjdGlvbiBjbG9zZXMgd2l0aG91dCBhIHNhbGUAAAAAAAAAAAAADUF1Y3Rpb25DYW5jZWwAAAAAAAABAAAADmF1Y3Rpb25fY2FuY2VsAAAAAAACAAAAAAAAAAl0aWNrZXRfaWQAAAAAAAAEAAAAAQAAAAAAAAAGc2VsbGVyAAAAAAATAAAAAQAAAAI=",
+        "AAAABQAAAENFbWl0dGVkIHdoZW4gdGhlIGNyZWF0b3IgY2hhbmdlcyB0aGUgYmFzZSBVUkkgc2hhcmVkIGJ5IGFsbCB0aWNrZXRzAAAAAAAAAAANQmFzZVVyaVVwZGF0ZQAAAAAAAAEAAAAPYmFzZV91cmlfdXBkYXRlAAAAAAEAAAAAAAAACGJhc2VfdXJpAAAAEAAAAAAAAAAC",
+        "AAAAAAAAASVCaWQgb24gYW4gYXVjdGlvbiwgaG9sZGluZyBgYW1vdW50YCBpbiBlc2Nyb3cgYW5kIGNyZWRpdGluZyB0aGUKcHJldmlvdXMgaGlnaGVzdCBiaWQgdG8gaXRzIGJpZGRlciBmb3IgYGNsYWltX2JhbGFuY2VgLiBCaWRzIG11c3QKbWVldCB0aGUgcmVzZXJ2ZSBhbmQgYmVhdCB0aGUgaGlnaGVzdCBiaWQgYnkgYE1JTl9CSURfSU5DUkVNRU5UX0JQU2AsCmFuZCBhIGJpZCBpbiB0aGUgbGFzdCBgQVVDVElPTl9FWFRFTlNJT05fU0VDT05EU2AgcHVzaGVzIHRoZSBlbmQKYmFjaywgdXAgdG8gdGhlIGV2ZW50IHN0YXJ0LgAAAAAAAANiaWQAAAAAAwAAAAAAAAAGYmlkZGVyAAAAAAATAAAAAAAAAAl0aWNrZXRfaWQAAAAAAAAEAAAAAAAAAAZhbW91bnQAAAAAAAsAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAABQAAADxFbWl0dGVkIHdoZW4gdGhlIGNyZWF0b3IgcGVybWFuZW50bHkgZnJlZXplcyB0aWNrZXQgbWV0YWRhdGEAAAAAAAAADk1ldGFkYXRhRnJlZXplAAAAAAABAAAAD21ldGFkYXRhX2ZyZWV6ZQAAAAABAAAAAAAAAAdjcmVhdG9yAAAAABMAAAABAAAAAg==",
+        "AAAAAAAAAN9Jbml0aWFsaXplIHRoZSB0aWNrZXQgbWFya2V0cGxhY2UgY29udHJhY3QuIFdpdGggYW4gZW1wdHkgYHRpZXJzYCBsaXN0CnRoZSBldmVudCBoYXMgYSBzaW5nbGUgdGllciBidWlsdCBmcm9tIGB0b3RhbF9zdXBwbHlgLCBgcHJpbWFyeV9wcmljZWAKYW5kIGBjcmVhdG9yX2ZlZV9icHNgOyBvdGhlcndpc2UgdGhlIHRpZXIgc3VwcGxpZXMgbXVzdCBhZGQgdXAgdG8KYHRvdGFsX3N1cHBseWAuAAAAAARpbml0AAAACgAAAAAAAAANZXZlbnRfY3JlYXRvcgAAAAAAABMAAAAAAAAADHRvdGFsX3N1cHBseQAAAAQAAAAAAAAADXByaW1hcnlfcHJpY2UAAAAAAAALAAAAAAAAAA9jcmVhdG9yX2ZlZV9icHMAAAAABAAAAAAAAAAOZXZlbnRfbWV0YWRhdGEAAAAAABAAAAAAAAAABG5hbWUAAAAQAAAAAAAAAAZzeW1ib2wAAAAAABAAAAAAAAAADXBheW1lbnRfdG9rZW4AAAAAAAATAAAAAAAAAAV0aWVycwAAAAAAA+oAAAfQAAAAClRpZXJDb25maWcAAAAAAAAAAAAIc2NoZWR1bGUAAAfQAAAADUV2ZW50U2NoZWR1bGUAAAAAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAACtHZXQgdGhlIG5hbWUgb2YgdGhlIGV2ZW50L3RpY2tldCBjb2xsZWN0aW9uAAAAAARuYW1lAAAAAAAAAAEAAAPpAAAAEAAAAAM=",
+        "AAAABQAAAENFbWl0dGVkIHdoZW4gdGhlIGNyZWF0b3Igc2V0cyBvciBjbGVhcnMgYSB0aWNrZXQncyBvd24gbWV0YWRhdGEgVVJJAAAAAAAAAAAPVGlja2V0VXJpVXBkYXRlAAAAAAEAAAARdGlja2V0X3VyaV91cGRhdGUAAAAAAAACAAAAAAAAAAl0aWNrZXRfaWQAAAAAAAAEAAAAAQAAAAAAAAADdXJpAAAAA+gAAAAQAAAAAAAAAAI=",
+        "AAAAAQAAAI5BIHRpY2tldCBmb3Igc2FsZSBvbiB0aGUgc2Vjb25kYXJ5IG1hcmtldC4gTGlzdGluZ3MgbGFwc2UgYXQgYGV4cGlyZXNfYXRgCihhIGxlZGdlciB0aW1lc3RhbXApLCBpZiBzZXQsIGFuZCBpbiBhbnkgY2FzZSBvbmNlIHRoZSBldmVudCBzdGFydHMuAAAAAAAAAAAAEFNlY29uZGFyeUxpc3RpbmcAAAAEAAAAAAAAAApleHBpcmVzX2F0AAAAAAPoAAAABgAAAAAAAAAFcHJpY2UAAAAAAAALAAAAAAAAAAZzZWxsZXIAAAAAABMAAAAAAAAACXRpY2tldF9pZAAAAAAAAAQ=",
+        "AAAAAgAAAGZXaG8gbWF5IHVwZ3JhZGUgdGhlIGNvbnRyYWN0IGNvZGU6IHRoZSBkZXBsb3lpbmcgZmFjdG9yeSAob24gYmVoYWxmIG9mIGl0cwphZG1pbikgb3IgdGhlIGV2ZW50IGNyZWF0b3IAAAAAAAAAAAAQVXBncmFkZUF1dGhvcml0eQAAAAIAAAAAAAAAAAAAAAdGYWN0b3J5AAAAAAAAAAAAAAAAB0NyZWF0b3IA",
+        "AAAAAAAAABxHZXQgdGhlIHN5bWJvbCBvZiB0aGUgdGlja2V0AAAABnN5bWJvbAAAAAAAAAAAAAEAAAPpAAAAEAAAAAM=",
+        "AAAAAAAAAMhBcHByb3ZlIGEgc3BlbmRlciBmb3IgYSBzaW5nbGUgdGlja2V0LCBvciBjbGVhciB0aGUgYXBwcm92YWwgd2l0aCBgTm9uZWAuClRoZSBhcHByb3ZlciBtdXN0IGJlIHRoZSBvd25lciBvciBvbmUgb2YgdGhlIG93bmVyJ3Mgb3BlcmF0b3JzLCBhbmQgdGhlCmFwcHJvdmFsIGlzIGNsZWFyZWQgd2hlbmV2ZXIgdGhlIHRpY2tldCBjaGFuZ2VzIGhhbmRzLgAAAAdhcHByb3ZlAAAAAAMAAAAAAAAACGFwcHJvdmVyAAAAEwAAAAAAAAAIYXBwcm92ZWQAAAPoAAAAEwAAAAAAAAAJdGlja2V0X2lkAAAAAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAACxHZXQgdGhlIG51bWJlciBvZiB0aWNrZXRzIGhlbGQgYnkgYW4gYWRkcmVzcwAAAAdiYWxhbmNlAAAAAAEAAAAAAAAABW93bmVyAAAAAAAAEwAAAAEAAAAE",
+        "AAAAAAAAAK5SZXBsYWNlIHRoZSBjb250cmFjdCBjb2RlLiBPbmx5IHRoZSB1cGdyYWRlIGF1dGhvcml0eSBtYXkgZG8gdGhpczogdGhlCmRlcGxveWluZyBmYWN0b3J5IGJ5IGRlZmF1bHQsIG9yIHRoZSBldmVudCBjcmVhdG9yIGZvciBldmVudHMgd2l0aG91dAphIGZhY3Rvcnkgb3IgdGhhdCBoYXZlIG9wdGVkIG91dC4AAAAAAAd1cGdyYWRlAAAAAAEAAAAAAAAADW5ld193YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAClHZXQgdGhlIHZlcnNpb24gb2YgdGhlIGNvbnRyYWN0IGludGVyZmFjZQAAAAAAAAd2ZXJzaW9uAAAAAAAAAAABAAAABA==",
+        "AAAABQAAAEdFbWl0dGVkIHdoZW4gYSBidXllciBwbGFjZXMgYSBiaWQgZm9yIGFueSB0aWNrZXQgb2YgdGhlIGV2ZW50IG9yIGEgdGllcgAAAAAAAAAAEkNvbGxlY3Rpb25CaWRQbGFjZQAAAAAAAQAAABRjb2xsZWN0aW9uX2JpZF9wbGFjZQAAAAUAAAAAAAAABmJpZF9pZAAAAAAABAAAAAEAAAAAAAAABWJ1eWVyAAAAAAAAEwAAAAEAAAAAAAAAB3RpZXJfaWQAAAAD6AAAAAQAAAAAAAAAAAAAAAVwcmljZQAAAAAAAAsAAAAAAAAAAAAAAAhxdWFudGl0eQAAAAQAAAAAAAAAAg==",
+        "AAAAAAAAABFHZXQgYSBzaW5nbGUgdGllcgAAAAAAAAhnZXRfdGllcgAAAAEAAAAAAAAAB3RpZXJfaWQAAAAABAAAAAEAAAPpAAAH0AAAAARUaWVyAAAAAw==",
+        "AAAAAAAAACFHZXQgdGhlIGN1cnJlbnQgb3duZXIgb2YgYSB0aWNrZXQAAAAAAAAIb3duZXJfb2YAAAABAAAAAAAAAAl0aWNrZXRfaWQAAAAAAAAEAAAAAQAAA+kAAAATAAAAAw==",
+        "AAAABQAAAFRFbWl0dGVkIHdoZW4gYSBidXllciB3aXRoZHJhd3MgYSBjb2xsZWN0aW9uIGJpZCBhbmQgZ2V0cyB0aGUgcmVzdCBvZiB0aGUKZXNjcm93IGJhY2sAAAAAAAAAE0NvbGxlY3Rpb25CaWRDYW5jZWwAAAAAAQAAABVjb2xsZWN0aW9uX2JpZF9jYW5jZWwAAAAAAAADAAAAAAAAAAZiaWRfaWQAAAAAAAQAAAABAAAAAAAAAAVidXllcgAAAAAAABMAAAABAAAAAAAAAAZyZWZ1bmQAAAAAAAsAAAAAAAAAAg==",
+        "AAAAAAAAAChHZXQgYSBidXllcidzIG9mZmVyIGZvciBhIHRpY2tldCwgaWYgYW55AAAACWdldF9vZmZlcgAAAAAAAAIAAAAAAAAACXRpY2tldF9pZAAAAAAAAAQAAAAAAAAABWJ1eWVyAAAAAAAAEwAAAAEAAAPoAAAH0AAAAAtUaWNrZXRPZmZlcgA=",
+        "AAAAAAAAAC1HZXQgYWxsIHRpZXJzIHdpdGggdGhlaXIgcHJpbWFyeSBzYWxlcyBzbyBmYXIAAAAAAAAJZ2V0X3RpZXJzAAAAAAAAAAAAAAEAAAPpAAAD6gAAB9AAAAAEVGllcgAAAAM=",
+        "AAAAAAAAAIVHZXQgdGhlIG1ldGFkYXRhIFVSSSBvZiBhIHRpY2tldC4gQSBwZXItdGlja2V0IG92ZXJyaWRlIHdpbnMsIHRoZW4gdGhlCmJhc2UgVVJJIGZvbGxvd2VkIGJ5IHRoZSB0aWNrZXQgaWQsIHRoZW4gdGhlIGV2ZW50J3MgbWV0YWRhdGEuAAAAAAAACXRva2VuX3VyaQAAAAAAAAEAAAAAAAAACXRpY2tldF9pZAAAAAAAAAQAAAABAAAD6QAAABAAAAAD",
+        "AAAAAAAAAEVHZXQgYWxsIG9mZmVycyBmb3IgYSB0aWNrZXQsIGluY2x1ZGluZyBleHBpcmVkIG9uZXMgbm90IHlldCBjYW5jZWxsZWQAAAAAAAAKZ2V0X29mZmVycwAAAAAAAQAAAAAAAAAJdGlja2V0X2lkAAAAAAAABAAAAAEAAAPqAAAH0AAAAAtUaWNrZXRPZmZlcgA=",
+        "AAAAAAAAABZHZXQgdGlja2V0IGluZm9ybWF0aW9uAAAAAAAKZ2V0X3RpY2tldAAAAAAAAQAAAAAAAAAJdGlja2V0X2lkAAAAAAAABAAAAAEAAAPpAAAH0AAAAApUaWNrZXREYXRhAAAAAAAD",
+        "AAAAAAAAAEFDaGVjayB3aGV0aGVyIHRoZSBwcmltYXJ5IHNhbGUgaXMgb3BlbiBhdCB0aGUgY3VycmVudCBsZWRnZXIgdGltZQAAAAAAAAppc19vbl9zYWxlAAAAAAAAAAAAAQAAA+kAAAABAAAAAw==",
+        "AAAAAAAAAM9PZmZlciB0byBidXkgYSB0aWNrZXQsIGxpc3RlZCBvciBub3QsIGhvbGRpbmcgYHByaWNlYCBpbiBlc2Nyb3cgdW50aWwKdGhlIG9mZmVyIGlzIGFjY2VwdGVkIG9yIGNhbmNlbGxlZC4gT25lIG9mZmVyIHBlciBidXllciBhbmQgdGlja2V0LAphbmQgYE9mZmVyRXhpc3RzYCBvbmNlIHRoZSB0aWNrZXQgaGFzIGBNQVhfT0ZGRVJTX1BFUl9USUNLRVRgIG9mZmVycy4AAAAACm1ha2Vfb2ZmZXIAAAAAAAQAAAAAAAAABWJ1eWVyAAAAAAAAEwAAAAAAAAAJdGlja2V0X2lkAAAAAAAABAAAAAAAAAAFcHJpY2UAAAAAAAALAAAAAAAAAAZleHBpcnkAAAAAAAYAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAUJIb2xkIHByaW1hcnkgc2FsZSBwYXltZW50cyBpbiB0aGUgY29udHJhY3QgaW5zdGVhZCBvZiBwYXlpbmcgdGhlCmNyZWF0b3Igc3RyYWlnaHQgYXdheSwgc28gYnV5ZXJzIGNhbiBiZSByZWZ1bmRlZCBpZiB0aGUgZXZlbnQgaXMKY2FuY2VsbGVkIChldmVudCBjcmVhdG9yIG9ubHkpLiBNdXN0IGJlIGNob3NlbiBiZWZvcmUgYW55IHRpY2tldCBpcyBzb2xkLgpUaGUgcGF5bWVudCBmb3IgYSB0aWNrZXQgaXMgcmVsZWFzZWQgd2hlbiBpdCBpcyBjaGVja2VkIGluLCBhbmQKZXZlcnl0aGluZyBzdGlsbCBoZWxkIGlzIHJlbGVhc2VkIG9uY2UgdGhlIGV2ZW50IGVuZHMuAAAAAAAKc2V0X2VzY3JvdwAAAAAAAgAAAAAAAAAHY3JlYXRvcgAAAAATAAAAAAAAAAdlbmFibGVkAAAAAAEAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAABtHZXQgdGhlIGF1Y3Rpb24gb2YgYSB0aWNrZXQAAAAAC2dldF9hdWN0aW9uAAAAAAEAAAAAAAAACXRpY2tldF9pZAAAAAAAAAQAAAABAAAD6QAAB9AAAAAHQXVjdGlvbgAAAAAD",
+        "AAAAAAAAADBHZXQgdGhlIGZhY3RvcnkgdGhhdCBkZXBsb3llZCB0aGlzIGV2ZW50LCBpZiBhbnkAAAALZ2V0X2ZhY3RvcnkAAAAAAAAAAAEAAAPoAAAAEw==",
+        "AAAAAAAAACVHZXQgdGhlIHByZXNhbGUgY29uZmlndXJhdGlvbiwgaWYgYW55AAAAAAAAC2dldF9wcmVzYWxlAAAAAAAAAAABAAAD6AAAB9AAAAANUHJlc2FsZUNvbmZpZwAAAA==",
+        "AAAAAAAAADZDaGVjayB3aGV0aGVyIHByaW1hcnkgc2FsZSBwYXltZW50cyBhcmUgaGVsZCBpbiBlc2Nyb3cAAAAAAAtpc19lc2Nyb3dlZAAAAAAAAAAAAQAAAAE=",
+        "AAAAAAAAAHBMaXN0IHRpY2tldCBvbiBzZWNvbmRhcnkgbWFya2V0cGxhY2UsIG9wdGlvbmFsbHkgdW50aWwgYGV4cGlyZXNfYXRgLgpBIGxhcHNlZCBsaXN0aW5nIG9mIHRoZSB0aWNrZXQgaXMgcmVwbGFjZWQuAAAAC2xpc3RfdGlja2V0AAAAAAQAAAAAAAAABnNlbGxlcgAAAAAAEwAAAAAAAAAJdGlja2V0X2lkAAAAAAAABAAAAAAAAAAFcHJpY2UAAAAAAAALAAAAAAAAAApleHBpcmVzX2F0AAAAAAPoAAAABgAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAFFNaW50IGEgdGlja2V0IG9mIHRoZSBnaXZlbiB0aWVyIGZyb20gcHJpbWFyeSBtYXJrZXRwbGFjZSAoYnV5IGZyb20gZXZlbnQgY3JlYXRvcikAAAAAAAALbWludF90aWNrZXQAAAAAAgAAAAAAAAAFYnV5ZXIAAAAAAAATAAAAAAAAAAd0aWVyX2lkAAAAAAQAAAABAAAD6QAAAAQAAAAD",
+        "AAAAAAAAAMFSZWNvcmQgdGhlIGZhY3RvcnkgdGhhdCBkZXBsb3llZCB0aGlzIGV2ZW50IGFuZCB0aGUgcHJvdG9jb2wgZmVlIGl0CmNoYXJnZXMuIEJvdGggdGhlIGZhY3RvcnkgYW5kIHRoZSBldmVudCBjcmVhdG9yIG11c3QgYXV0aG9yaXplIHRoaXMsCmFuZCBpdCBjYW4gb25seSBiZSBkb25lIG9uY2UsIGJlZm9yZSBhbnkgdGlja2V0IGlzIHNvbGQuAAAAAAAAC3NldF9mYWN0b3J5AAAAAAIAAAAAAAAAB2ZhY3RvcnkAAAAAEwAAAAAAAAAMcHJvdG9jb2xfZmVlAAAH0AAAAAtQcm90b2NvbEZlZQAAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAIlDb25maWd1cmUgdGhlIGFsbG93bGlzdCBwcmVzYWxlLCBvciByZXBsYWNlIGl0cyByb290LCBwcmljZSBvciBjYXAKKGV2ZW50IGNyZWF0b3Igb25seSkuIFRoZSBwcmVzYWxlIGhhcyB0byBzdGFydCBiZWZvcmUgdGhlIHB1YmxpYyBzYWxlLgAAAAAAAAtzZXRfcHJlc2FsZQAAAAACAAAAAAAAAAdjcmVhdG9yAAAAABMAAAAAAAAAB3ByZXNhbGUAAAAH0AAAAA1QcmVzYWxlQ29uZmlnAAAAAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAJ1BY2NlcHQgYW4gdW5leHBpcmVkIG9mZmVyIGZvciBhIHRpY2tldCAoY3VycmVudCBvd25lciBvbmx5KS4gVGhlCmVzY3Jvd2VkIHByaWNlIGlzIHBhaWQgb3V0IGxpa2UgYSBzZWNvbmRhcnkgc2FsZSBhbmQgYW55IGxpc3Rpbmcgb2YKdGhlIHRpY2tldCBpcyB3aXRoZHJhd24uAAAAAAAADGFjY2VwdF9vZmZlcgAAAAMAAAAAAAAABnNlbGxlcgAAAAAAEwAAAAAAAAAJdGlja2V0X2lkAAAAAAAABAAAAAAAAAAFYnV5ZXIAAAAAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAL9DYW5jZWwgdGhlIGV2ZW50IGJlZm9yZSBpdCBlbmRzIChldmVudCBjcmVhdG9yIG9ubHkpLiBNaW50aW5nLCB0cmFkaW5nLAp0cmFuc2ZlcnMgYW5kIGNoZWNrLWluIHN0b3AgZm9yIGdvb2QsIGFuZCBob2xkZXJzIG9mIGVzY3Jvd2VkIHRpY2tldHMKY2FuIGNsYWltIHRoZWlyIHBheW1lbnQgYmFjayB3aXRoIGBjbGFpbV9yZWZ1bmRgLgAAAAAMY2FuY2VsX2V2ZW50AAAAAQAAAAAAAAAHY3JlYXRvcgAAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAJJXaXRoZHJhdyBhbiBvZmZlciBhbmQgZ2V0IHRoZSBlc2Nyb3dlZCBwcmljZSBiYWNrLiBUaGlzIGlzIGFsc28gaG93CmJ1eWVycyByZWNvdmVyIGV4cGlyZWQgb2ZmZXJzIG9yIG9mZmVycyBvbiB0aWNrZXRzIHRoYXQgY2FuIG5vIGxvbmdlcgpiZSBzb2xkLgAAAAAADGNhbmNlbF9vZmZlcgAAAAIAAAAAAAAABWJ1eWVyAAAAAAAAEwAAAAAAAAAJdGlja2V0X2lkAAAAAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAOJQYXkgYSBEdXRjaCBhdWN0aW9uIGJ1eWVyIGJhY2sgd2hhdCB0aGV5IHBhaWQgZm9yIGEgdGlja2V0IGFib3ZlIHRoZQpjbGVhcmluZyBwcmljZSAob3JpZ2luYWwgYnV5ZXIgb25seSksIG9uY2UuIFJlYmF0ZXMgc3RheSBjbGFpbWFibGUKYWZ0ZXIgdGhlIHRpY2tldCBpcyByZXNvbGQgb3IgY2hlY2tlZCBpbiBhbmQgYWZ0ZXIgdGhlIGNyZWF0b3IgaGFzCndpdGhkcmF3biB0aGUgcHJvY2VlZHMuAAAAAAAMY2xhaW1fcmViYXRlAAAAAQAAAAAAAAAJdGlja2V0X2lkAAAAAAAABAAAAAEAAAPpAAAACwAAAAM=",
+        "AAAAAAAAAI9SZWZ1bmQgdGhlIHByaWNlIHBhaWQgZm9yIGEgdGlja2V0IG9mIGEgY2FuY2VsbGVkIGV2ZW50IHRvIGl0cyBvd25lci4KT25seSB1bnVzZWQgdGlja2V0cyBib3VnaHQgd2l0aCBlc2Nyb3cgZW5hYmxlZCBhcmUgcmVmdW5kYWJsZSwgb25jZSBlYWNoLgAAAAAMY2xhaW1fcmVmdW5kAAAAAQAAAAAAAAAJdGlja2V0X2lkAAAAAAAABAAAAAEAAAPpAAAACwAAAAM=",
+        "AAAAAAAAADRHZXQgdGhlIGFkZHJlc3MgYXBwcm92ZWQgdG8gdHJhbnNmZXIgYSBzaW5nbGUgdGlja2V0AAAADGdldF9hcHByb3ZlZAAAAAEAAAAAAAAACXRpY2tldF9pZAAAAAAAAAQAAAABAAAD6QAAA+gAAAATAAAAAw==",
+        "AAAAAAAAACVHZXQgdGhlIGJhc2UgVVJJLCBpZiBvbmUgaGFzIGJlZW4gc2V0AAAAAAAADGdldF9iYXNlX3VyaQAAAAAAAAABAAAD6AAAABA=",
+        "AAAAAAAAACpDaGVjayB3aGV0aGVyIHRoZSBldmVudCBoYXMgYmVlbiBjYW5jZWxsZWQAAAAAAAxpc19jYW5jZWxsZWQAAAAAAAAAAQAAAAE=",
+        "AAAAAAAAAI1NaW50IHByZXNhbGUgdGlja2V0cyBhdCB0aGUgcHJlc2FsZSBwcmljZS4gVGhlIGJ1eWVyIHByb3ZlcyB0aGV5IGFyZSBvbgp0aGUgYWxsb3dsaXN0IHdpdGggdGhlIHNpYmxpbmcgaGFzaGVzIGZyb20gdGhlaXIgbGVhZiB1cCB0byB0aGUgcm9vdC4AAAAAAAAMbWludF9wcmVzYWxlAAAAAwAAAAAAAAAFYnV5ZXIAAAAAAAATAAAAAAAAAAhxdWFudGl0eQAAAAQAAAAAAAAABXByb29mAAAAAAAD6gAAA+4AAAAgAAAAAQAAA+kAAAPqAAAABAAAAAM=",
+        "AAAAAAAAAMFNaW50IHNldmVyYWwgdGlja2V0cyBvZiBvbmUgdGllciBpbiBhIHNpbmdsZSBwdXJjaGFzZS4gVGhlIGJ1eWVyIHBheXMKb25jZSBmb3IgdGhlIHdob2xlIGJhdGNoIGFuZCByZWNlaXZlcyBjb25zZWN1dGl2ZSB0aWNrZXQgaWRzOyBpZiB0aGUKdGllciBjYW5ub3QgY292ZXIgdGhlIGZ1bGwgcXVhbnRpdHkgbm90aGluZyBpcyBtaW50ZWQuAAAAAAAADG1pbnRfdGlja2V0cwAAAAMAAAAAAAAABWJ1eWVyAAAAAAAAEwAAAAAAAAAHdGllcl9pZAAAAAAEAAAAAAAAAAhxdWFudGl0eQAAAAQAAAABAAAD6QAAA+oAAAAEAAAAAw==",
+        "AAAAAAAAAEVTZXQgdGhlIGJhc2UgVVJJIHRoYXQgdGlja2V0IGlkcyBhcmUgYXBwZW5kZWQgdG8gKGV2ZW50IGNyZWF0b3Igb25seSkAAAAAAAAMc2V0X2Jhc2VfdXJpAAAAAgAAAAAAAAAHY3JlYXRvcgAAAAATAAAAAAAAAAhiYXNlX3VyaQAAABAAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAHxQYXkgb3V0IHdoYXQgYW4gYWRkcmVzcyBpcyBvd2VkIGZyb20gYXVjdGlvbnM6IGJpZHMgdGhhdCB3ZXJlIG91dGJpZApvciByZWZ1bmRlZCwgYW5kIHRoZSBzZWxsZXIncyBzaGFyZSBvZiBzZXR0bGVkIGF1Y3Rpb25zAAAADWNsYWltX2JhbGFuY2UAAAAAAAABAAAAAAAAAAVvd25lcgAAAAAAABMAAAABAAAD6QAAAAsAAAAD",
+        "AAAAAAAAAChEZWxpc3QgdGlja2V0IGZyb20gc2Vjb25kYXJ5IG1hcmtldHBsYWNlAAAADWRlbGlzdF90aWNrZXQAAAAAAAACAAAAAAAAAAZzZWxsZXIAAAAAABMAAAAAAAAACXRpY2tldF9pZAAAAAAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAADRHZXQgd2hhdCBhbiBhZGRyZXNzIGNhbiBjb2xsZWN0IHdpdGggYGNsYWltX2JhbGFuY2VgAAAADWdldF9jbGFpbWFibGUAAAAAAAABAAAAAAAAAAVvd25lcgAAAAAAABMAAAABAAAACw==",
+        "AAAAAAAAAHFHZXQgaG93IG1hbnkgdGlja2V0cyBhbiBhZGRyZXNzIGhhcyBib3VnaHQgb24gdGhlIHByaW1hcnkgbWFya2V0LgpUcmFuc2ZlcnMgYW5kIHJlc2FsZXMgZG8gbm90IHJlZHVjZSB0aGlzIGNvdW50LgAAAAAAAA1nZXRfcHVyY2hhc2VkAAAAAAAAAQAAAAAAAAAFYnV5ZXIAAAAAAAATAAAAAQAAAAQ=",
+        "AAAAAAAAAMdQdXQgYW4gdW51c2VkLCB1bmxpc3RlZCB0aWNrZXQgdXAgZm9yIGF1Y3Rpb24gdW50aWwgYGVuZF90aW1lYCwgd2hpY2gKbXVzdCBiZSBubyBsYXRlciB0aGFuIHRoZSBzdGFydCBvZiB0aGUgZXZlbnQgKHRpY2tldCBvd25lciBvbmx5KS4KQXVjdGlvbmVkIHRpY2tldHMgY291bnQgYXMgbGlzdGVkIHVudGlsIHRoZSBhdWN0aW9uIGlzIHNldHRsZWQuAAAAAA1zdGFydF9hdWN0aW9uAAAAAAAAAwAAAAAAAAAJdGlja2V0X2lkAAAAAAAABAAAAAAAAAAHcmVzZXJ2ZQAAAAALAAAAAAAAAAhlbmRfdGltZQAAAAYAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAM1UcmFuc2ZlciBhIHRpY2tldCBvbiBiZWhhbGYgb2YgaXRzIG93bmVyLiBUaGUgc3BlbmRlciBtdXN0IGJlIHRoZSBvd25lciwKdGhlIHRpY2tldCdzIGFwcHJvdmVkIGFkZHJlc3Mgb3IgYW4gb3BlcmF0b3Igb2YgdGhlIG93bmVyLiBVc2VkIGFuZApsaXN0ZWQgdGlja2V0cyBjYW5ub3QgYmUgdHJhbnNmZXJyZWQsIGFzIHdpdGggYHRyYW5zZmVyX3RpY2tldGAuAAAAAAAADXRyYW5zZmVyX2Zyb20AAAAAAAAEAAAAAAAAAAdzcGVuZGVyAAAAABMAAAAAAAAABGZyb20AAAATAAAAAAAAAAJ0bwAAAAAAEwAAAAAAAAAJdGlja2V0X2lkAAAAAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAABVHZXQgZXZlbnQgaW5mb3JtYXRpb24AAAAAAAAOZ2V0X2V2ZW50X2luZm8AAAAAAAAAAAABAAAD6QAAB9AAAAAJRXZlbnRJbmZvAAAAAAAAAw==",
+        "AAAAAAAAABpHZXQgdGhlIHN0b3JhZ2UgVFRMIHBvbGljeQAAAAAADmdldF90dGxfcG9saWN5AAAAAAAAAAAAAQAAB9AAAAAJVHRsUG9saWN5AAAA",
+        "AAAAAAAAAI1TZXQgb3IgY2xlYXIgYSB0aWNrZXQncyBvd24gbWV0YWRhdGEgVVJJIChldmVudCBjcmVhdG9yIG9ubHkpLiBUaWNrZXRzCnRoYXQgaGF2ZSBub3QgYmVlbiBtaW50ZWQgeWV0IGNhbiBiZSBnaXZlbiBzZWF0LXNwZWNpZmljIG1ldGFkYXRhIHRvby4AAAAAAAAOc2V0X3RpY2tldF91cmkAAAAAAAMAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAAAAAAJdGlja2V0X2lkAAAAAAAABAAAAAAAAAADdXJpAAAAA+gAAAAQAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAD1TZXQgaG93IGxvbmcgZXZlbnQgc3RvcmFnZSBpcyBrZXB0IGFsaXZlIChldmVudCBjcmVhdG9yIG9ubHkpAAAAAAAADnNldF90dGxfcG9saWN5AAAAAAACAAAAAAAAAAdjcmVhdG9yAAAAABMAAAAAAAAABnBvbGljeQAAAAAH0AAAAAlUdGxQb2xpY3kAAAAAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAYRDbG9zZSBhbiBhdWN0aW9uIG9uY2UgaXQgaGFzIGVuZGVkIChjYWxsYWJsZSBieSBhbnlvbmUpLiBUaGUgdGlja2V0CmdvZXMgdG8gdGhlIGhpZ2hlc3QgYmlkZGVyLCB3aXRoIHRoZSB3aW5uaW5nIGJpZCBwYWlkIG91dCBsaWtlIGEKc2Vjb25kYXJ5IHNhbGUgZXhjZXB0IHRoYXQgdGhlIHNlbGxlcidzIHNoYXJlIGlzIGNyZWRpdGVkIHRvCmBjbGFpbV9iYWxhbmNlYC4gSWYgdGhlcmUgd2VyZSBubyBiaWRzIHRoZSB0aWNrZXQgc3RheXMgd2l0aCB0aGUKc2VsbGVyLCBhbmQgaWYgdGhlIGV2ZW50IHdhcyBjYW5jZWxsZWQgb3IgdGhlIHRpY2tldCBjaGVja2VkIGluCm1lYW53aGlsZSB0aGUgaGlnaGVzdCBiaWQgaXMgY3JlZGl0ZWQgYmFjayB0byBpdHMgYmlkZGVyIGluc3RlYWQuAAAADnNldHRsZV9hdWN0aW9uAAAAAAABAAAAAAAAAAl0aWNrZXRfaWQAAAAAAAAEAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAEdQZXJtYW5lbnRseSBwcmV2ZW50IGZ1cnRoZXIgY2hhbmdlcyB0byB0aWNrZXQgVVJJcyAoZXZlbnQgY3JlYXRvciBvbmx5KQAAAAAPZnJlZXplX21ldGFkYXRhAAAAAAEAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAB5UcmFuc2ZlciB0aWNrZXQgZGlyZWN0bHkgKFAyUCkAAAAAAA90cmFuc2Zlcl90aWNrZXQAAAAAAwAAAAAAAAAEZnJvbQAAABMAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAl0aWNrZXRfaWQAAAAAAAAEAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAHRFeHRlbmQgdGhlIFRUTCBvZiB0aGUgY29udHJhY3QgaW5zdGFuY2UsIGNvZGUgYW5kIGV2ZW50IGluZm9ybWF0aW9uLgpBbnlvbmUgbWF5IGNhbGwgdGhpcyBhbmQgcGF5IGZvciB0aGUgZXh0ZW5zaW9uLgAAABBleHRlbmRfZXZlbnRfdHRsAAAAAAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAFFHZXQgdGhlIGF1Y3Rpb25zIHRoYXQgaGF2ZSBub3QgYmVlbiBzZXR0bGVkIGFtb25nIGBsaW1pdGAgdGlja2V0IGlkcwpmcm9tIGBzdGFydGAAAAAAAAAQZ2V0X2FsbF9hdWN0aW9ucwAAAAIAAAAAAAAABXN0YXJ0AAAAAAAABAAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAA+oAAAfQAAAAB0F1Y3Rpb24A",
+        "AAAAAAAAAD1HZXQgdGhlIHByb3RvY29sIGZlZSBjaGFyZ2VkIGJ5IHRoZSBkZXBsb3lpbmcgZmFjdG9yeSwgaWYgYW55AAAAAAAAEGdldF9wcm90b2NvbF9mZWUAAAAAAAAAAQAAA+gAAAfQAAAAC1Byb3RvY29sRmVlAA==",
         "AAAAAAAAABlHZXQgdGlja2V0cyBvd25lZCBieSB1c2VyAAAAAAAAEGdldF91c2VyX3RpY2tldHMAAAABAAAAAAAAAAR1c2VyAAAAEwAAAAEAAAPqAAAABA==",
-        "AAAAAAAAACdNYXJrIHRpY2tldCBhcyB1c2VkIChjaGVjay1pbiBhdCBldmVudCkAAAAAEG1hcmtfdGlja2V0X3VzZWQAAAACAAAAAAAAAAdjcmVhdG9yAAAAABMAAAAAAAAACXRpY2tldF9pZAAAAAAAAAQAAAAA",
+        "AAAAAAAAAIdNYXJrIHRpY2tldCBhcyB1c2VkIChjaGVjay1pbiBhdCBldmVudCkuIENoZWNrLWluIG9wZW5zIGF0IHRoZSBzdGFydApvZiB0aGUgZXZlbnQsIHNvIGVzY3Jvd2VkIHBheW1lbnRzIGNhbm5vdCBiZSByZWxlYXNlZCBiZWZvcmUgdGhlbi4AAAAAEG1hcmtfdGlja2V0X3VzZWQAAAACAAAAAAAAAAdjcmVhdG9yAAAAABMAAAAAAAAACXRpY2tldF9pZAAAAAAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAANNFeHRlbmQgdGhlIFRUTCBvZiBhIHRpY2tldCBhbmQgdGhlIGVudHJpZXMgdGllZCB0byBpdDogaXRzIGxpc3RpbmcsCmF1Y3Rpb24sIG9mZmVycywgYXBwcm92YWwsIG1ldGFkYXRhIFVSSSwgZXNjcm93ZWQgcGF5bWVudCwgcmViYXRlIGFuZAp0aGUgb3duZXIncyB0aWNrZXQgaW5kZXguCkFueW9uZSBtYXkgY2FsbCB0aGlzIGFuZCBwYXkgZm9yIHRoZSBleHRlbnNpb24uAAAAABFleHRlbmRfdGlja2V0X3R0bAAAAAAAAAEAAAAAAAAACXRpY2tldF9pZAAAAAAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAENHZXQgdGhlIHByaWNlIGEgdGlja2V0IG9mIGEgdGllciBjb3N0cyBvbiB0aGUgcHVibGljIHNhbGUgcmlnaHQgbm93AAAAABFnZXRfY3VycmVudF9wcmljZQAAAAAAAAEAAAAAAAAAB3RpZXJfaWQAAAAABAAAAAEAAAPpAAAACwAAAAM=",
+        "AAAAAAAAACtHZXQgdGhlIER1dGNoIGF1Y3Rpb24gY29uZmlndXJhdGlvbiwgaWYgYW55AAAAABFnZXRfZHV0Y2hfYXVjdGlvbgAAAAAAAAAAAAABAAAD6AAAB9AAAAAMRHV0Y2hBdWN0aW9u",
+        "AAAAAAAAACpHZXQgdGhlIGxpbWl0cyBvbiBzZWNvbmRhcnkgbGlzdGluZyBwcmljZXMAAAAAABFnZXRfcmVzYWxlX3BvbGljeQAAAAAAAAAAAAABAAAH0AAAAAxSZXNhbGVQb2xpY3k=",
+        "AAAAAAAAAM5TZWxsIGEgdGllciBhdCBhIGRlc2NlbmRpbmcgcHJpY2UgaW5zdGVhZCBvZiBpdHMgZml4ZWQgcHJpY2UgKGV2ZW50CmNyZWF0b3Igb25seSkuIE11c3QgYmUgc2V0IGJlZm9yZSBhbnkgdGlja2V0IGlzIHNvbGQuIFJlYmF0ZXMgbmVlZCB0aGUKcGF5bWVudHMgYXQgaGFuZCwgc28gdGhleSBhbHNvIGhvbGQgYWxsIHByaW1hcnkgcGF5bWVudHMgaW4gZXNjcm93LgAAAAAAEXNldF9kdXRjaF9hdWN0aW9uAAAAAAAAAgAAAAAAAAAHY3JlYXRvcgAAAAATAAAAAAAAAAdhdWN0aW9uAAAAB9AAAAAMRHV0Y2hBdWN0aW9uAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAIRTZXQgdGhlIGxpbWl0cyBvbiBzZWNvbmRhcnkgbGlzdGluZyBwcmljZXMgKGV2ZW50IGNyZWF0b3Igb25seSkuCkxpc3RpbmdzIG1hZGUgYmVmb3JlIGEgY2hhbmdlIGtlZXAgdGhlaXIgcHJpY2UgdW50aWwgaXQgaXMgdXBkYXRlZC4AAAARc2V0X3Jlc2FsZV9wb2xpY3kAAAAAAAACAAAAAAAAAAdjcmVhdG9yAAAAABMAAAAAAAAABnBvbGljeQAAAAAH0AAAAAxSZXNhbGVQb2xpY3kAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAJhXaXRoZHJhdyB0aGUgZXNjcm93ZWQgcHJvY2VlZHMgcmVsZWFzZWQgc28gZmFyIChldmVudCBjcmVhdG9yIG9ubHkpLApsZXNzIHRoZSBwcm90b2NvbCdzIHNoYXJlIG9mIHByaW1hcnkgc2FsZXMuIFJldHVybnMgdGhlIGFtb3VudCBwYWlkIHRvCnRoZSBjcmVhdG9yLgAAABF3aXRoZHJhd19wcm9jZWVkcwAAAAAAAAEAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAEAAAPpAAAACwAAAAM=",
+        "AAAAAAAAAIBHZXQgdGhlIER1dGNoIGF1Y3Rpb24ncyBjbGVhcmluZyBwcmljZSwgdGhlIHByaWNlIG9mIHRoZSBsYXN0IHRpY2tldApzb2xkLiBJdCBpcyBrbm93biBvbmNlIHRoZSB0aWVyIHNlbGxzIG91dCBvciB0aGUgc2FsZSBlbmRzLgAAABJnZXRfY2xlYXJpbmdfcHJpY2UAAAAAAAAAAAABAAAD6QAAA+gAAAALAAAAAw==",
+        "AAAAAAAAABpHZXQgYSBjb2xsZWN0aW9uIGJpZCBieSBpZAAAAAAAEmdldF9jb2xsZWN0aW9uX2JpZAAAAAAAAQAAAAAAAAAGYmlkX2lkAAAAAAAEAAAAAQAAA+kAAAfQAAAADUNvbGxlY3Rpb25CaWQAAAAAAAAD",
+        "AAAAAAAAAD1HZXQgdGhlIHByaW1hcnkgc2FsZSBwYXltZW50cyBoZWxkIGluIGVzY3JvdywgcmVsZWFzZWQgb3Igbm90AAAAAAAAEmdldF9lc2Nyb3dfYmFsYW5jZQAAAAAAAAAAAAEAAAAL",
+        "AAAAAAAAAEZHZXQgdGhlIGV2ZW50LXdpZGUgY2FwIG9uIHByaW1hcnkgcHVyY2hhc2VzIHBlciBhZGRyZXNzICgwIGZvciBubyBjYXApAAAAAAASZ2V0X21heF9wZXJfd2FsbGV0AAAAAAAAAAAAAQAAAAQ=",
         "AAAAAAAAABxHZXQgbnVtYmVyIG9mIHRpY2tldHMgbWludGVkAAAAEmdldF90aWNrZXRzX21pbnRlZAAAAAAAAAAAAAEAAAAE",
-        "AAAAAAAAACVCdXkgdGlja2V0IGZyb20gc2Vjb25kYXJ5IG1hcmtldHBsYWNlAAAAAAAAFGJ1eV9zZWNvbmRhcnlfdGlja2V0AAAAAgAAAAAAAAAFYnV5ZXIAAAAAAAATAAAAAAAAAAl0aWNrZXRfaWQAAAAAAAAEAAAAAA==",
-        "AAAAAAAAABRVcGRhdGUgbGlzdGluZyBwcmljZQAAABR1cGRhdGVfbGlzdGluZ19wcmljZQAAAAMAAAAAAAAABnNlbGxlcgAAAAAAEwAAAAAAAAAJdGlja2V0X2lkAAAAAAAABAAAAAAAAAAJbmV3X3ByaWNlAAAAAAAACwAAAAA=",
+        "AAAAAAAAAEFHZXQgbnVtYmVyIG9mIHRpY2tldHMgb2YgYSB0aWVyIHN0aWxsIGF2YWlsYWJsZSBpbiBwcmltYXJ5IG1hcmtldAAAAAAAABJnZXRfdGllcl9hdmFpbGFibGUAAAAAAAEAAAAAAAAAB3RpZXJfaWQAAAAABAAAAAEAAAPpAAAABAAAAAM=",
+        "AAAAAAAAAC1DaGVjayB3aGV0aGVyIHRpY2tldCBtZXRhZGF0YSBoYXMgYmVlbiBmcm96ZW4AAAAAAAASaXNfbWV0YWRhdGFfZnJvemVuAAAAAAAAAAAAAQAAAAE=",
+        "AAAAAAAAAHxDYXAgaG93IG1hbnkgdGlja2V0cyBvbmUgYWRkcmVzcyBtYXkgYnV5IG9uIHRoZSBwcmltYXJ5IG1hcmtldCBhY3Jvc3MKYWxsIHRpZXJzLCBvciBsaWZ0IHRoZSBjYXAgd2l0aCAwIChldmVudCBjcmVhdG9yIG9ubHkpAAAAEnNldF9tYXhfcGVyX3dhbGxldAAAAAAAAgAAAAAAAAAHY3JlYXRvcgAAAAATAAAAAAAAAA5tYXhfcGVyX3dhbGxldAAAAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAMlTcGxpdCB0aGUgY3JlYXRvciBmZWUgb24gc2Vjb25kYXJ5IHNhbGVzIGJldHdlZW4gc2V2ZXJhbCByZWNpcGllbnRzCihldmVudCBjcmVhdG9yIG9ubHkpLiBTaGFyZXMgYXJlIGluIGJhc2lzIHBvaW50cyBvZiB0aGUgZmVlIGFuZCBtYXkKdG90YWwgYXQgbW9zdCAxMDAwMDsgdGhlIHJlbWFpbmRlciBnb2VzIHRvIGByZW1haW5kZXJfcmVjaXBpZW50YC4AAAAAAAASc2V0X3JveWFsdHlfc3BsaXRzAAAAAAADAAAAAAAAAAdjcmVhdG9yAAAAABMAAAAAAAAABnNwbGl0cwAAAAAD6gAAB9AAAAAMUm95YWx0eVNwbGl0AAAAAAAAABNyZW1haW5kZXJfcmVjaXBpZW50AAAAABMAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAIBTZWxsIGFuIHVudXNlZCwgdW5saXN0ZWQgdGlja2V0IGludG8gYSBjb2xsZWN0aW9uIGJpZCAodGlja2V0IG93bmVyCm9ubHkpLiBUaGUgZXNjcm93ZWQgcHJpY2UgaXMgcGFpZCBvdXQgbGlrZSBhIHNlY29uZGFyeSBzYWxlLgAAABNmaWxsX2NvbGxlY3Rpb25fYmlkAAAAAAMAAAAAAAAABnNlbGxlcgAAAAAAEwAAAAAAAAAGYmlkX2lkAAAAAAAEAAAAAAAAAAl0aWNrZXRfaWQAAAAAAAAEAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAD9HZXQgdGhlIG9wZW4gY29sbGVjdGlvbiBiaWRzIGFtb25nIGBsaW1pdGAgYmlkIGlkcyBmcm9tIGBzdGFydGAAAAAAE2dldF9jb2xsZWN0aW9uX2JpZHMAAAAAAgAAAAAAAAAFc3RhcnQAAAAAAAAEAAAAAAAAAAVsaW1pdAAAAAAAAAQAAAABAAAD6gAAB9AAAAANQ29sbGVjdGlvbkJpZAAAAA==",
+        "AAAAAAAAAEFDaGVjayB3aGV0aGVyIGFuIG9wZXJhdG9yIG1heSB0cmFuc2ZlciBhbGwgb2YgdGhlIG93bmVyJ3MgdGlja2V0cwAAAAAAABNpc19hcHByb3ZlZF9mb3JfYWxsAAAAAAIAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAIb3BlcmF0b3IAAAATAAAAAQAAAAE=",
+        "AAAAAAAAACVCdXkgdGlja2V0IGZyb20gc2Vjb25kYXJ5IG1hcmtldHBsYWNlAAAAAAAAFGJ1eV9zZWNvbmRhcnlfdGlja2V0AAAAAgAAAAAAAAAFYnV5ZXIAAAAAAAATAAAAAAAAAAl0aWNrZXRfaWQAAAAAAAAEAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAADNHZXQgdGhlIHBheW1lbnQgaGVsZCBpbiBlc2Nyb3cgZm9yIGEgdGlja2V0LCBpZiBhbnkAAAAAFGdldF9lc2Nyb3dlZF9wYXltZW50AAAAAQAAAAAAAAAJdGlja2V0X2lkAAAAAAAABAAAAAEAAAPoAAAACw==",
+        "AAAAAAAAAEFHZXQgdGhlIGhpZ2hlc3QgcHJpY2UgYSB0aWNrZXQgbWF5IGJlIGxpc3RlZCBmb3IsIGlmIGl0IGlzIGNhcHBlZAAAAAAAABRnZXRfbWF4X3Jlc2FsZV9wcmljZQAAAAEAAAAAAAAACXRpY2tldF9pZAAAAAAAAAQAAAABAAAD6QAAA+gAAAALAAAAAw==",
+        "AAAAAAAAAINHZXQgaG93IG1hbnkgdGlja2V0cyBvZiBhIHRpZXIgdGhlIGJ1eWVyIG1heSBzdGlsbCBidXkgb24gdGhlIHByaW1hcnkKbWFya2V0LCBnaXZlbiB0aGUgd2FsbGV0IGNhcHMgYW5kIHRoZSB0aWVyJ3MgcmVtYWluaW5nIHN1cHBseQAAAAAUZ2V0X3dhbGxldF9hbGxvd2FuY2UAAAACAAAAAAAAAAVidXllcgAAAAAAABMAAAAAAAAAB3RpZXJfaWQAAAAABAAAAAEAAAPpAAAABAAAAAM=",
+        "AAAAAAAAAIBCaWQgZm9yIGBxdWFudGl0eWAgdGlja2V0cyBvZiBhbnkgdGllciwgb3Igb2YgYHRpZXJfaWRgLCBhdCBgcHJpY2VgCmVhY2gsIGhvbGRpbmcgdGhlIGZ1bGwgYW1vdW50IGluIGVzY3Jvdy4gUmV0dXJucyB0aGUgYmlkIGlkLgAAABRwbGFjZV9jb2xsZWN0aW9uX2JpZAAAAAQAAAAAAAAABWJ1eWVyAAAAAAAAEwAAAAAAAAAHdGllcl9pZAAAAAPoAAAABAAAAAAAAAAFcHJpY2UAAAAAAAALAAAAAAAAAAhxdWFudGl0eQAAAAQAAAABAAAD6QAAAAQAAAAD",
+        "AAAAAAAAAEhHcmFudCBvciByZXZva2UgYW4gb3BlcmF0b3IgdGhhdCBtYXkgdHJhbnNmZXIgYWxsIG9mIHRoZSBvd25lcidzIHRpY2tldHMAAAAUc2V0X2FwcHJvdmFsX2Zvcl9hbGwAAAADAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAACG9wZXJhdG9yAAAAEwAAAAAAAAAIYXBwcm92ZWQAAAABAAAAAA==",
+        "AAAAAAAAABRVcGRhdGUgbGlzdGluZyBwcmljZQAAABR1cGRhdGVfbGlzdGluZ19wcmljZQAAAAMAAAAAAAAABnNlbGxlcgAAAAAAEwAAAAAAAAAJdGlja2V0X2lkAAAAAAAABAAAAAAAAAAJbmV3X3ByaWNlAAAAAAAACwAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAFhXaXRoZHJhdyBhIGNvbGxlY3Rpb24gYmlkIGFuZCBnZXQgdGhlIGVzY3JvdyBmb3IgaXRzIHVuZmlsbGVkIHRpY2tldHMKYmFjayAoYmlkZGVyIG9ubHkpAAAAFWNhbmNlbF9jb2xsZWN0aW9uX2JpZAAAAAAAAAEAAAAAAAAABmJpZF9pZAAAAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAADJHZXQgaG93IG1hbnkgcHJlc2FsZSB0aWNrZXRzIGFuIGFkZHJlc3MgaGFzIGJvdWdodAAAAAAAFWdldF9wcmVzYWxlX3B1cmNoYXNlZAAAAAAAAAEAAAAAAAAABWJ1eWVyAAAAAAAAEwAAAAEAAAAE",
         "AAAAAAAAABVHZXQgc2Vjb25kYXJ5IGxpc3RpbmcAAAAAAAAVZ2V0X3NlY29uZGFyeV9saXN0aW5nAAAAAAAAAQAAAAAAAAAJdGlja2V0X2lkAAAAAAAABAAAAAEAAAPoAAAH0AAAABBTZWNvbmRhcnlMaXN0aW5n",
-        "AAAAAAAAADFHZXQgbnVtYmVyIG9mIHRpY2tldHMgYXZhaWxhYmxlIGluIHByaW1hcnkgbWFya2V0AAAAAAAAFWdldF90aWNrZXRzX2F2YWlsYWJsZQAAAAAAAAAAAAABAAAABA==",
-        "AAAAAAAAABpHZXQgYWxsIHNlY29uZGFyeSBsaXN0aW5ncwAAAAAAGmdldF9hbGxfc2Vjb25kYXJ5X2xpc3RpbmdzAAAAAAAAAAAAAQAAA+oAAAfQAAAAEFNlY29uZGFyeUxpc3Rpbmc=" ]),
+        "AAAAAAAAADFHZXQgbnVtYmVyIG9mIHRpY2tldHMgYXZhaWxhYmxlIGluIHByaW1hcnkgbWFya2V0AAAAAAAAFWdldF90aWNrZXRzX2F2YWlsYWJsZQAAAAAAAAAAAAABAAAD6QAAAAQAAAAD",
+        "AAAAAAAAACVHZXQgd2hvIG1heSB1cGdyYWRlIHRoZSBjb250cmFjdCBjb2RlAAAAAAAAFWdldF91cGdyYWRlX2F1dGhvcml0eQAAAAAAAAAAAAABAAAH0AAAABBVcGdyYWRlQXV0aG9yaXR5",
+        "AAAAAAAAALtIYW5kIHRoZSByaWdodCB0byB1cGdyYWRlIHRvIHRoZSBmYWN0b3J5IG9yIHRoZSBjcmVhdG9yLiBUaGUgY3VycmVudAp1cGdyYWRlIGF1dGhvcml0eSBtdXN0IGF1dGhvcml6ZSB0aGUgY2hhbmdlOyB0aGUgZmFjdG9yeSBkb2VzIHNvCnRocm91Z2ggaXRzIGFkbWluLW9ubHkgYHNldF9ldmVudF91cGdyYWRlX2F1dGhvcml0eWAuAAAAABVzZXRfdXBncmFkZV9hdXRob3JpdHkAAAAAAAABAAAAAAAAAAlhdXRob3JpdHkAAAAAAAfQAAAAEFVwZ3JhZGVBdXRob3JpdHkAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAFFHZXQgdGhlIGVzY3Jvd2VkIHByb2NlZWRzIHRoZSBjcmVhdG9yIGNvdWxkIHdpdGhkcmF3IG5vdywgYmVmb3JlIHRoZQpwcm90b2NvbCBmZWUAAAAAAAAZZ2V0X3dpdGhkcmF3YWJsZV9wcm9jZWVkcwAAAAAAAAAAAAABAAAD6QAAAAsAAAAD",
+        "AAAAAAAAAHZHZXQgdGhlIHNlY29uZGFyeSBsaXN0aW5ncyB0aGF0IGNhbiBzdGlsbCBiZSBib3VnaHQgYW1vbmcgYGxpbWl0YAp0aWNrZXQgaWRzIGZyb20gYHN0YXJ0YC4gQSBjYW5jZWxsZWQgZXZlbnQgaGFzIG5vbmUuAAAAAAAaZ2V0X2FsbF9zZWNvbmRhcnlfbGlzdGluZ3MAAAAAAAIAAAAAAAAABXN0YXJ0AAAAAAAABAAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAA+oAAAfQAAAAEFNlY29uZGFyeUxpc3Rpbmc=" ]),
       options
     )
   }
   public readonly fromJSON = {
-    init: this.txFromJSON<null>,
-        name: this.txFromJSON<string>,
-        symbol: this.txFromJSON<string>,
-        get_ticket: this.txFromJSON<TicketData>,
-        list_ticket: this.txFromJSON<null>,
-        mint_ticket: this.txFromJSON<u32>,
-        delist_ticket: this.txFromJSON<null>,
-        get_event_info: this.txFromJSON<EventInfo>,
-        transfer_ticket: this.txFromJSON<null>,
+    bid: this.txFromJSON<Result<readonly []>>,
+        init: this.txFromJSON<Result<readonly []>>,
+        name: this.txFromJSON<Result<string>>,
+        symbol: this.txFromJSON<Result<string>>,
+        approve: this.txFromJSON<Result<readonly []>>,
+        balance: this.txFromJSON<u32>,
+        upgrade: this.txFromJSON<Result<readonly []>>,
+        version: this.txFromJSON<u32>,
+        get_tier: this.txFromJSON<Result<Tier>>,
+        owner_of: this.txFromJSON<Result<string>>,
+        get_offer: this.txFromJSON<Option<TicketOffer>>,
+        get_tiers: this.txFromJSON<Result<Array<Tier>>>,
+        token_uri: this.txFromJSON<Result<string>>,
+        get_offers: this.txFromJSON<Array<TicketOffer>>,
+        get_ticket: this.txFromJSON<Result<TicketData>>,
+        is_on_sale: this.txFromJSON<Result<boolean>>,
+        make_offer: this.txFromJSON<Result<readonly []>>,
+        set_escrow: this.txFromJSON<Result<readonly []>>,
+        get_auction: this.txFromJSON<Result<Auction>>,
+        get_factory: this.txFromJSON<Option<string>>,
+        get_presale: this.txFromJSON<Option<PresaleConfig>>,
+        is_escrowed: this.txFromJSON<boolean>,
+        list_ticket: this.txFromJSON<Result<readonly []>>,
+        mint_ticket: this.txFromJSON<Result<u32>>,
+        set_factory: this.txFromJSON<Result<readonly []>>,
+        set_presale: this.txFromJSON<Result<readonly []>>,
+        accept_offer: this.txFromJSON<Result<readonly []>>,
+        cancel_event: this.txFromJSON<Result<readonly []>>,
+        cancel_offer: this.txFromJSON<Result<readonly []>>,
+        claim_rebate: this.txFromJSON<Result<i128>>,
+        claim_refund: this.txFromJSON<Result<i128>>,
+        get_approved: this.txFromJSON<Result<Option<string>>>,
+        get_base_uri: this.txFromJSON<Option<string>>,
+        is_cancelled: this.txFromJSON<boolean>,
+        mint_presale: this.txFromJSON<Result<Array<u32>>>,
+        mint_tickets: this.txFromJSON<Result<Array<u32>>>,
+        set_base_uri: this.txFromJSON<Result<readonly []>>,
+        claim_balance: this.txFromJSON<Result<i128>>,
+        delist_ticket: this.txFromJSON<Result<readonly []>>,
+        get_claimable: this.txFromJSON<i128>,
+        get_purchased: this.txFromJSON<u32>,
+        start_auction: this.txFromJSON<Result<readonly []>>,
+        transfer_from: this.txFromJSON<Result<readonly []>>,
+        get_event_info: this.txFromJSON<Result<EventInfo>>,
+        get_ttl_policy: this.txFromJSON<TtlPolicy>,
+        set_ticket_uri: this.txFromJSON<Result<readonly []>>,
+        set_ttl_policy: this.txFromJSON<Result<readonly []>>,
+        settle_auction: this.txFromJSON<Result<readonly []>>,
+        freeze_metadata: this.txFromJSON<Result<readonly []>>,
+        transfer_ticket: this.txFromJSON<Result<readonly []>>,
+        extend_event_ttl: this.txFromJSON<Result<readonly []>>,
+        get_all_auctions: this.txFromJSON<Array<Auction>>,
+        get_protocol_fee: this.txFromJSON<Option<ProtocolFee>>,
         get_user_tickets: this.txFromJSON<Array<u32>>,
-        mark_ticket_used: this.txFromJSON<null>,
+        mark_ticket_used: this.txFromJSON<Result<readonly []>>,
+        extend_ticket_ttl: this.txFromJSON<Result<readonly []>>,
+        get_current_price: this.txFromJSON<Result<i128>>,
+        get_dutch_auction: this.txFromJSON<Option<DutchAuction>>,
+        get_resale_policy: this.txFromJSON<ResalePolicy>,
+        set_dutch_auction: this.txFromJSON<Result<readonly []>>,
+        set_resale_policy: this.txFromJSON<Result<readonly []>>,
+        withdraw_proceeds: this.txFromJSON<Result<i128>>,
+        get_clearing_price: this.txFromJSON<Result<Option<i128>>>,
+        get_collection_bid: this.txFromJSON<Result<CollectionBid>>,
+        get_escrow_balance: this.txFromJSON<i128>,
+        get_max_per_wallet: this.txFromJSON<u32>,
         get_tickets_minted: this.txFromJSON<u32>,
-        buy_secondary_ticket: this.txFromJSON<null>,
-        update_listing_price: this.txFromJSON<null>,
+        get_tier_available: this.txFromJSON<Result<u32>>,
+        is_metadata_frozen: this.txFromJSON<boolean>,
+        set_max_per_wallet: this.txFromJSON<Result<readonly []>>,
+        set_royalty_splits: this.txFromJSON<Result<readonly []>>,
+        fill_collection_bid: this.txFromJSON<Result<readonly []>>,
+        get_collection_bids: this.txFromJSON<Array<CollectionBid>>,
+        is_approved_for_all: this.txFromJSON<boolean>,
+        buy_secondary_ticket: this.txFromJSON<Result<readonly []>>,
+        get_escrowed_payment: this.txFromJSON<Option<i128>>,
+        get_max_resale_price: this.txFromJSON<Result<Option<i128>>>,
+        get_wallet_allowance: this.txFromJSON<Result<u32>>,
+        place_collection_bid: this.txFromJSON<Result<u32>>,
+        set_approval_for_all: this.txFromJSON<null>,
+        update_listing_price: this.txFromJSON<Result<readonly []>>,
+        cancel_collection_bid: this.txFromJSON<Result<readonly []>>,
+        get_presale_purchased: this.txFromJSON<u32>,
         get_secondary_listing: this.txFromJSON<Option<SecondaryListing>>,
-        get_tickets_available: this.txFromJSON<u32>,
+        get_tickets_available: this.txFromJSON<Result<u32>>,
+        get_upgrade_authority: this.txFromJSON<UpgradeAuthority>,
+        set_upgrade_authority: this.txFromJSON<Result<readonly []>>,
+        get_withdrawable_proceeds: this.txFromJSON<Result<i128>>,
         get_all_secondary_listings: this.txFromJSON<Array<SecondaryListing>>
   }
 }
\ No newline at end of file